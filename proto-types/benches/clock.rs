@@ -0,0 +1,34 @@
+//! Benchmarks for reading the current time with the precise and the coarse clocks.
+//!
+//! On Linux, the coarse clocks are read from the vDSO without touching the hardware clock source,
+//! so `coarse` should be several times faster than `precise`. On the other platforms, both go through the standard library.
+
+use std::hint::black_box;
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use proto_types::{
+	Timestamp,
+	clock::{Clock, CoarseClock, SystemClock},
+};
+
+fn now(c: &mut Criterion) {
+	let mut group = c.benchmark_group("now");
+
+	group.bench_function("precise", |b| b.iter(|| black_box(SystemClock).now()));
+	group.bench_function("coarse", |b| b.iter(|| black_box(CoarseClock).now()));
+	group.bench_function("timestamp_now_coarse", |b| b.iter(Timestamp::now_coarse));
+
+	group.finish();
+}
+
+fn monotonic(c: &mut Criterion) {
+	let mut group = c.benchmark_group("monotonic");
+
+	group.bench_function("instant", |b| b.iter(std::time::Instant::now));
+	group.bench_function("coarse", |b| b.iter(CoarseClock::monotonic));
+
+	group.finish();
+}
+
+criterion_group!(benches, now, monotonic);
+criterion_main!(benches);
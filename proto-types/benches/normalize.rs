@@ -0,0 +1,78 @@
+//! Benchmarks for the normalization of Duration and Timestamp.
+//!
+//! Decoded messages are almost always normalized already, so the `normalized` inputs measure the fast path,
+//! while the `denormalized` inputs go through the full carry and sign fixups.
+//! To compare against a previous commit, run `cargo bench --bench normalize -- --save-baseline before`
+//! on that commit, and then `-- --baseline before` on the current one.
+
+use std::hint::black_box;
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use proto_types::{Duration, Timestamp};
+
+const NORMALIZED_DURATIONS: [(i64, i32); 4] =
+	[(0, 0), (30, 500_000_000), (-30, -500_000_000), (0, -1)];
+const DENORMALIZED_DURATIONS: [(i64, i32); 4] = [
+	(0, 1_500_000_000),
+	(30, -500_000_000),
+	(-30, 500_000_000),
+	(1, -2_000_000_001),
+];
+
+const NORMALIZED_TIMESTAMPS: [(i64, i32); 4] = [
+	(0, 0),
+	(1_714_557_600, 0),
+	(1_714_557_600, 123_456_789),
+	(-1, 999_999_999),
+];
+const DENORMALIZED_TIMESTAMPS: [(i64, i32); 4] = [
+	(0, -1),
+	(1_714_557_600, 1_000_000_000),
+	(1_714_557_600, -123_456_789),
+	(-1, -1_999_999_999),
+];
+
+fn durations(c: &mut Criterion) {
+	let mut group = c.benchmark_group("duration");
+
+	for (name, inputs) in [
+		("normalize/normalized", NORMALIZED_DURATIONS),
+		("normalize/denormalized", DENORMALIZED_DURATIONS),
+	] {
+		let values = inputs.map(|(seconds, nanos)| Duration { seconds, nanos });
+
+		group.bench_function(name, |b| {
+			b.iter(|| {
+				for value in black_box(&values) {
+					black_box(value.normalized());
+				}
+			});
+		});
+	}
+
+	group.finish();
+}
+
+fn timestamps(c: &mut Criterion) {
+	let mut group = c.benchmark_group("timestamp");
+
+	for (name, inputs) in [
+		("normalize/normalized", NORMALIZED_TIMESTAMPS),
+		("normalize/denormalized", DENORMALIZED_TIMESTAMPS),
+	] {
+		let values = inputs.map(|(seconds, nanos)| Timestamp { seconds, nanos });
+
+		group.bench_function(name, |b| {
+			b.iter(|| {
+				for value in black_box(&values) {
+					black_box(value.normalized());
+				}
+			});
+		});
+	}
+
+	group.finish();
+}
+
+criterion_group!(benches, durations, timestamps);
+criterion_main!(benches);
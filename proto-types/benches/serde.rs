@@ -0,0 +1,81 @@
+//! Benchmarks for the hand-written serde impls of the hot types.
+//!
+//! Each group also measures an allocating baseline (deserializing into a `String` and parsing it afterwards),
+//! which is how the values were handled before the impls parsed the input in place.
+//! To compare against a previous commit, run `cargo bench --bench serde --features serde,interval -- --save-baseline before`
+//! on that commit, and then `-- --baseline before` on the current one.
+
+use std::hint::black_box;
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use proto_types::{FieldMask, Interval, Timestamp};
+
+const TIMESTAMPS: &str = r#"["2024-05-01T10:00:00Z","2024-05-01T10:00:00.123Z","2024-05-01T10:00:00.123456789+02:00","1970-01-01T00:00:00Z"]"#;
+
+fn timestamps(c: &mut Criterion) {
+	let mut group = c.benchmark_group("timestamp");
+
+	group.bench_function("deserialize", |b| {
+		b.iter(|| serde_json::from_str::<Vec<Timestamp>>(black_box(TIMESTAMPS)).unwrap());
+	});
+
+	group.bench_function("deserialize_via_string", |b| {
+		b.iter(|| {
+			serde_json::from_str::<Vec<String>>(black_box(TIMESTAMPS))
+				.unwrap()
+				.iter()
+				.map(|s| s.parse::<Timestamp>().unwrap())
+				.collect::<Vec<_>>()
+		});
+	});
+
+	let values: Vec<Timestamp> = serde_json::from_str(TIMESTAMPS).unwrap();
+
+	group.bench_function("serialize", |b| {
+		b.iter(|| serde_json::to_string(black_box(&values)).unwrap());
+	});
+
+	group.bench_function("serialize_via_string", |b| {
+		b.iter(|| {
+			let strings: Vec<String> = black_box(&values)
+				.iter()
+				.map(ToString::to_string)
+				.collect();
+			serde_json::to_string(&strings).unwrap()
+		});
+	});
+
+	group.finish();
+}
+
+fn field_masks(c: &mut Criterion) {
+	let json = r#""name,address.street,address.city,labels""#;
+	let mask: FieldMask = serde_json::from_str(json).unwrap();
+
+	let mut group = c.benchmark_group("field_mask");
+
+	group.bench_function("deserialize", |b| {
+		b.iter(|| serde_json::from_str::<FieldMask>(black_box(json)).unwrap());
+	});
+
+	group.bench_function("serialize", |b| {
+		b.iter(|| serde_json::to_string(black_box(&mask)).unwrap());
+	});
+
+	group.bench_function("serialize_via_join", |b| {
+		b.iter(|| serde_json::to_string(&black_box(&mask).paths.join(",")).unwrap());
+	});
+
+	group.finish();
+}
+
+fn intervals(c: &mut Criterion) {
+	let json = r#"{"startTime":"2024-05-01T10:00:00Z","endTime":"2024-05-02T10:00:00Z"}"#;
+
+	c.bench_function("interval/deserialize", |b| {
+		b.iter(|| serde_json::from_str::<Interval>(black_box(json)).unwrap());
+	});
+}
+
+criterion_group!(benches, timestamps, field_masks, intervals);
+criterion_main!(benches);
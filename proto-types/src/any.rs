@@ -26,8 +26,8 @@ impl Any {
 		let expected_type_url = M::type_url();
 
 		if let (Some(expected), Some(actual)) = (
-			TypeUrl::new(&expected_type_url),
-			TypeUrl::new(&self.type_url),
+			TypeUrl::parse(&expected_type_url),
+			TypeUrl::parse(&self.type_url),
 		) && expected == actual
 		{
 			return M::decode(self.value.as_slice());
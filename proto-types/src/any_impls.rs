@@ -1,6 +1,8 @@
 use core::cmp::Ordering;
 
-use crate::Any;
+use prost::Message;
+
+use crate::{Any, Vec};
 
 impl Ord for Any {
 	fn cmp(&self, other: &Self) -> Ordering {
@@ -17,6 +19,17 @@ impl PartialOrd for Any {
 	}
 }
 
+impl Any {
+	/// Encodes the message into a byte-stable form, suitable for content hashing and deduplication.
+	///
+	/// The payload is treated as opaque bytes, so two messages are only encoded identically if their payloads were encoded identically.
+	#[must_use]
+	#[inline]
+	pub fn encode_canonical(&self) -> Vec<u8> {
+		self.encode_to_vec()
+	}
+}
+
 #[cfg(feature = "serde")]
 mod serde {
 	use core::fmt;
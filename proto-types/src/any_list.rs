@@ -0,0 +1,122 @@
+use alloc::collections::BTreeSet;
+
+use crate::{Any, DecodeError, Message, Name, String, ToString, TypeUrl, Vec};
+
+/// A set of message types that are known to the application, used to sort lists of [`Any`] messages.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AnyRegistry {
+	full_names: BTreeSet<String>,
+}
+
+impl AnyRegistry {
+	/// Creates a new empty registry.
+	#[must_use]
+	#[inline]
+	pub const fn new() -> Self {
+		Self {
+			full_names: BTreeSet::new(),
+		}
+	}
+
+	/// Registers the message type `M`.
+	#[inline]
+	pub fn register<M: Name>(&mut self) {
+		self.full_names.insert(M::full_name());
+	}
+
+	/// Registers the message type `M`, returning the registry.
+	#[must_use]
+	#[inline]
+	pub fn with<M: Name>(mut self) -> Self {
+		self.register::<M>();
+		self
+	}
+
+	/// Registers a message type by its fully qualified name, such as `google.rpc.BadRequest`.
+	#[inline]
+	pub fn register_name(&mut self, full_name: &str) {
+		self.full_names.insert(full_name.to_string());
+	}
+
+	/// Returns `true` if the type of the given [`Any`] message is registered.
+	#[must_use]
+	pub fn is_known(&self, any: &Any) -> bool {
+		TypeUrl::parse(&any.type_url).is_some_and(|url| self.full_names.contains(url.full_name()))
+	}
+}
+
+/// Helpers for lists of [`Any`] messages, such as `Status.details`.
+pub trait AnyListExt {
+	/// Returns the first message of type `M`, if there is one.
+	fn find_type<M: Name>(&self) -> Option<&Any>;
+
+	/// Decodes all the messages of type `M`, ignoring the messages of other types.
+	///
+	/// Returns an error if a message of type `M` cannot be decoded.
+	fn filter_unpack<M: Message + Name + Default>(&self) -> Result<Vec<M>, DecodeError>;
+
+	/// Splits the messages into those whose type is registered in the given registry, and the others.
+	fn partition_known<'a>(&'a self, registry: &AnyRegistry) -> (Vec<&'a Any>, Vec<&'a Any>);
+}
+
+fn is_type<M: Name>(any: &Any) -> bool {
+	TypeUrl::parse(&any.type_url).is_some_and(|url| url.matches::<M>())
+}
+
+impl AnyListExt for [Any] {
+	fn find_type<M: Name>(&self) -> Option<&Any> {
+		self.iter().find(|any| is_type::<M>(any))
+	}
+
+	fn filter_unpack<M: Message + Name + Default>(&self) -> Result<Vec<M>, DecodeError> {
+		self.iter()
+			.filter(|any| is_type::<M>(any))
+			.map(|any| M::decode(any.value.as_slice()))
+			.collect()
+	}
+
+	fn partition_known<'a>(&'a self, registry: &AnyRegistry) -> (Vec<&'a Any>, Vec<&'a Any>) {
+		self.iter()
+			.partition(|any| registry.is_known(any))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use alloc::vec;
+
+	use super::*;
+	use crate::{Duration, Empty, Timestamp};
+
+	#[test]
+	fn any_lists() {
+		let details = [
+			Any::from_msg(&Duration::new(1, 0)).unwrap(),
+			Any::from_msg(&Timestamp::new(10, 0)).unwrap(),
+			Any::from_msg(&Duration::new(2, 0)).unwrap(),
+			Any {
+				type_url: "example.com/acme.Custom".into(),
+				value: vec![],
+			},
+		];
+
+		assert_eq!(
+			details
+				.find_type::<Timestamp>()
+				.unwrap()
+				.to_msg::<Timestamp>(),
+			Ok(Timestamp::new(10, 0))
+		);
+		assert!(details.find_type::<Empty>().is_none());
+		assert_eq!(
+			details.filter_unpack::<Duration>().unwrap(),
+			vec![Duration::new(1, 0), Duration::new(2, 0)]
+		);
+
+		let registry = AnyRegistry::new().with::<Duration>();
+		let (known, unknown) = details.partition_known(&registry);
+		assert_eq!(known.len(), 2);
+		assert_eq!(unknown.len(), 2);
+		assert_eq!(unknown[1].type_url, "example.com/acme.Custom");
+	}
+}
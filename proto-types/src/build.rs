@@ -0,0 +1,25 @@
+//! ```rust,ignore
+//! let mut config = prost_build::Config::new();
+//!
+//! for (proto_path, rust_path) in proto_types::build::extern_paths() {
+//!     config.extern_path(*proto_path, *rust_path);
+//! }
+//! ```
+//!
+//! The mappings for `google.type`, `google.rpc` and `buf.validate` require the corresponding features
+//! (`all_common` or the individual type features, `rpc` and `protovalidate`) to be enabled on the regular dependency.
+
+/// The `extern_path` mappings from the protobuf packages implemented in this crate to their rust paths.
+pub const EXTERN_PATHS: &[(&str, &str)] = &[
+	(".google.protobuf", "::proto_types"),
+	(".google.type", "::proto_types"),
+	(".google.rpc", "::proto_types"),
+	(".buf.validate", "::proto_types::protovalidate"),
+];
+
+/// Returns the `extern_path` mappings from the protobuf packages implemented in this crate to their rust paths.
+#[must_use]
+#[inline]
+pub const fn extern_paths() -> &'static [(&'static str, &'static str)] {
+	EXTERN_PATHS
+}
@@ -0,0 +1,201 @@
+//! Calendar arithmetic in the proleptic Gregorian calendar, shared by the date-based types.
+
+/// Returns `true` if the given year is a leap year in the proleptic Gregorian calendar.
+#[must_use]
+#[inline]
+pub const fn is_leap_year(year: i64) -> bool {
+	(year % 4 == 0) && ((year % 100 != 0) || (year % 400 == 0))
+}
+
+/// Returns the number of days in the given year (365 or 366).
+#[must_use]
+#[inline]
+pub const fn days_in_year(year: i64) -> u16 {
+	if is_leap_year(year) { 366 } else { 365 }
+}
+
+/// Returns the number of days in the given month (from 1 to 12) of the given year, or 0 if the month is out of range.
+#[must_use]
+#[inline]
+pub const fn days_in_month(year: i64, month: u8) -> u8 {
+	match month {
+		1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+		4 | 6 | 9 | 11 => 30,
+		2 if is_leap_year(year) => 29,
+		2 => 28,
+		_ => 0,
+	}
+}
+
+/// Returns the number of days between the unix epoch and the given date.
+pub(crate) const fn days_from_civil(year: i64, month: u8, day: u8) -> i64 {
+	let (month, day) = (month as i64, day as i64);
+	let year = if month <= 2 { year - 1 } else { year };
+	let era = year.div_euclid(400);
+	let year_of_era = year - era * 400;
+	let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+	let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+	era * 146_097 + day_of_era - 719_468
+}
+
+/// Returns the ISO weekday of the given date, from 1 (Monday) to 7 (Sunday).
+#[cfg(feature = "datetime")]
+pub(crate) const fn iso_weekday(year: i32, month: u8, day: u8) -> u8 {
+	// The unix epoch was a Thursday
+	// SAFETY: The euclidean remainder is always within 0..7
+	#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+	let weekday = (days_from_civil(year as i64, month, day) + 3).rem_euclid(7) as u8;
+
+	weekday + 1
+}
+
+/// Returns the day of the year of the given date, starting from 1.
+#[cfg(feature = "datetime")]
+pub(crate) const fn day_of_year(year: i32, month: u8, day: u8) -> u16 {
+	let mut total = day as u16;
+	let mut m = 1;
+
+	while m < month {
+		total += days_in_month(year as i64, m) as u16;
+		m += 1;
+	}
+
+	total
+}
+
+/// Returns the month and day for the given day of the year, or `None` if it is out of range.
+#[cfg(feature = "datetime")]
+pub(crate) const fn from_ordinal(year: i32, ordinal: u16) -> Option<(u8, u8)> {
+	if ordinal == 0 || ordinal > days_in_year(year as i64) {
+		return None;
+	}
+
+	let mut remaining = ordinal;
+	let mut month = 1;
+
+	while remaining > days_in_month(year as i64, month) as u16 {
+		remaining -= days_in_month(year as i64, month) as u16;
+		month += 1;
+	}
+
+	// SAFETY: The remainder is at most 31
+	#[allow(clippy::cast_possible_truncation)]
+	Some((month, remaining as u8))
+}
+
+/// Returns the number of ISO weeks in the given ISO week-numbering year (52 or 53).
+#[cfg(feature = "datetime")]
+pub(crate) const fn iso_weeks_in_year(year: i32) -> u8 {
+	// A year has 53 weeks if it starts on a Thursday, or if it is a leap year starting on a Wednesday
+	let jan_first = iso_weekday(year, 1, 1);
+
+	if jan_first == 4 || (jan_first == 3 && is_leap_year(year as i64)) {
+		53
+	} else {
+		52
+	}
+}
+
+/// Returns the ISO week-numbering year, the week number and the weekday of the given date.
+#[cfg(feature = "datetime")]
+pub(crate) const fn iso_week_date(year: i32, month: u8, day: u8) -> (i32, u8, u8) {
+	let weekday = iso_weekday(year, month, day);
+	let ordinal = day_of_year(year, month, day) as i32;
+	let week = (ordinal - weekday as i32 + 10) / 7;
+
+	if week < 1 {
+		(year - 1, iso_weeks_in_year(year - 1), weekday)
+	} else if week > iso_weeks_in_year(year) as i32 {
+		(year + 1, 1, weekday)
+	} else {
+		// SAFETY: The week is within 1..=53
+		#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+		(year, week as u8, weekday)
+	}
+}
+
+/// Returns the calendar date of the given ISO week date, or `None` if the week or the weekday are out of range.
+#[cfg(feature = "datetime")]
+pub(crate) const fn from_iso_week_date(
+	iso_year: i32,
+	week: u8,
+	weekday: u8,
+) -> Option<(i32, u8, u8)> {
+	if week == 0 || week > iso_weeks_in_year(iso_year) || weekday == 0 || weekday > 7 {
+		return None;
+	}
+
+	// The 4th of January is always in the first week
+	let ordinal = week as i32 * 7 + weekday as i32 - (iso_weekday(iso_year, 1, 4) as i32 + 3);
+
+	let (year, ordinal) = if ordinal < 1 {
+		(
+			iso_year - 1,
+			ordinal + days_in_year(iso_year as i64 - 1) as i32,
+		)
+	} else if ordinal > days_in_year(iso_year as i64) as i32 {
+		(iso_year + 1, ordinal - days_in_year(iso_year as i64) as i32)
+	} else {
+		(iso_year, ordinal)
+	};
+
+	// SAFETY: The ordinal is within 1..=366
+	#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+	match from_ordinal(year, ordinal as u16) {
+		Some((month, day)) => Some((year, month, day)),
+		None => None,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn leap_years() {
+		assert!(is_leap_year(2024));
+		assert!(is_leap_year(2000));
+		assert!(!is_leap_year(1900));
+		assert!(!is_leap_year(2023));
+
+		assert_eq!(days_in_year(2024), 366);
+		assert_eq!(days_in_year(2100), 365);
+		assert_eq!(days_in_month(2024, 2), 29);
+		assert_eq!(days_in_month(2023, 2), 28);
+		assert_eq!(days_in_month(2023, 13), 0);
+		// Year 0 is a leap year in the proleptic Gregorian calendar
+		assert_eq!(days_in_month(0, 2), 29);
+		assert!(is_leap_year(-4));
+	}
+
+	#[test]
+	fn civil_days() {
+		assert_eq!(days_from_civil(1970, 1, 1), 0);
+		assert_eq!(days_from_civil(2000, 3, 1), 11_017);
+		assert_eq!(days_from_civil(1969, 12, 31), -1);
+	}
+
+	#[cfg(feature = "datetime")]
+	#[test]
+	fn iso_week_dates() {
+		assert_eq!(iso_week_date(2024, 5, 14), (2024, 20, 2));
+		// Belongs to the last week of the previous ISO year
+		assert_eq!(iso_week_date(2021, 1, 1), (2020, 53, 5));
+		// Belongs to the first week of the next ISO year
+		assert_eq!(iso_week_date(2024, 12, 30), (2025, 1, 1));
+
+		assert_eq!(from_iso_week_date(2020, 53, 5), Some((2021, 1, 1)));
+		assert_eq!(from_iso_week_date(2025, 1, 1), Some((2024, 12, 30)));
+		assert_eq!(from_iso_week_date(2024, 53, 1), None);
+	}
+
+	#[cfg(feature = "datetime")]
+	#[test]
+	fn ordinal_dates() {
+		assert_eq!(day_of_year(2024, 5, 20), 141);
+		assert_eq!(day_of_year(2023, 12, 31), 365);
+		assert_eq!(from_ordinal(2024, 366), Some((12, 31)));
+		assert_eq!(from_ordinal(2023, 366), None);
+	}
+}
@@ -0,0 +1,98 @@
+use core::hash::Hash;
+
+use crate::{
+	CalendarPeriod, Code, DayOfWeek, Duration, Empty, Month,
+	num_wrappers::{Fixed32, Fixed64, Sfixed32, Sfixed64, Sint32, Sint64},
+};
+
+mod sealed {
+	pub trait Sealed {}
+}
+
+/// Marker for types whose [`Ord`] implementation compares the represented values, regardless of how they are normalized.
+///
+/// For example, [`Duration`] is compared by its total amount of nanoseconds, so `1s - 1ns` and `999_999_999ns` compare as equal.
+/// [`Timestamp`](crate::Timestamp) is not included because it is compared field by field, so it must be normalized first.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait CanonicalOrd: Ord + sealed::Sealed {}
+
+/// Marker for types whose [`Hash`] implementation only depends on the represented value, because they have a single valid representation for each value.
+///
+/// Types such as [`Timestamp`](crate::Timestamp), [`Duration`] or [`Money`](crate::Money) are not included because the same value can be represented with different combinations of fields.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait CanonicalHash: Hash + Eq + sealed::Sealed {}
+
+macro_rules! impl_canonical {
+	($trait:ident => $($ty:ty),* $(,)?) => {
+		$(
+			impl $trait for $ty {}
+		)*
+	};
+}
+
+macro_rules! impl_sealed {
+	($($ty:ty),* $(,)?) => {
+		$(
+			impl sealed::Sealed for $ty {}
+		)*
+	};
+}
+
+impl_sealed!(
+	Duration,
+	Empty,
+	Code,
+	DayOfWeek,
+	Month,
+	CalendarPeriod,
+	Sint64,
+	Sint32,
+	Sfixed64,
+	Sfixed32,
+	Fixed64,
+	Fixed32,
+);
+
+impl_canonical!(CanonicalOrd => Duration, Code, DayOfWeek, Month, CalendarPeriod, Sint64, Sint32, Sfixed64, Sfixed32, Fixed64, Fixed32);
+impl_canonical!(CanonicalHash => Empty, Code, DayOfWeek, Month, CalendarPeriod, Sint64, Sint32, Sfixed64, Sfixed32, Fixed64, Fixed32);
+
+#[cfg(feature = "date")]
+impl_sealed!(crate::Date);
+#[cfg(feature = "date")]
+impl_canonical!(CanonicalHash => crate::Date);
+
+#[cfg(feature = "timeofday")]
+impl_sealed!(crate::TimeOfDay);
+#[cfg(feature = "timeofday")]
+impl_canonical!(CanonicalOrd => crate::TimeOfDay);
+#[cfg(feature = "timeofday")]
+impl_canonical!(CanonicalHash => crate::TimeOfDay);
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn max_canonical<T: CanonicalOrd + Copy>(values: &[T]) -> Option<T> {
+		values.iter().copied().max()
+	}
+
+	#[test]
+	fn duration_ordering_ignores_normalization() {
+		let unnormalized = Duration {
+			seconds: 1,
+			nanos: -1,
+		};
+		let normalized = Duration {
+			seconds: 0,
+			nanos: 999_999_999,
+		};
+
+		assert_eq!(unnormalized.cmp(&normalized), core::cmp::Ordering::Equal);
+		assert_eq!(
+			max_canonical(&[Duration::new(1, 0), Duration::new(2, 0)]),
+			Some(Duration::new(2, 0))
+		);
+	}
+}
@@ -1,10 +1,17 @@
 #![allow(clippy::std_instead_of_core)]
+use alloc::sync::Arc;
 use std::{collections::HashMap, convert::Infallible};
 
-use cel::{Value as CelValue, objects::Key as CelKey};
+use cel::{
+	Context, ExecutionError, FunctionContext, Value as CelValue,
+	extractors::This,
+	objects::{Key as CelKey, Map as CelMap},
+};
 use thiserror::Error;
 
-use crate::{Any, Empty, FieldMask, Vec, duration::DurationError, timestamp::TimestampError};
+use crate::{
+	Any, Empty, FieldMask, String, Vec, duration::DurationError, timestamp::TimestampError,
+};
 
 #[derive(Debug, Error, PartialEq, Eq, Clone)]
 #[non_exhaustive]
@@ -14,6 +21,97 @@ pub enum CelConversionError {
 
 	#[error("{0}")]
 	TimestampError(#[from] TimestampError),
+
+	#[error("The value is not represented as a CEL map")]
+	NotAMap,
+}
+
+/// Converts a common type (such as [`Money`](crate::Money), [`LatLng`](crate::LatLng) or [`PostalAddress`](crate::PostalAddress)) into a CEL map, keyed by its proto field names.
+pub fn to_cel_map<T>(value: T) -> Result<HashMap<CelKey, CelValue>, CelConversionError>
+where
+	T: TryInto<CelValue>,
+	CelConversionError: From<T::Error>,
+{
+	match value.try_into()? {
+		CelValue::Map(map) => Ok(Arc::unwrap_or_clone(map.map)),
+		_ => Err(CelConversionError::NotAMap),
+	}
+}
+
+/// The proto field names of the common types that are converted into CEL maps, which are registered as accessor functions by [`register_common_type_accessors`].
+pub const COMMON_TYPE_FIELDS: &[&str] = &[
+	"address_lines",
+	"administrative_area",
+	"alpha",
+	"blue",
+	"currency_code",
+	"day",
+	"denominator",
+	"description",
+	"end_time",
+	"expression",
+	"extension",
+	"green",
+	"hours",
+	"id",
+	"kind",
+	"language_code",
+	"latitude",
+	"locality",
+	"location",
+	"longitude",
+	"minutes",
+	"month",
+	"nanos",
+	"number",
+	"numerator",
+	"postal_code",
+	"recipients",
+	"red",
+	"region_code",
+	"revision",
+	"seconds",
+	"sorting_code",
+	"start_time",
+	"sublocality",
+	"text",
+	"time_offset",
+	"title",
+	"units",
+	"value",
+	"version",
+	"w",
+	"x",
+	"y",
+	"year",
+	"z",
+];
+
+/// Registers an accessor function for each of the [`COMMON_TYPE_FIELDS`].
+///
+/// This allows the fields of the common types to be read with a method call (`money.units()`) or a global function (`units(money)`), in addition to the regular field selection (`money.units`).
+pub fn register_common_type_accessors(ctx: &mut Context) {
+	for field in COMMON_TYPE_FIELDS {
+		let key = CelKey::String(Arc::new(String::from(*field)));
+
+		ctx.add_function(
+			field,
+			move |ftx: &FunctionContext,
+			      This(this): This<CelValue>|
+			      -> Result<CelValue, ExecutionError> {
+				match this {
+					CelValue::Map(CelMap { map }) => map
+						.get(&key)
+						.cloned()
+						.ok_or_else(|| ExecutionError::no_such_key(field)),
+					other => Err(ftx.error(crate::format!(
+						"cannot read the field `{field}` from a value of type {:?}",
+						other.type_of()
+					))),
+				}
+			},
+		);
+	}
 }
 
 impl From<Infallible> for CelConversionError {
@@ -22,6 +120,100 @@ impl From<Infallible> for CelConversionError {
 	}
 }
 
+#[cfg(feature = "money")]
+mod money {
+	use core::cmp::Ordering;
+
+	use cel::{Context, ExecutionError, FunctionContext, Value as CelValue, extractors::This};
+
+	use crate::{Money, String};
+
+	fn money_from_cel(ftx: &FunctionContext, value: &CelValue) -> Result<Money, ExecutionError> {
+		let CelValue::Map(map) = value else {
+			return Err(ftx.error(crate::format!(
+				"expected a google.type.Money value, found {:?}",
+				value.type_of()
+			)));
+		};
+
+		let field = |name: &str| {
+			map.map
+				.get(&String::from(name).into())
+				.ok_or_else(|| ExecutionError::no_such_key(name))
+		};
+
+		let currency_code = match field("currency_code")? {
+			CelValue::String(code) => code.as_str(),
+			_ => return Err(ftx.error("currency_code must be a string")),
+		};
+		let units = match field("units")? {
+			CelValue::Int(units) => *units,
+			_ => return Err(ftx.error("units must be an int")),
+		};
+		let nanos = match field("nanos")? {
+			CelValue::Int(nanos) => {
+				i32::try_from(*nanos).map_err(|_| ftx.error("nanos out of range"))?
+			}
+			_ => return Err(ftx.error("nanos must be an int")),
+		};
+
+		Money::new(currency_code, units, nanos).map_err(|e| ftx.error(e))
+	}
+
+	fn compare(
+		ftx: &FunctionContext,
+		this: &CelValue,
+		other: &CelValue,
+	) -> Result<Ordering, ExecutionError> {
+		let this = money_from_cel(ftx, this)?;
+		let other = money_from_cel(ftx, other)?;
+
+		this.try_cmp(&other).map_err(|e| ftx.error(e))
+	}
+
+	/// The functions registered by [`register_money_comparisons`], along with the orderings that make them return `true`.
+	const COMPARISONS: &[(&str, &[Ordering])] = &[
+		("moneyLt", &[Ordering::Less]),
+		("moneyLe", &[Ordering::Less, Ordering::Equal]),
+		("moneyGt", &[Ordering::Greater]),
+		("moneyGe", &[Ordering::Greater, Ordering::Equal]),
+		("moneyEq", &[Ordering::Equal]),
+	];
+
+	/// Registers currency-safe comparison functions for [`Money`] values.
+	///
+	/// The operators of CEL cannot be overloaded, so comparisons must use these functions, which raise an evaluation error
+	/// when the currencies of the two amounts differ instead of comparing their units:
+	/// - `moneyCmp(a, b)` returns -1, 0 or 1
+	/// - `moneyLt`, `moneyLe`, `moneyGt`, `moneyGe` and `moneyEq` return a bool
+	///
+	/// All of them can also be called as methods, as in `this.price.moneyLe(this.budget)`.
+	pub fn register_money_comparisons(ctx: &mut Context) {
+		ctx.add_function(
+			"moneyCmp",
+			|ftx: &FunctionContext,
+			 This(this): This<CelValue>,
+			 other: CelValue|
+			 -> Result<i64, ExecutionError> { Ok(compare(ftx, &this, &other)? as i64) },
+		);
+
+		for (name, orderings) in COMPARISONS {
+			ctx.add_function(
+				name,
+				move |ftx: &FunctionContext,
+				      This(this): This<CelValue>,
+				      other: CelValue|
+				      -> Result<bool, ExecutionError> {
+					Ok(orderings.contains(&compare(ftx, &this, &other)?))
+				},
+			);
+		}
+	}
+}
+
+#[cfg(feature = "money")]
+pub use money::register_money_comparisons;
+
 impl From<Any> for CelValue {
 	fn from(value: Any) -> Self {
 		let mut cel_map: HashMap<CelKey, Self> = HashMap::new();
@@ -100,3 +292,65 @@ impl From<Empty> for CelValue {
 		Self::Map(HashMap::<CelKey, Self>::new().into())
 	}
 }
+
+#[cfg(all(test, feature = "money", feature = "latlng"))]
+mod tests {
+	use cel::Program;
+
+	use super::*;
+	use crate::{LatLng, Money, ToString};
+
+	#[test]
+	fn common_type_accessors() {
+		let money = Money::new("USD", 10, 500_000_000).unwrap();
+		let map = to_cel_map(money.clone()).unwrap();
+		assert_eq!(map.get(&"units".into()), Some(&CelValue::Int(10)));
+		assert_eq!(to_cel_map(Empty {}), Ok(HashMap::new()));
+
+		let mut ctx = Context::default();
+		register_common_type_accessors(&mut ctx);
+		ctx.add_variable_from_value("money", money);
+		ctx.add_variable_from_value(
+			"location",
+			LatLng {
+				latitude: 45.0,
+				longitude: 9.0,
+			},
+		);
+
+		let program = Program::compile(
+			"money.units == 10 && money.units() == 10 && units(money) == 10 && location.latitude() > 40.0",
+		)
+		.unwrap();
+		assert_eq!(program.execute(&ctx), Ok(CelValue::Bool(true)));
+
+		let program = Program::compile("units(location)").unwrap();
+		assert!(program.execute(&ctx).is_err());
+	}
+
+	#[test]
+	fn money_comparisons() {
+		let mut ctx = Context::default();
+		register_money_comparisons(&mut ctx);
+		ctx.add_variable_from_value("price", Money::new("USD", 10, 0).unwrap());
+		ctx.add_variable_from_value("budget", Money::new("USD", 12, 500_000_000).unwrap());
+		ctx.add_variable_from_value("foreign", Money::new("EUR", 1, 0).unwrap());
+
+		let eval = |expression: &str| {
+			Program::compile(expression)
+				.unwrap()
+				.execute(&ctx)
+		};
+
+		assert_eq!(
+			eval(
+				"price.moneyLe(budget) && moneyLt(price, budget) && !price.moneyGe(budget) && price.moneyEq(price)"
+			),
+			Ok(CelValue::Bool(true))
+		);
+		assert_eq!(eval("moneyCmp(budget, price)"), Ok(CelValue::Int(1)));
+
+		let error = eval("price.moneyLe(foreign)").unwrap_err();
+		assert!(error.to_string().contains("Currency mismatch"));
+	}
+}
@@ -0,0 +1,203 @@
+//! Serde adapters that (de)serialize a [`Timestamp`] exactly like the equivalent [`chrono`] type would.
+//!
+//! They are meant to be used with `#[serde(with = "...")]` while migrating an existing JSON API to the types of this crate,
+//! so that the JSON output stays byte-identical.
+//!
+//! # Examples
+//! ```rust
+//! use proto_types::{Timestamp, chrono_serde};
+//! use serde::{Deserialize, Serialize};
+//!
+//! proto_types::chrono_format_adapter!(pub mod legacy_format, "%Y-%m-%d %H:%M:%S");
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Event {
+//!   #[serde(with = "chrono_serde::as_chrono_datetime")]
+//!   created_at: Timestamp,
+//!   #[serde(with = "chrono_serde::ts_seconds")]
+//!   expires_at: Timestamp,
+//!   #[serde(with = "legacy_format")]
+//!   legacy: Timestamp,
+//! }
+//!
+//! let event = Event {
+//!   created_at: "2024-05-01T10:00:00.5Z".parse().unwrap(),
+//!   expires_at: "2024-05-02T10:00:00Z".parse().unwrap(),
+//!   legacy: "2024-05-01T10:00:00Z".parse().unwrap(),
+//! };
+//!
+//! assert_eq!(
+//!   serde_json::to_string(&event).unwrap(),
+//!   r#"{"created_at":"2024-05-01T10:00:00.500Z","expires_at":1714644000,"legacy":"2024-05-01 10:00:00"}"#
+//! );
+//! ```
+
+use core::fmt::Write;
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error as _, ser::Error as _};
+
+use crate::{String, Timestamp, format};
+
+fn to_chrono<E: serde::ser::Error>(timestamp: &Timestamp) -> Result<DateTime<Utc>, E> {
+	DateTime::<Utc>::try_from(*timestamp).map_err(|e| {
+		E::custom(format!(
+			"Cannot represent {timestamp:?} as a chrono DateTime: {e}"
+		))
+	})
+}
+
+/// (De)serializes a [`Timestamp`] like a [`chrono::DateTime<Utc>`], as an RFC 3339 string with 0, 3, 6 or 9 fractional digits.
+pub mod as_chrono_datetime {
+	use super::*;
+
+	pub fn serialize<S: Serializer>(
+		timestamp: &Timestamp,
+		serializer: S,
+	) -> Result<S::Ok, S::Error> {
+		to_chrono(timestamp)?.serialize(serializer)
+	}
+
+	pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Timestamp, D::Error> {
+		DateTime::<Utc>::deserialize(deserializer).map(Timestamp::from)
+	}
+}
+
+/// (De)serializes a [`Timestamp`] like [`chrono::serde::ts_seconds`], as a number of whole seconds since the Unix epoch.
+pub mod ts_seconds {
+	use super::*;
+
+	pub fn serialize<S: Serializer>(
+		timestamp: &Timestamp,
+		serializer: S,
+	) -> Result<S::Ok, S::Error> {
+		chrono::serde::ts_seconds::serialize(&to_chrono(timestamp)?, serializer)
+	}
+
+	pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Timestamp, D::Error> {
+		chrono::serde::ts_seconds::deserialize(deserializer).map(Timestamp::from)
+	}
+}
+
+/// (De)serializes a [`Timestamp`] like [`chrono::serde::ts_milliseconds`], as a number of milliseconds since the Unix epoch.
+pub mod ts_milliseconds {
+	use super::*;
+
+	pub fn serialize<S: Serializer>(
+		timestamp: &Timestamp,
+		serializer: S,
+	) -> Result<S::Ok, S::Error> {
+		chrono::serde::ts_milliseconds::serialize(&to_chrono(timestamp)?, serializer)
+	}
+
+	pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Timestamp, D::Error> {
+		chrono::serde::ts_milliseconds::deserialize(deserializer).map(Timestamp::from)
+	}
+}
+
+/// Serializes a [`Timestamp`] as a string with a chrono format string, in UTC.
+///
+/// Used by the modules generated with [`chrono_format_adapter!`](crate::chrono_format_adapter).
+pub fn serialize_with_format<S: Serializer>(
+	timestamp: &Timestamp,
+	format: &str,
+	serializer: S,
+) -> Result<S::Ok, S::Error> {
+	let datetime = to_chrono::<S::Error>(timestamp)?;
+	let mut output = String::new();
+
+	write!(output, "{}", datetime.format(format))
+		.map_err(|_| S::Error::custom(format!("Invalid chrono format string '{format}'")))?;
+
+	serializer.serialize_str(&output)
+}
+
+/// Deserializes a [`Timestamp`] from a string with a chrono format string.
+///
+/// If the format does not contain an offset, the value is interpreted as UTC.
+/// Used by the modules generated with [`chrono_format_adapter!`](crate::chrono_format_adapter).
+pub fn deserialize_with_format<'de, D: Deserializer<'de>>(
+	deserializer: D,
+	format: &str,
+) -> Result<Timestamp, D::Error> {
+	let value = String::deserialize(deserializer)?;
+
+	DateTime::parse_from_str(&value, format)
+		.map(|datetime| Timestamp::from(datetime.to_utc()))
+		.or_else(|_| NaiveDateTime::parse_from_str(&value, format).map(Timestamp::from))
+		.map_err(|e| {
+			D::Error::custom(format!(
+				"Cannot parse '{value}' with format '{format}': {e}"
+			))
+		})
+}
+
+/// Generates a module to be used with `#[serde(with = "...")]`, which (de)serializes a [`Timestamp`] as a string with the given chrono format.
+///
+/// See the [`chrono_serde`](crate::chrono_serde) module for an example.
+#[macro_export]
+macro_rules! chrono_format_adapter {
+	($vis:vis mod $name:ident, $format:literal) => {
+		$vis mod $name {
+			pub fn serialize<S: ::serde::Serializer>(
+				timestamp: &$crate::Timestamp,
+				serializer: S,
+			) -> ::core::result::Result<S::Ok, S::Error> {
+				$crate::chrono_serde::serialize_with_format(timestamp, $format, serializer)
+			}
+
+			pub fn deserialize<'de, D: ::serde::Deserializer<'de>>(
+				deserializer: D,
+			) -> ::core::result::Result<$crate::Timestamp, D::Error> {
+				$crate::chrono_serde::deserialize_with_format(deserializer, $format)
+			}
+		}
+	};
+}
+
+#[cfg(test)]
+mod tests {
+	use serde_json::json;
+
+	use super::*;
+
+	crate::chrono_format_adapter!(mod with_offset, "%d/%m/%Y %H:%M %z");
+
+	#[derive(Debug, PartialEq, Serialize, Deserialize)]
+	struct Legacy {
+		#[serde(with = "as_chrono_datetime")]
+		at: Timestamp,
+		#[serde(with = "ts_milliseconds")]
+		millis: Timestamp,
+		#[serde(with = "with_offset")]
+		custom: Timestamp,
+	}
+
+	#[test]
+	fn matches_chrono_output() {
+		let at: Timestamp = "2024-05-01T10:00:00.123456Z".parse().unwrap();
+		let chrono_at = DateTime::<Utc>::try_from(at).unwrap();
+
+		let legacy = Legacy {
+			at,
+			millis: "2024-05-01T10:00:00.250Z".parse().unwrap(),
+			custom: "2024-05-01T08:30:00Z".parse().unwrap(),
+		};
+
+		let value = serde_json::to_value(&legacy).unwrap();
+		assert_eq!(value["at"], serde_json::to_value(chrono_at).unwrap());
+		assert_eq!(value["millis"], json!(1_714_557_600_250_i64));
+		assert_eq!(value["custom"], json!("01/05/2024 08:30 +0000"));
+
+		assert_eq!(Legacy::deserialize(value).unwrap(), legacy);
+
+		let parsed: Legacy = Legacy::deserialize(json!({
+			"at": "2024-05-01T12:00:00+02:00",
+			"millis": 0,
+			"custom": "01/05/2024 10:30 +0200"
+		}))
+		.unwrap();
+		assert_eq!(parsed.at, "2024-05-01T10:00:00Z".parse().unwrap());
+		assert_eq!(parsed.custom, legacy.custom);
+	}
+}
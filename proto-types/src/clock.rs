@@ -0,0 +1,213 @@
+//! Sources of the current time, which can be swapped depending on the precision and the cost that a service can afford.
+//!
+//! Services that read the time on every request can use [`CoarseClock`], which reads the coarse clocks of the kernel
+//! (updated on every tick, usually every 1-4ms) without going through the more expensive precise clock source.
+
+#[cfg(feature = "std")]
+use crate::Duration;
+use crate::Timestamp;
+
+/// A source of the current time, which can be swapped with a cheaper or a controllable implementation.
+pub trait Clock {
+	/// Returns the current time.
+	fn now(&self) -> Timestamp;
+}
+
+impl<C: Clock + ?Sized> Clock for &C {
+	#[inline]
+	fn now(&self) -> Timestamp {
+		(**self).now()
+	}
+}
+
+/// A clock that reads the precise system time, like [`Timestamp::now`].
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SystemClock;
+
+#[cfg(feature = "std")]
+impl Clock for SystemClock {
+	#[inline]
+	fn now(&self) -> Timestamp {
+		Timestamp::now()
+	}
+}
+
+/// A clock that trades precision for speed, by reading the coarse clocks of the kernel.
+///
+/// On Linux and Android, it uses `CLOCK_REALTIME_COARSE` for the current time and `CLOCK_MONOTONIC_COARSE` for [`monotonic`](Self::monotonic),
+/// which are served from the vDSO without a syscall and without reading the hardware clock source.
+/// Their resolution is the length of a kernel tick, as returned by [`resolution`](Self::resolution).
+///
+/// On the other platforms, or if the coarse clocks are not available, it falls back to the precise clocks of the standard library
+/// (which use `GetSystemTimePreciseAsFileTime` on Windows).
+///
+/// # Examples
+/// ```rust
+/// use proto_types::{Timestamp, clock::{Clock, CoarseClock}};
+///
+/// fn handle_request(clock: &impl Clock) -> Timestamp {
+///   clock.now()
+/// }
+///
+/// let received_at = handle_request(&CoarseClock);
+/// assert!(received_at > Timestamp::default());
+///
+/// let start = CoarseClock::monotonic();
+/// assert!(CoarseClock::monotonic() >= start);
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct CoarseClock;
+
+#[cfg(all(feature = "std", any(target_os = "linux", target_os = "android")))]
+mod coarse {
+	use core::mem::MaybeUninit;
+
+	type ClockFn = unsafe extern "C" fn(libc::clockid_t, *mut libc::timespec) -> libc::c_int;
+
+	/// Calls `clock_gettime` or `clock_getres` for the given clock, returning the seconds and the nanoseconds, or `None` if the clock is not available.
+	fn call(function: ClockFn, clock: libc::clockid_t) -> Option<(i64, i32)> {
+		let mut time = MaybeUninit::<libc::timespec>::uninit();
+
+		// SAFETY: The pointer refers to a writable timespec, which outlives the call
+		if unsafe { function(clock, time.as_mut_ptr()) } != 0 {
+			return None;
+		}
+
+		// SAFETY: The timespec is always initialized when the call succeeds
+		let time = unsafe { time.assume_init() };
+
+		// SAFETY: time_t is 32 bits wide on some targets, and tv_nsec is always within 0..10^9
+		#[allow(clippy::useless_conversion, clippy::cast_possible_truncation)]
+		Some((i64::from(time.tv_sec), time.tv_nsec as i32))
+	}
+
+	#[inline]
+	pub(super) fn read(clock: libc::clockid_t) -> Option<(i64, i32)> {
+		call(libc::clock_gettime, clock)
+	}
+
+	#[inline]
+	pub(super) fn resolution(clock: libc::clockid_t) -> Option<(i64, i32)> {
+		call(libc::clock_getres, clock)
+	}
+}
+
+#[cfg(feature = "std")]
+impl CoarseClock {
+	/// Returns the time elapsed since an unspecified starting point, which never goes backwards.
+	///
+	/// Only the differences between two readings are meaningful, for example to measure the latency of a request.
+	#[must_use]
+	#[inline]
+	pub fn monotonic() -> Duration {
+		// The source is picked on the first call, since the readings of two different sources cannot be compared
+		#[cfg(any(target_os = "linux", target_os = "android"))]
+		{
+			static HAS_COARSE: std::sync::OnceLock<bool> = std::sync::OnceLock::new();
+
+			if *HAS_COARSE.get_or_init(|| coarse::read(libc::CLOCK_MONOTONIC_COARSE).is_some()) {
+				// CLOCK_MONOTONIC shares the time base of the coarse clock, in the unlikely case that a later reading fails
+				let (seconds, nanos) = coarse::read(libc::CLOCK_MONOTONIC_COARSE)
+					.or_else(|| coarse::read(libc::CLOCK_MONOTONIC))
+					.unwrap_or_default();
+
+				return Duration { seconds, nanos };
+			}
+		}
+
+		static START: std::sync::OnceLock<std::time::Instant> = std::sync::OnceLock::new();
+
+		START
+			.get_or_init(std::time::Instant::now)
+			.elapsed()
+			.try_into()
+			.unwrap_or(Duration {
+				seconds: i64::MAX,
+				nanos: 999_999_999,
+			})
+	}
+
+	/// Returns the resolution of the coarse clock, which is the maximum amount by which it can lag behind the precise one.
+	///
+	/// It returns a duration of one nanosecond when the clock falls back to the precise clocks of the standard library.
+	#[must_use]
+	#[inline]
+	pub fn resolution() -> Duration {
+		#[cfg(any(target_os = "linux", target_os = "android"))]
+		if let Some((seconds, nanos)) = coarse::resolution(libc::CLOCK_REALTIME_COARSE) {
+			return Duration { seconds, nanos };
+		}
+
+		Duration::new(0, 1)
+	}
+}
+
+#[cfg(feature = "std")]
+impl Clock for CoarseClock {
+	#[inline]
+	fn now(&self) -> Timestamp {
+		#[cfg(any(target_os = "linux", target_os = "android"))]
+		if let Some((seconds, nanos)) = coarse::read(libc::CLOCK_REALTIME_COARSE) {
+			return Timestamp { seconds, nanos };
+		}
+
+		Timestamp::now()
+	}
+}
+
+#[cfg(feature = "std")]
+impl Timestamp {
+	/// Returns the current timestamp read from the [`CoarseClock`], which is cheaper than [`Timestamp::now`] but can lag behind it by up to [`CoarseClock::resolution`].
+	#[must_use]
+	#[inline]
+	pub fn now_coarse() -> Self {
+		CoarseClock.now()
+	}
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn coarse_clock_is_close_to_the_system_clock() {
+		let before = SystemClock.now();
+		let coarse = Timestamp::now_coarse();
+		let after = SystemClock.now();
+
+		let tolerance = CoarseClock::resolution().total_nanos() + 50_000_000;
+
+		assert!(
+			coarse.total_nanos() >= before.total_nanos() - tolerance,
+			"{coarse} < {before}"
+		);
+		assert!(
+			coarse.total_nanos() <= after.total_nanos() + tolerance,
+			"{coarse} > {after}"
+		);
+		assert!(CoarseClock::resolution() > Duration::default());
+	}
+
+	#[test]
+	fn monotonic_clock_never_goes_backwards() {
+		let mut last = CoarseClock::monotonic();
+
+		for _ in 0..1_000 {
+			let next = CoarseClock::monotonic();
+			assert!(next >= last);
+			last = next;
+		}
+	}
+
+	#[test]
+	fn clocks_are_interchangeable() {
+		fn read(clock: &dyn Clock) -> Timestamp {
+			clock.now()
+		}
+
+		assert!(read(&CoarseClock) > Timestamp::default());
+		assert!(read(&&SystemClock) > Timestamp::default());
+	}
+}
@@ -0,0 +1,405 @@
+use thiserror::Error;
+
+use crate::{Date, DayOfWeek, Duration, Interval, TimeOfDay, Timestamp, Vec};
+
+const NANOS_PER_SECOND: i128 = 1_000_000_000;
+const NANOS_PER_DAY: i128 = 86_400 * NANOS_PER_SECOND;
+
+/// Errors that can occur during the creation of a [`BusinessCalendar`].
+#[derive(Debug, Error, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum BusinessCalendarError {
+	#[error(
+		"Business hours must be valid times of day, with the opening time before the closing time"
+	)]
+	InvalidWorkingHours,
+	#[error("Holidays must be full dates with a year, a month and a day")]
+	InvalidHoliday,
+	#[error("The UTC offset of a business calendar must be shorter than a day")]
+	InvalidUtcOffset,
+}
+
+/// A calendar of business hours, for clocks that only tick during working time (such as SLA deadlines).
+///
+/// A business day goes from `opens_at` (inclusive) to `closes_at` (exclusive), on the working days of the week that are not holidays.
+/// The business hours are expressed in local time, at a fixed offset from UTC.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BusinessCalendar {
+	/// The working days, as a bitmask where bit `n` is the ISO weekday `n` (1 for Monday, 7 for Sunday).
+	working_days: u8,
+	opens_at: TimeOfDay,
+	closes_at: TimeOfDay,
+	/// The holidays, as sorted and deduplicated numbers of days since the unix epoch.
+	holidays: Vec<i64>,
+	utc_offset: Duration,
+}
+
+impl BusinessCalendar {
+	/// Creates a new calendar with the given business hours, from Monday to Friday, without holidays and in UTC.
+	pub fn new(opens_at: TimeOfDay, closes_at: TimeOfDay) -> Result<Self, BusinessCalendarError> {
+		if !opens_at.is_valid() || !closes_at.is_valid() || opens_at >= closes_at {
+			return Err(BusinessCalendarError::InvalidWorkingHours);
+		}
+
+		Ok(Self {
+			working_days: 0b0011_1110,
+			opens_at,
+			closes_at,
+			holidays: Vec::new(),
+			utc_offset: Duration::default(),
+		})
+	}
+
+	/// Replaces the working days of the week. Unspecified days are ignored.
+	#[must_use]
+	pub fn with_working_days(mut self, days: impl IntoIterator<Item = DayOfWeek>) -> Self {
+		self.working_days = days
+			.into_iter()
+			.filter(|day| !day.is_unspecified())
+			.fold(0, |mask, day| mask | (1 << day as u8));
+		self
+	}
+
+	/// Adds the given dates to the holidays, on which no working time is counted.
+	pub fn with_holidays(
+		mut self,
+		holidays: impl IntoIterator<Item = Date>,
+	) -> Result<Self, BusinessCalendarError> {
+		for date in holidays {
+			let day = day_number(&date).ok_or(BusinessCalendarError::InvalidHoliday)?;
+			self.holidays.push(day);
+		}
+
+		self.holidays.sort_unstable();
+		self.holidays.dedup();
+
+		Ok(self)
+	}
+
+	/// Sets the offset from UTC of the local time in which the business hours are expressed (e.g. `+01:00` for Central European Time).
+	pub fn with_utc_offset(mut self, offset: Duration) -> Result<Self, BusinessCalendarError> {
+		if offset.total_nanos().abs() >= NANOS_PER_DAY {
+			return Err(BusinessCalendarError::InvalidUtcOffset);
+		}
+
+		self.utc_offset = offset.normalized();
+		Ok(self)
+	}
+
+	/// Returns the time at which business days start.
+	#[must_use]
+	#[inline]
+	pub const fn opens_at(&self) -> TimeOfDay {
+		self.opens_at
+	}
+
+	/// Returns the time at which business days end.
+	#[must_use]
+	#[inline]
+	pub const fn closes_at(&self) -> TimeOfDay {
+		self.closes_at
+	}
+
+	/// Returns the offset from UTC of the business hours.
+	#[must_use]
+	#[inline]
+	pub const fn utc_offset(&self) -> Duration {
+		self.utc_offset
+	}
+
+	/// Checks if the given date is a working day that is not a holiday. Returns false for dates without a year, a month or a day.
+	#[must_use]
+	pub fn is_working_day(&self, date: &Date) -> bool {
+		day_number(date).is_some_and(|day| self.is_working_day_number(day))
+	}
+
+	/// Returns the amount of business time within the interval.
+	///
+	/// Intervals without a start or an end time cannot be measured, and contain no business time.
+	#[must_use]
+	pub fn working_duration_within(&self, interval: &Interval) -> Duration {
+		let (Some(start), Some(end)) = (interval.start_time, interval.end_time) else {
+			return Duration::default();
+		};
+
+		let (start, end) = (self.local_nanos(&start), self.local_nanos(&end));
+
+		if end <= start {
+			return Duration::default();
+		}
+
+		let (first_day, last_day) = (day_of(start), day_of(end));
+
+		let total = if first_day == last_day {
+			self.overlap(first_day, start, end)
+		} else {
+			self.overlap(first_day, start, end)
+				+ self.overlap(last_day, start, end)
+				+ i128::from(self.working_days_between(first_day + 1, last_day))
+					* self.daily_nanos()
+		};
+
+		Duration::from_total_nanos(total).unwrap_or(Duration {
+			seconds: i64::MAX,
+			nanos: 999_999_999,
+		})
+	}
+
+	/// Returns the first instant, starting from `at`, that falls within business hours.
+	///
+	/// If `at` is already within business hours, it is returned as is (normalized).
+	/// Returns `None` if the calendar has no working days.
+	#[must_use]
+	pub fn next_working_instant(&self, at: Timestamp) -> Option<Timestamp> {
+		if self.working_days == 0 {
+			return None;
+		}
+
+		let now = self.local_nanos(&at);
+		let mut day = day_of(now);
+
+		if self.is_working_day_number(day) {
+			let time = now - i128::from(day) * NANOS_PER_DAY;
+
+			if time < self.open_nanos() {
+				return self.timestamp_at(day, self.open_nanos());
+			} else if time < self.close_nanos() {
+				return Some(at.normalized());
+			}
+		}
+
+		// Every week has at least one working day, and each holiday can take away at most one of them
+		for _ in 0..7 * (self.holidays.len() + 1) {
+			day += 1;
+
+			if self.is_working_day_number(day) {
+				return self.timestamp_at(day, self.open_nanos());
+			}
+		}
+
+		None
+	}
+
+	const fn open_nanos(&self) -> i128 {
+		self.opens_at.nanos_since_midnight() as i128
+	}
+
+	const fn close_nanos(&self) -> i128 {
+		self.closes_at.nanos_since_midnight() as i128
+	}
+
+	const fn daily_nanos(&self) -> i128 {
+		self.close_nanos() - self.open_nanos()
+	}
+
+	/// Returns the nanoseconds since the unix epoch in local time.
+	fn local_nanos(&self, timestamp: &Timestamp) -> i128 {
+		i128::from(timestamp.seconds) * NANOS_PER_SECOND
+			+ i128::from(timestamp.nanos)
+			+ self.utc_offset.total_nanos()
+	}
+
+	/// Returns the timestamp for the given local time of the day.
+	fn timestamp_at(&self, day: i64, time_nanos: i128) -> Option<Timestamp> {
+		let utc = i128::from(day) * NANOS_PER_DAY + time_nanos - self.utc_offset.total_nanos();
+
+		// SAFETY: The euclidean remainder is always within 0..NANOS_PER_SECOND
+		#[allow(clippy::cast_possible_truncation)]
+		let nanos = utc.rem_euclid(NANOS_PER_SECOND) as i32;
+
+		Some(Timestamp {
+			seconds: i64::try_from(utc.div_euclid(NANOS_PER_SECOND)).ok()?,
+			nanos,
+		})
+	}
+
+	const fn is_working_weekday(&self, day: i64) -> bool {
+		self.working_days & (1 << iso_weekday(day)) != 0
+	}
+
+	fn is_working_day_number(&self, day: i64) -> bool {
+		self.is_working_weekday(day) && self.holidays.binary_search(&day).is_err()
+	}
+
+	/// Returns the business time of the given day that falls between `start` and `end`.
+	fn overlap(&self, day: i64, start: i128, end: i128) -> i128 {
+		if !self.is_working_day_number(day) {
+			return 0;
+		}
+
+		let day_start = i128::from(day) * NANOS_PER_DAY;
+		let from = start.max(day_start + self.open_nanos());
+		let to = end.min(day_start + self.close_nanos());
+
+		(to - from).max(0)
+	}
+
+	/// Returns the number of working days in the range `start..end`.
+	fn working_days_between(&self, start: i64, end: i64) -> i64 {
+		if end <= start {
+			return 0;
+		}
+
+		let full_weeks = (end - start) / 7;
+		let mut count = full_weeks * i64::from(self.working_days.count_ones());
+
+		for day in (start + full_weeks * 7)..end {
+			if self.is_working_weekday(day) {
+				count += 1;
+			}
+		}
+
+		let first = self.holidays.partition_point(|h| *h < start);
+		let last = self.holidays.partition_point(|h| *h < end);
+
+		// SAFETY: The number of holidays is always far below i64::MAX
+		#[allow(clippy::cast_possible_wrap)]
+		let holidays = self.holidays[first..last]
+			.iter()
+			.filter(|day| self.is_working_weekday(**day))
+			.count() as i64;
+
+		count - holidays
+	}
+}
+
+/// Returns the number of the day (since the unix epoch) that contains the given local time.
+const fn day_of(local_nanos: i128) -> i64 {
+	// SAFETY: Timestamps in nanoseconds divided by the nanoseconds in a day always fit in an i64
+	#[allow(clippy::cast_possible_truncation)]
+	let day = local_nanos.div_euclid(NANOS_PER_DAY) as i64;
+	day
+}
+
+/// Returns the ISO weekday of the given day since the unix epoch, from 1 (Monday) to 7 (Sunday).
+const fn iso_weekday(day: i64) -> u8 {
+	// The unix epoch was a Thursday
+	// SAFETY: The euclidean remainder is always within 0..7
+	#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+	let weekday = (day + 3).rem_euclid(7) as u8;
+
+	weekday + 1
+}
+
+fn day_number(date: &Date) -> Option<i64> {
+	let timestamp = Timestamp::date(
+		i64::from(date.year),
+		u8::try_from(date.month).ok()?,
+		u8::try_from(date.day).ok()?,
+	)
+	.ok()?;
+
+	Some(timestamp.seconds.div_euclid(86_400))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn ts(s: &str) -> Timestamp {
+		s.parse().unwrap()
+	}
+
+	fn calendar() -> BusinessCalendar {
+		BusinessCalendar::new(
+			TimeOfDay::new(9, 0, 0, 0).unwrap(),
+			TimeOfDay::new(17, 0, 0, 0).unwrap(),
+		)
+		.unwrap()
+	}
+
+	#[test]
+	fn working_duration() {
+		let calendar = calendar();
+
+		// From Friday at 16:00 to Monday at 10:00
+		let weekend = Interval::new(
+			Some(ts("2024-05-03T16:00:00Z")),
+			Some(ts("2024-05-06T10:00:00Z")),
+		)
+		.unwrap();
+		assert_eq!(
+			calendar.working_duration_within(&weekend),
+			Duration::new(7_200, 0)
+		);
+
+		// Two full weeks, from Monday at midnight
+		let weeks = Interval::new(
+			Some(ts("2024-05-06T00:00:00Z")),
+			Some(ts("2024-05-20T00:00:00Z")),
+		)
+		.unwrap();
+		assert_eq!(
+			calendar.working_duration_within(&weeks),
+			Duration::new(10 * 8 * 3_600, 0)
+		);
+
+		let with_holiday = calendar
+			.clone()
+			.with_holidays([Date::new(2024, 5, 8).unwrap()])
+			.unwrap();
+		assert_eq!(
+			with_holiday.working_duration_within(&weeks),
+			Duration::new(9 * 8 * 3_600, 0)
+		);
+
+		let within_day = Interval::new(
+			Some(ts("2024-05-06T08:00:00Z")),
+			Some(ts("2024-05-06T09:30:00Z")),
+		)
+		.unwrap();
+		assert_eq!(
+			calendar.working_duration_within(&within_day),
+			Duration::new(1_800, 0)
+		);
+
+		assert_eq!(
+			calendar.working_duration_within(&Interval::default()),
+			Duration::default()
+		);
+	}
+
+	#[test]
+	fn next_working_instant() {
+		let calendar = calendar()
+			.with_holidays([Date::new(2024, 5, 6).unwrap()])
+			.unwrap();
+
+		// Friday evening to Tuesday morning, skipping the weekend and the holiday
+		assert_eq!(
+			calendar.next_working_instant(ts("2024-05-03T18:00:00Z")),
+			Some(ts("2024-05-07T09:00:00Z"))
+		);
+		assert_eq!(
+			calendar.next_working_instant(ts("2024-05-07T08:00:00Z")),
+			Some(ts("2024-05-07T09:00:00Z"))
+		);
+		assert_eq!(
+			calendar.next_working_instant(ts("2024-05-07T12:00:00Z")),
+			Some(ts("2024-05-07T12:00:00Z"))
+		);
+
+		let never = calendar.with_working_days([]);
+		assert_eq!(never.next_working_instant(ts("2024-05-07T12:00:00Z")), None);
+	}
+
+	#[test]
+	fn utc_offset() {
+		let calendar = calendar()
+			.with_utc_offset(Duration::new(2 * 3_600, 0))
+			.unwrap();
+
+		// The business day opens at 07:00 UTC
+		assert_eq!(
+			calendar.next_working_instant(ts("2024-05-07T06:00:00Z")),
+			Some(ts("2024-05-07T07:00:00Z"))
+		);
+		assert!(calendar.is_working_day(&Date::new(2024, 5, 7).unwrap()));
+		assert!(!calendar.is_working_day(&Date::new(2024, 5, 11).unwrap()));
+
+		assert_eq!(
+			calendar.with_utc_offset(Duration::new(86_400, 0)),
+			Err(BusinessCalendarError::InvalidUtcOffset)
+		);
+	}
+}
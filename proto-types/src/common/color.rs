@@ -1,3 +1,5 @@
+#[cfg(not(feature = "std"))]
+use num_traits::float::FloatCore;
 use thiserror::Error;
 
 use crate::common::Color;
@@ -1,11 +1,11 @@
 use serde::{
 	Deserialize, Deserializer, Serialize,
-	de::{self, MapAccess, Visitor},
-	ser::{SerializeMap, Serializer},
+	de::{self, Visitor},
+	ser::Serializer,
 };
 
 use crate::{
-	DayOfWeek, String, Vec,
+	DayOfWeek,
 	common::{CalendarPeriod, Month},
 	format,
 };
@@ -46,130 +46,308 @@ impl<'de> Deserialize<'de> for CalendarPeriod {
 	}
 }
 
+/// Implements the proto3 JSON mapping for [`DateTime`](crate::DateTime).
+///
+/// Fields are serialized in camelCase and omitted when they have their default value,
+/// the UTC offset is serialized as a duration string (e.g. `"-14400s"`) and the time zone as an object with `id` and an optional `version`.
 #[cfg(feature = "datetime")]
-impl Serialize for crate::DateTime {
-	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-	where
-		S: Serializer,
-	{
-		let mut map = serializer.serialize_map(None)?;
-
-		map.serialize_entry("year", &self.year)?;
-		map.serialize_entry("month", &self.month)?;
-		map.serialize_entry("day", &self.day)?;
-		map.serialize_entry("hours", &self.hours)?;
-		map.serialize_entry("minutes", &self.minutes)?;
-		map.serialize_entry("seconds", &self.seconds)?;
-		map.serialize_entry("nanos", &self.nanos)?;
-
-		match self.time_offset {
-			Some(crate::date_time::TimeOffset::UtcOffset(ref d)) => {
-				map.serialize_entry("utcOffset", d)?;
-			}
-			Some(crate::date_time::TimeOffset::TimeZone(ref tz)) => {
-				map.serialize_entry("timeZone", tz)?;
-			}
-			None => {
-				// No time_offset, just omit the field as per proto3 JSON spec
+mod datetime_json {
+	use serde::{de::MapAccess, ser::SerializeMap};
+
+	use super::*;
+	use crate::{DateTime, Duration, String, TimeZone, date_time::TimeOffset};
+
+	/// Serializes a [`TimeZone`], omitting the `version` if it is empty.
+	struct TimeZoneJson<'a>(&'a TimeZone);
+
+	impl Serialize for TimeZoneJson<'_> {
+		fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+		where
+			S: Serializer,
+		{
+			let mut map = serializer.serialize_map(None)?;
+
+			map.serialize_entry("id", &self.0.id)?;
+			if !self.0.version.is_empty() {
+				map.serialize_entry("version", &self.0.version)?;
 			}
+
+			map.end()
 		}
+	}
 
-		map.end()
+	#[derive(Deserialize)]
+	#[serde(deny_unknown_fields)]
+	struct StrictTimeZone {
+		#[serde(default)]
+		id: String,
+		#[serde(default)]
+		version: String,
 	}
-}
 
-#[cfg(feature = "datetime")]
-impl<'de> Deserialize<'de> for crate::DateTime {
-	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-	where
-		D: Deserializer<'de>,
-	{
-		struct DateTimeVisitor;
+	impl From<StrictTimeZone> for TimeOffset {
+		fn from(value: StrictTimeZone) -> Self {
+			Self::TimeZone(TimeZone {
+				id: value.id,
+				version: value.version,
+			})
+		}
+	}
 
-		impl<'de> Visitor<'de> for DateTimeVisitor {
-			type Value = crate::DateTime;
+	#[derive(Deserialize)]
+	struct LenientTimeZone {
+		#[serde(default)]
+		id: String,
+		#[serde(default)]
+		version: String,
+	}
 
-			fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
-				formatter.write_str("a JSON object representing a DateTime")
+	impl From<LenientTimeZone> for TimeOffset {
+		fn from(value: LenientTimeZone) -> Self {
+			Self::TimeZone(TimeZone {
+				id: value.id,
+				version: value.version,
+			})
+		}
+	}
+
+	impl Serialize for DateTime {
+		fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+		where
+			S: Serializer,
+		{
+			let mut map = serializer.serialize_map(None)?;
+
+			for (key, value) in [
+				("year", self.year),
+				("month", self.month),
+				("day", self.day),
+				("hours", self.hours),
+				("minutes", self.minutes),
+				("seconds", self.seconds),
+				("nanos", self.nanos),
+			] {
+				if value != 0 {
+					map.serialize_entry(key, &value)?;
+				}
 			}
 
-			fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
-			where
-				A: MapAccess<'de>,
-			{
-				let mut year = None;
-				let mut month = None;
-				let mut day = None;
-				let mut hours = None;
-				let mut minutes = None;
-				let mut seconds = None;
-				let mut nanos = None;
-				let mut utc_offset = None;
-				let mut time_zone = None;
-
-				while let Some(key) = map.next_key::<String>()? {
-					match key.as_str() {
-						"year" => year = Some(map.next_value()?),
-						"month" => month = Some(map.next_value()?),
-						"day" => day = Some(map.next_value()?),
-						"hours" => hours = Some(map.next_value()?),
-						"minutes" => minutes = Some(map.next_value()?),
-						"seconds" => seconds = Some(map.next_value()?),
-						"nanos" => nanos = Some(map.next_value()?),
-						"utcOffset" => {
-							if time_zone.is_some() {
-								return Err(de::Error::custom(
-									"found 'utcOffset' and 'timeZone', expected only one",
-								));
-							}
-							utc_offset = Some(map.next_value()?);
-						}
-						"timeZone" => {
-							if utc_offset.is_some() {
+			match &self.time_offset {
+				Some(TimeOffset::UtcOffset(duration)) => {
+					map.serialize_entry("utcOffset", duration)?;
+				}
+				Some(TimeOffset::TimeZone(tz)) => {
+					map.serialize_entry("timeZone", &TimeZoneJson(tz))?;
+				}
+				None => {
+					// No time_offset, just omit the field as per proto3 JSON spec
+				}
+			}
+
+			map.end()
+		}
+	}
+
+	pub(crate) struct DateTimeVisitor {
+		/// Rejects unknown fields and nested time zone fields instead of ignoring them.
+		pub(crate) strict: bool,
+	}
+
+	impl<'de> Visitor<'de> for DateTimeVisitor {
+		type Value = DateTime;
+
+		fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+			formatter.write_str("a JSON object representing a DateTime")
+		}
+
+		fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+		where
+			A: MapAccess<'de>,
+		{
+			let mut datetime = DateTime::default();
+
+			while let Some(key) = map.next_key::<String>()? {
+				let field = match key.as_str() {
+					"year" => &mut datetime.year,
+					"month" => &mut datetime.month,
+					"day" => &mut datetime.day,
+					"hours" => &mut datetime.hours,
+					"minutes" => &mut datetime.minutes,
+					"seconds" => &mut datetime.seconds,
+					"nanos" => &mut datetime.nanos,
+					"utcOffset" | "utc_offset" | "timeZone" | "time_zone" => {
+						let offset = if key.starts_with("utc") {
+							map.next_value::<Option<Duration>>()?
+								.map(TimeOffset::UtcOffset)
+						} else if self.strict {
+							map.next_value::<Option<StrictTimeZone>>()?
+								.map(TimeOffset::from)
+						} else {
+							map.next_value::<Option<LenientTimeZone>>()?
+								.map(TimeOffset::from)
+						};
+
+						if offset.is_some() {
+							if datetime.time_offset.is_some() {
 								return Err(de::Error::custom(
 									"found 'utcOffset' and 'timeZone', expected only one",
 								));
 							}
-							time_zone = Some(map.next_value()?);
+							datetime.time_offset = offset;
 						}
-						_ => {
-							let _ = map.next_value::<serde_json::Value>()?;
-						}
-					}
-				}
 
-				let time_offset = match (utc_offset, time_zone) {
-					(Some(d), None) => Some(crate::date_time::TimeOffset::UtcOffset(d)),
-					(None, Some(tz)) => Some(crate::date_time::TimeOffset::TimeZone(tz)),
-					(None, None) => None,
+						continue;
+					}
+					_ if self.strict => {
+						return Err(de::Error::unknown_field(&key, FIELDS));
+					}
 					_ => {
-						return Err(de::Error::custom(
-							"found 'utcOffset' and 'timeZone', expected only one",
-						));
+						let _ = map.next_value::<de::IgnoredAny>()?;
+						continue;
 					}
 				};
 
-				let month_val = month.ok_or_else(|| de::Error::missing_field("month"))?;
-				let day_val = day.ok_or_else(|| de::Error::missing_field("day"))?;
-				let hours_val = hours.ok_or_else(|| de::Error::missing_field("hours"))?;
-				let minutes_val = minutes.ok_or_else(|| de::Error::missing_field("minutes"))?;
-				let seconds_val = seconds.ok_or_else(|| de::Error::missing_field("seconds"))?;
-				let nanos_val = nanos.ok_or_else(|| de::Error::missing_field("nanos"))?;
-
-				Ok(crate::DateTime {
-					year: year.unwrap_or(0), // Year is optional, default 0
-					month: month_val,
-					day: day_val,
-					hours: hours_val,
-					minutes: minutes_val,
-					seconds: seconds_val,
-					nanos: nanos_val,
-					time_offset,
-				})
+				// Null is equivalent to the default value in the proto3 JSON mapping
+				*field = map
+					.next_value::<Option<i32>>()?
+					.unwrap_or_default();
+			}
+
+			Ok(datetime)
+		}
+	}
+
+	const FIELDS: &[&str] = &[
+		"year",
+		"month",
+		"day",
+		"hours",
+		"minutes",
+		"seconds",
+		"nanos",
+		"utcOffset",
+		"timeZone",
+	];
+
+	impl<'de> Deserialize<'de> for DateTime {
+		fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+		where
+			D: Deserializer<'de>,
+		{
+			deserializer.deserialize_map(DateTimeVisitor { strict: false })
+		}
+	}
+}
+
+#[cfg(feature = "datetime")]
+pub(crate) use datetime_json::DateTimeVisitor;
+
+/// Serializes an [`Interval`](crate::Interval) as `{ "startTime", "endTime" }` RFC 3339 strings, omitting missing bounds.
+#[cfg(all(feature = "interval", feature = "canonical-serde"))]
+mod interval_json {
+	use serde::{de::MapAccess, ser::SerializeMap};
+
+	use super::*;
+	use crate::{Interval, Timestamp};
+
+	impl Serialize for Interval {
+		fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+		where
+			S: Serializer,
+		{
+			let mut map = serializer.serialize_map(None)?;
+
+			if let Some(start) = &self.start_time {
+				map.serialize_entry("startTime", start)?;
+			}
+			if let Some(end) = &self.end_time {
+				map.serialize_entry("endTime", end)?;
+			}
+
+			map.end()
+		}
+	}
+
+	/// The keys of the Interval object, matched without allocating a String for each key.
+	enum IntervalField {
+		StartTime,
+		EndTime,
+		Other,
+	}
+
+	impl<'de> Deserialize<'de> for IntervalField {
+		fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+		where
+			D: Deserializer<'de>,
+		{
+			struct FieldVisitor;
+
+			impl Visitor<'_> for FieldVisitor {
+				type Value = IntervalField;
+
+				fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+					formatter.write_str("a field name")
+				}
+
+				fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+				where
+					E: de::Error,
+				{
+					Ok(match value {
+						"startTime" | "start_time" => IntervalField::StartTime,
+						"endTime" | "end_time" => IntervalField::EndTime,
+						_ => IntervalField::Other,
+					})
+				}
 			}
+
+			deserializer.deserialize_identifier(FieldVisitor)
 		}
+	}
+
+	impl<'de> Deserialize<'de> for Interval {
+		fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+		where
+			D: Deserializer<'de>,
+		{
+			struct IntervalVisitor;
+
+			impl<'de> Visitor<'de> for IntervalVisitor {
+				type Value = Interval;
+
+				fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+					formatter.write_str(
+						"a JSON object with optional 'startTime' and 'endTime' timestamps",
+					)
+				}
+
+				fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+				where
+					A: MapAccess<'de>,
+				{
+					let mut start_time = None;
+					let mut end_time = None;
+
+					while let Some(key) = map.next_key::<IntervalField>()? {
+						match key {
+							IntervalField::StartTime => {
+								start_time = map.next_value::<Option<Timestamp>>()?;
+							}
+							IntervalField::EndTime => {
+								end_time = map.next_value::<Option<Timestamp>>()?;
+							}
+							IntervalField::Other => {
+								let _ = map.next_value::<de::IgnoredAny>()?;
+							}
+						}
+					}
+
+					Interval::new(start_time, end_time).map_err(de::Error::custom)
+				}
+			}
 
-		deserializer.deserialize_map(DateTimeVisitor)
+			deserializer.deserialize_map(IntervalVisitor)
+		}
 	}
 }
 
@@ -179,8 +357,10 @@ impl Serialize for crate::Date {
 	where
 		S: Serializer,
 	{
-		let date_string = format!("{:04}-{:02}-{:02}", self.year, self.month, self.day);
-		serializer.serialize_str(&date_string)
+		serializer.collect_str(&format_args!(
+			"{:04}-{:02}-{:02}",
+			self.year, self.month, self.day
+		))
 	}
 }
 
@@ -203,17 +383,19 @@ impl<'de> Deserialize<'de> for crate::Date {
 			where
 				E: de::Error,
 			{
-				let parts: Vec<&str> = v.split('-').collect();
-
-				if parts.len() != 3 {
-					return Err(E::custom(format!(
-						"invalid date format: {v}, expected YYYY-MM-DD"
-					)));
-				}
+				let invalid_format =
+					|| E::custom(format!("invalid date format: {v}, expected YYYY-MM-DD"));
+
+				let mut parts = v.split('-');
+				let (Some(year), Some(month), Some(day), None) =
+					(parts.next(), parts.next(), parts.next(), parts.next())
+				else {
+					return Err(invalid_format());
+				};
 
-				let year = parts[0].parse::<i32>().map_err(E::custom)?;
-				let month = parts[1].parse::<i32>().map_err(E::custom)?;
-				let day = parts[2].parse::<i32>().map_err(E::custom)?;
+				let year = year.parse::<i32>().map_err(E::custom)?;
+				let month = month.parse::<i32>().map_err(E::custom)?;
+				let day = day.parse::<i32>().map_err(E::custom)?;
 
 				if !(1..=12).contains(&month) {
 					return Err(E::custom(format!("invalid month: {month}")));
@@ -303,3 +485,111 @@ impl<'de> Deserialize<'de> for Month {
 		deserializer.deserialize_str(MonthVisitor)
 	}
 }
+
+#[cfg(all(test, feature = "datetime"))]
+mod tests {
+	use ::serde::Deserialize;
+	use serde_json::json;
+
+	#[cfg(all(feature = "interval", feature = "canonical-serde"))]
+	#[test]
+	fn interval_canonical_json() {
+		use crate::{Interval, Timestamp};
+
+		let start: Timestamp = "2024-05-01T10:00:00Z".parse().unwrap();
+		let end: Timestamp = "2024-05-01T12:30:00.5Z".parse().unwrap();
+
+		let interval = Interval::new(Some(start), Some(end)).unwrap();
+		let value = serde_json::to_value(interval).unwrap();
+		assert_eq!(
+			value,
+			json!({ "startTime": "2024-05-01T10:00:00Z", "endTime": "2024-05-01T12:30:00.5Z" })
+		);
+		assert_eq!(Interval::deserialize(value).unwrap(), interval);
+
+		let open_ended = Interval::new(Some(start), None).unwrap();
+		assert_eq!(
+			serde_json::to_value(open_ended).unwrap(),
+			json!({ "startTime": "2024-05-01T10:00:00Z" })
+		);
+		assert_eq!(
+			Interval::deserialize(json!({ "start_time": "2024-05-01T10:00:00Z", "endTime": null }))
+				.unwrap(),
+			open_ended
+		);
+		assert_eq!(
+			Interval::deserialize(json!({})).unwrap(),
+			Interval::default()
+		);
+
+		assert!(
+			Interval::deserialize(
+				json!({ "startTime": "2024-05-01T12:30:00Z", "endTime": "2024-05-01T10:00:00Z" })
+			)
+			.is_err()
+		);
+	}
+
+	use crate::{DateTime, Duration, String, TimeZone, ToString, date_time::TimeOffset};
+
+	#[test]
+	fn datetime_proto_json() {
+		let datetime = DateTime {
+			year: 2024,
+			month: 5,
+			day: 1,
+			hours: 10,
+			time_offset: Some(TimeOffset::UtcOffset(Duration::new(-14400, 0))),
+			..Default::default()
+		};
+
+		let value = serde_json::to_value(&datetime).unwrap();
+		assert_eq!(
+			value,
+			json!({ "year": 2024, "month": 5, "day": 1, "hours": 10, "utcOffset": "-14400s" })
+		);
+		assert_eq!(DateTime::deserialize(value).unwrap(), datetime);
+
+		let with_zone = DateTime {
+			month: 12,
+			day: 25,
+			time_offset: Some(TimeOffset::TimeZone(TimeZone {
+				id: "America/New_York".to_string(),
+				version: String::new(),
+			})),
+			..Default::default()
+		};
+		let value = serde_json::to_value(&with_zone).unwrap();
+		assert_eq!(
+			value,
+			json!({ "month": 12, "day": 25, "timeZone": { "id": "America/New_York" } })
+		);
+		assert_eq!(DateTime::deserialize(value).unwrap(), with_zone);
+	}
+
+	#[test]
+	fn datetime_accepts_other_encoders() {
+		let datetime = DateTime::deserialize(json!({
+			"month": 12,
+			"day": 25,
+			"minutes": null,
+			"time_zone": { "id": "Europe/Rome", "version": "2024a" },
+			"unknown": [1, 2, 3]
+		}))
+		.unwrap();
+
+		assert_eq!(datetime.minutes, 0);
+		assert_eq!(
+			datetime.time_offset,
+			Some(TimeOffset::TimeZone(TimeZone {
+				id: "Europe/Rome".to_string(),
+				version: "2024a".to_string(),
+			}))
+		);
+
+		assert!(
+			DateTime::deserialize(json!({ "utcOffset": "3600s", "timeZone": { "id": "UTC" } }))
+				.is_err()
+		);
+	}
+}
@@ -0,0 +1,92 @@
+//! A small registry of ISO 4217 currencies, with their symbols and minor units.
+
+/// Information about an ISO 4217 currency.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Currency {
+	/// The three-letter ISO 4217 code, such as `USD`.
+	pub code: &'static str,
+	/// The symbol commonly used for this currency, such as `$`.
+	pub symbol: &'static str,
+	/// The number of digits after the decimal separator in the smallest unit of this currency (e.g. 2 for cents).
+	pub minor_units: u32,
+}
+
+macro_rules! currencies {
+	($($code:literal => $symbol:literal, $minor_units:literal),* $(,)?) => {
+		/// The currencies known to the registry.
+		///
+		/// When the same symbol is used by several currencies, the first one in this list is used for lookups by symbol.
+		pub const CURRENCIES: &[Currency] = &[
+			$(Currency {
+				code: $code,
+				symbol: $symbol,
+				minor_units: $minor_units,
+			}),*
+		];
+	};
+}
+
+currencies! {
+	"USD" => "$", 2,
+	"EUR" => "€", 2,
+	"GBP" => "£", 2,
+	"JPY" => "¥", 0,
+	"CNY" => "CN¥", 2,
+	"CHF" => "CHF", 2,
+	"CAD" => "CA$", 2,
+	"AUD" => "A$", 2,
+	"NZD" => "NZ$", 2,
+	"HKD" => "HK$", 2,
+	"SGD" => "S$", 2,
+	"MXN" => "MX$", 2,
+	"BRL" => "R$", 2,
+	"INR" => "₹", 2,
+	"KRW" => "₩", 0,
+	"RUB" => "₽", 2,
+	"TRY" => "₺", 2,
+	"ILS" => "₪", 2,
+	"PLN" => "zł", 2,
+	"CZK" => "Kč", 2,
+	"SEK" => "kr", 2,
+	"NOK" => "NOK", 2,
+	"DKK" => "DKK", 2,
+	"ZAR" => "R", 2,
+	"BHD" => "BHD", 3,
+	"KWD" => "KWD", 3,
+	"CLP" => "CLP", 0,
+}
+
+impl Currency {
+	/// Returns the currency with the given ISO 4217 code (case-insensitive), if it is known.
+	#[must_use]
+	pub fn from_code(code: &str) -> Option<&'static Self> {
+		CURRENCIES
+			.iter()
+			.find(|c| c.code.eq_ignore_ascii_case(code))
+	}
+
+	/// Returns the currency that uses the given symbol, if it is known.
+	///
+	/// ISO codes are accepted as well, so that amounts such as `CHF 10` can be resolved.
+	#[must_use]
+	pub fn from_symbol(symbol: &str) -> Option<&'static Self> {
+		CURRENCIES
+			.iter()
+			.find(|c| c.symbol == symbol)
+			.or_else(|| Self::from_code(symbol))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn lookups() {
+		assert_eq!(Currency::from_code("eur").unwrap().symbol, "€");
+		assert_eq!(Currency::from_symbol("$").unwrap().code, "USD");
+		assert_eq!(Currency::from_symbol("CHF").unwrap().code, "CHF");
+		assert_eq!(Currency::from_symbol("JPY").unwrap().minor_units, 0);
+		assert_eq!(Currency::from_symbol("?"), None);
+	}
+}
@@ -5,7 +5,7 @@ use core::{
 
 use thiserror::Error;
 
-use crate::{String, ToString, common::Date};
+use crate::{ProtoValidate, String, ToString, calendar, common::Date};
 
 /// Errors that can occur during the creation, conversion or validation of a [`Date`].
 #[derive(Debug, Error, PartialEq, Eq, Clone)]
@@ -46,6 +46,31 @@ pub enum DateKind {
 }
 
 impl Date {
+	/// The earliest full date that can be represented, `0001-01-01`.
+	pub const MIN: Self = Self {
+		year: 1,
+		month: 1,
+		day: 1,
+	};
+
+	/// The latest full date that can be represented, `9999-12-31`.
+	pub const MAX: Self = Self {
+		year: 9999,
+		month: 12,
+		day: 31,
+	};
+
+	/// Returns the date of the Unix epoch, `1970-01-01`.
+	#[must_use]
+	#[inline]
+	pub const fn epoch() -> Self {
+		Self {
+			year: 1970,
+			month: 1,
+			day: 1,
+		}
+	}
+
 	/// Creates a new [`Date`] instance with validation.
 	/// Allows `year: 0`, `month: 0`, `day: 0` as special cases described in the proto spec.
 	/// Returns an error if any component is out of range or date is invalid (e.g., February 30th).
@@ -102,6 +127,160 @@ impl Date {
 	pub const fn is_month_and_day(&self) -> bool {
 		self.year == 0 && self.month != 0 && self.day != 0
 	}
+
+	/// Returns the quarter of the year (from 1 to 4) that this date falls in, or `None` if the month is not set.
+	#[must_use]
+	#[inline]
+	pub const fn quarter(&self) -> Option<u8> {
+		if self.month < 1 || self.month > 12 {
+			return None;
+		}
+
+		// SAFETY: The month was checked above
+		#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+		let quarter = ((self.month - 1) / 3 + 1) as u8;
+
+		Some(quarter)
+	}
+
+	/// Returns the first day of the month of this date, or `None` if the month is not set.
+	#[must_use]
+	#[inline]
+	pub const fn first_of_month(&self) -> Option<Self> {
+		if self.month < 1 || self.month > 12 {
+			return None;
+		}
+
+		Some(Self {
+			year: self.year,
+			month: self.month,
+			day: 1,
+		})
+	}
+
+	/// Returns the last day of the month of this date, or `None` if the month is not set.
+	///
+	/// If the year is not set, February is assumed to have 29 days, as the date may recur on leap years.
+	#[must_use]
+	#[inline]
+	pub const fn last_of_month(&self) -> Option<Self> {
+		if self.month < 1 || self.month > 12 {
+			return None;
+		}
+
+		// SAFETY: The month was checked to be within 1..=12
+		#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+		let day = calendar::days_in_month(self.year as i64, self.month as u8) as i32;
+
+		Some(Self {
+			year: self.year,
+			month: self.month,
+			day,
+		})
+	}
+
+	/// Returns `true` if the year is set and is a leap year.
+	#[must_use]
+	#[inline]
+	pub const fn is_leap_year(&self) -> bool {
+		self.has_year() && calendar::is_leap_year(self.year as i64)
+	}
+
+	/// Returns the number of days in the year of this date (365 or 366), or `None` if the year is not set.
+	#[must_use]
+	#[inline]
+	pub const fn days_in_year(&self) -> Option<u16> {
+		if self.has_year() {
+			Some(calendar::days_in_year(self.year as i64))
+		} else {
+			None
+		}
+	}
+
+	/// Returns `true` if this date is the last day of its month.
+	///
+	/// If the year is not set, February 29th is considered the last day of February, as the date may recur on leap years.
+	#[must_use]
+	#[inline]
+	pub const fn is_last_day_of_month(&self) -> bool {
+		// SAFETY: The month is only converted after it is checked to be within 1..=12
+		#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+		let is_last = self.month >= 1
+			&& self.month <= 12
+			&& self.day == calendar::days_in_month(self.year as i64, self.month as u8) as i32;
+
+		is_last
+	}
+
+	/// Returns the first day of the quarter of this date, or `None` if the month is not set.
+	#[must_use]
+	#[inline]
+	pub const fn first_of_quarter(&self) -> Option<Self> {
+		match self.quarter() {
+			Some(quarter) => Some(Self {
+				year: self.year,
+				month: (quarter as i32 - 1) * 3 + 1,
+				day: 1,
+			}),
+			None => None,
+		}
+	}
+
+	/// Returns the last day of the quarter of this date, or `None` if the month is not set.
+	#[must_use]
+	#[inline]
+	pub const fn last_of_quarter(&self) -> Option<Self> {
+		match self.quarter() {
+			Some(quarter) => {
+				let month = quarter * 3;
+
+				Some(Self {
+					year: self.year,
+					month: month as i32,
+					day: calendar::days_in_month(self.year as i64, month) as i32,
+				})
+			}
+			None => None,
+		}
+	}
+}
+
+impl Date {
+	/// Sets the year, checking that the resulting date is still valid.
+	///
+	/// The date is left unchanged if the check fails.
+	pub fn set_year_checked(&mut self, year: i32) -> Result<(), DateError> {
+		validate_date(year, self.month, self.day)?;
+		self.year = year;
+		Ok(())
+	}
+
+	/// Sets the month, checking that the resulting date is still valid.
+	///
+	/// The date is left unchanged if the check fails.
+	pub fn set_month_checked(&mut self, month: i32) -> Result<(), DateError> {
+		validate_date(self.year, month, self.day)?;
+		self.month = month;
+		Ok(())
+	}
+
+	/// Sets the day, checking that the resulting date is still valid.
+	///
+	/// The date is left unchanged if the check fails.
+	pub fn set_day_checked(&mut self, day: i32) -> Result<(), DateError> {
+		validate_date(self.year, self.month, day)?;
+		self.day = day;
+		Ok(())
+	}
+}
+
+impl ProtoValidate for Date {
+	type Error = DateError;
+
+	#[inline]
+	fn validate(&self) -> Result<(), Self::Error> {
+		validate_date(self.year, self.month, self.day)
+	}
 }
 
 impl PartialOrd for Date {
@@ -189,27 +368,6 @@ mod chrono_impls {
 	}
 }
 
-const fn is_leap_year(year: i32) -> bool {
-	(year % 4 == 0) && ((year % 100 != 0) || (year % 400 == 0))
-}
-
-pub(crate) const fn days_in_month(month: i32, year: i32) -> i32 {
-	match month {
-		1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
-		4 | 6 | 9 | 11 => 30,
-		2 => {
-			// If year is 0, we assume it's a recurring date (like a birthday),
-			// so we must allow Feb 29th.
-			if year == 0 || is_leap_year(year) {
-				29
-			} else {
-				28
-			}
-		}
-		_ => 0,
-	}
-}
-
 fn validate_date(year: i32, month: i32, day: i32) -> Result<(), DateError> {
 	if !(0..=9999).contains(&year) {
 		return Err(DateError::InvalidYear(
@@ -250,7 +408,9 @@ fn validate_date(year: i32, month: i32, day: i32) -> Result<(), DateError> {
 	}
 
 	if day != 0 {
-		let max_days = days_in_month(month, year);
+		// SAFETY: The month was checked to be within 1..=12
+		#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+		let max_days = i32::from(calendar::days_in_month(i64::from(year), month as u8));
 		if day > max_days {
 			return Err(DateError::InvalidDay(alloc::format!(
 				"Invalid day {day} for month {month} (max is {max_days} for year {year})"
@@ -269,6 +429,74 @@ mod tests {
 		Date::new(y, m, d)
 	}
 
+	#[test]
+	fn month_and_quarter_boundaries() {
+		let leap = date(2024, 2, 10).unwrap();
+		assert_eq!(leap.quarter(), Some(1));
+		assert_eq!(leap.first_of_month(), Some(date(2024, 2, 1).unwrap()));
+		assert_eq!(leap.last_of_month(), Some(date(2024, 2, 29).unwrap()));
+		assert_eq!(leap.first_of_quarter(), Some(date(2024, 1, 1).unwrap()));
+		assert_eq!(leap.last_of_quarter(), Some(date(2024, 3, 31).unwrap()));
+
+		let common = date(2023, 2, 10).unwrap();
+		assert_eq!(common.last_of_month(), Some(date(2023, 2, 28).unwrap()));
+
+		let autumn = date(2023, 11, 5).unwrap();
+		assert_eq!(autumn.quarter(), Some(4));
+		assert_eq!(autumn.first_of_quarter(), Some(date(2023, 10, 1).unwrap()));
+		assert_eq!(autumn.last_of_quarter(), Some(date(2023, 12, 31).unwrap()));
+
+		let recurring = date(0, 2, 3).unwrap();
+		assert_eq!(recurring.last_of_month(), Some(date(0, 2, 29).unwrap()));
+
+		let year_only = date(2024, 0, 0).unwrap();
+		assert_eq!(year_only.quarter(), None);
+		assert_eq!(year_only.first_of_month(), None);
+	}
+
+	#[test]
+	fn calendar_predicates() {
+		assert!(date(2024, 5, 1).unwrap().is_leap_year());
+		assert!(!date(1900, 5, 1).unwrap().is_leap_year());
+		assert!(!date(0, 2, 29).unwrap().is_leap_year());
+
+		assert_eq!(date(2000, 0, 0).unwrap().days_in_year(), Some(366));
+		assert_eq!(date(2023, 1, 1).unwrap().days_in_year(), Some(365));
+		assert_eq!(date(0, 1, 1).unwrap().days_in_year(), None);
+
+		assert!(date(2024, 2, 29).unwrap().is_last_day_of_month());
+		assert!(date(2023, 2, 28).unwrap().is_last_day_of_month());
+		assert!(!date(2024, 2, 28).unwrap().is_last_day_of_month());
+		assert!(date(0, 2, 29).unwrap().is_last_day_of_month());
+		assert!(!date(2024, 12, 0).unwrap().is_last_day_of_month());
+	}
+
+	#[test]
+	fn bounds_and_epoch() {
+		for d in [Date::MIN, Date::MAX, Date::epoch()] {
+			assert!(d.is_valid(), "{d:?}");
+		}
+
+		assert!(Date::MIN < Date::epoch() && Date::epoch() < Date::MAX);
+	}
+
+	#[test]
+	fn checked_setters() {
+		let mut d = date(2024, 1, 31).unwrap();
+
+		assert!(matches!(
+			d.set_month_checked(2),
+			Err(DateError::InvalidDay(_))
+		));
+		assert!(d.set_day_checked(29).is_ok());
+		assert!(d.set_month_checked(2).is_ok());
+		assert!(matches!(
+			d.set_year_checked(2023),
+			Err(DateError::InvalidDay(_))
+		));
+		assert_eq!(d, date(2024, 2, 29).unwrap());
+	}
+
 	#[test]
 	fn test_date_kinds_creation() {
 		// 1. Full Date
@@ -3,7 +3,7 @@ use core::fmt::{Display, Formatter};
 use thiserror::Error;
 
 use crate::{
-	Duration, String,
+	Duration, ProtoValidate, String, ToString,
 	common::{DateTime, TimeZone, date_time::TimeOffset},
 };
 
@@ -20,6 +20,16 @@ impl Display for DateTime {
 		}
 		write!(f, "{:02}-{:02}", self.month, self.day)?;
 
+		self.write_time_and_offset(f)
+	}
+}
+
+impl DateTime {
+	/// Writes the `THH:MM:SS` time and the UTC offset, if any, as they appear in the [`Display`] output.
+	pub(super) fn write_time_and_offset<W: core::fmt::Write + ?Sized>(
+		&self,
+		f: &mut W,
+	) -> core::fmt::Result {
 		write!(
 			f,
 			"T{:02}:{:02}:{:02}",
@@ -46,6 +56,37 @@ impl Display for DateTime {
 	}
 }
 
+impl core::fmt::Debug for DateTime {
+	/// The alternate form (`{:#?}`) shows both the ISO 8601 rendering and the raw fields.
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		if f.alternate() {
+			write!(
+				f,
+				"DateTime({self}; year={}, month={}, day={}, hours={}, minutes={}, seconds={}, nanos={}, time_offset={:?})",
+				self.year,
+				self.month,
+				self.day,
+				self.hours,
+				self.minutes,
+				self.seconds,
+				self.nanos,
+				self.time_offset
+			)
+		} else {
+			f.debug_struct("DateTime")
+				.field("year", &self.year)
+				.field("month", &self.month)
+				.field("day", &self.day)
+				.field("hours", &self.hours)
+				.field("minutes", &self.minutes)
+				.field("seconds", &self.seconds)
+				.field("nanos", &self.nanos)
+				.field("time_offset", &self.time_offset)
+				.finish()
+		}
+	}
+}
+
 /// Errors that can occur during the creation, conversion or validation of a [`DateTime`].
 #[derive(Debug, Error, PartialEq, Eq, Clone)]
 #[non_exhaustive]
@@ -76,6 +117,62 @@ pub enum DateTimeError {
 	OutOfRange,
 	#[error("DateTime conversion error: {0}")]
 	ConversionError(String),
+	#[error("Invalid ISO 8601 string: {0}")]
+	ParseError(String),
+}
+
+#[cfg(feature = "date")]
+impl From<crate::date::DateError> for DateTimeError {
+	fn from(value: crate::date::DateError) -> Self {
+		use crate::date::DateError;
+
+		match value {
+			DateError::InvalidYear(_) => Self::InvalidYear,
+			DateError::InvalidMonth(_) => Self::InvalidMonth,
+			DateError::InvalidDay(_) => Self::InvalidDay,
+			DateError::ConversionError(msg) => Self::ConversionError(msg),
+		}
+	}
+}
+
+#[cfg(feature = "timeofday")]
+impl From<crate::time_of_day::TimeOfDayError> for DateTimeError {
+	fn from(value: crate::time_of_day::TimeOfDayError) -> Self {
+		use crate::time_of_day::TimeOfDayError;
+
+		match value {
+			TimeOfDayError::InvalidHours => Self::InvalidHours,
+			TimeOfDayError::InvalidMinutes => Self::InvalidMinutes,
+			TimeOfDayError::InvalidSeconds => Self::InvalidSeconds,
+			TimeOfDayError::InvalidNanos => Self::InvalidNanos,
+			TimeOfDayError::ConversionError => Self::InvalidTime,
+		}
+	}
+}
+
+impl From<crate::timestamp::TimestampError> for DateTimeError {
+	#[inline]
+	fn from(value: crate::timestamp::TimestampError) -> Self {
+		Self::ConversionError(value.to_string())
+	}
+}
+
+impl From<crate::duration::DurationError> for DateTimeError {
+	fn from(value: crate::duration::DurationError) -> Self {
+		match value {
+			crate::duration::DurationError::OutOfRange => Self::OutOfRange,
+			other => Self::ConversionError(other.to_string()),
+		}
+	}
+}
+
+impl ProtoValidate for DateTime {
+	type Error = DateTimeError;
+
+	#[inline]
+	fn validate(&self) -> Result<(), Self::Error> {
+		Self::validate(self)
+	}
 }
 
 impl PartialOrd for TimeOffset {
@@ -134,7 +231,9 @@ fn datetime_is_valid(
 	if !(1..=12).contains(&month) {
 		return Err(DateTimeError::InvalidMonth);
 	}
-	let max_days = crate::date::days_in_month(month, year);
+	// SAFETY: The month was checked to be within 1..=12
+	#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+	let max_days = i32::from(crate::calendar::days_in_month(i64::from(year), month as u8));
 	if !(1..=max_days).contains(&day) {
 		return Err(DateTimeError::InvalidDay);
 	}
@@ -160,6 +259,46 @@ fn datetime_is_valid(
 }
 
 impl DateTime {
+	/// The earliest date and time that can be represented, `0001-01-01T00:00:00Z`.
+	pub const MIN: Self = Self {
+		year: 1,
+		month: 1,
+		day: 1,
+		hours: 0,
+		minutes: 0,
+		seconds: 0,
+		nanos: 0,
+		time_offset: Some(TimeOffset::UtcOffset(UTC_OFFSET)),
+	};
+
+	/// The latest date and time that can be represented, `9999-12-31T23:59:59.999999999Z`.
+	pub const MAX: Self = Self {
+		year: 9999,
+		month: 12,
+		day: 31,
+		hours: 23,
+		minutes: 59,
+		seconds: 59,
+		nanos: 999_999_999,
+		time_offset: Some(TimeOffset::UtcOffset(UTC_OFFSET)),
+	};
+
+	/// Returns the Unix epoch, `1970-01-01T00:00:00Z`.
+	#[must_use]
+	#[inline]
+	pub const fn epoch() -> Self {
+		Self {
+			year: 1970,
+			month: 1,
+			day: 1,
+			hours: 0,
+			minutes: 0,
+			seconds: 0,
+			nanos: 0,
+			time_offset: Some(TimeOffset::UtcOffset(UTC_OFFSET)),
+		}
+	}
+
 	/// Checks if this [`DateTime`] instance represents a valid date and time, and returns the related error if it does not.
 	pub fn validate(&self) -> Result<(), DateTimeError> {
 		datetime_is_valid(
@@ -502,6 +641,59 @@ mod chrono_impls {
 		}
 	}
 
+	// Local conversions
+
+	#[cfg(feature = "std")]
+	impl DateTime {
+		/// Creates a new instance from a [`chrono::DateTime`] in the local time zone of the host, using its current offset from UTC as the [`TimeOffset`].
+		#[must_use]
+		#[inline]
+		pub fn from_local(value: chrono::DateTime<chrono::Local>) -> Self {
+			value.into()
+		}
+
+		/// Converts this [`DateTime`] to [`chrono::DateTime`] in the local time zone of the host.
+		/// It succeeds if the [`TimeOffset`] results in an unambiguous [`FixedOffset`](chrono::FixedOffset).
+		pub fn to_local_datetime(self) -> Result<chrono::DateTime<chrono::Local>, DateTimeError> {
+			self.try_into()
+		}
+	}
+
+	#[cfg(feature = "std")]
+	impl From<chrono::DateTime<chrono::Local>> for DateTime {
+		fn from(value: chrono::DateTime<chrono::Local>) -> Self {
+			use chrono::{Datelike, Offset, Timelike};
+
+			let offset_seconds = value.offset().fix().local_minus_utc();
+
+			// Casting is safe due to chrono's constructor API
+			Self {
+				year: value.year(),
+				month: value.month().cast_signed(),
+				day: value.day().cast_signed(),
+				hours: value.hour().cast_signed(),
+				minutes: value.minute().cast_signed(),
+				seconds: value.second().cast_signed(),
+				nanos: value.nanosecond().cast_signed(),
+				time_offset: Some(TimeOffset::UtcOffset(Duration::new(
+					offset_seconds.into(),
+					0,
+				))),
+			}
+		}
+	}
+
+	#[cfg(feature = "std")]
+	impl TryFrom<DateTime> for chrono::DateTime<chrono::Local> {
+		type Error = DateTimeError;
+
+		fn try_from(value: DateTime) -> Result<Self, Self::Error> {
+			let fixed: chrono::DateTime<chrono::FixedOffset> = value.try_into()?;
+
+			Ok(fixed.with_timezone(&chrono::Local))
+		}
+	}
+
 	#[cfg(feature = "chrono-tz")]
 	impl TryFrom<DateTime> for chrono::DateTime<chrono_tz::Tz> {
 		type Error = DateTimeError;
@@ -569,6 +761,16 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn bounds_and_epoch() {
+		for value in [DateTime::MIN, DateTime::MAX, DateTime::epoch()] {
+			assert!(value.is_valid(), "{value:?}");
+			assert!(value.has_utc_offset());
+		}
+
+		assert_eq!(DateTime::epoch().to_string(), "1970-01-01T00:00:00Z");
+	}
+
 	#[test]
 	fn test_display_formatting() {
 		// 1. Standard Local
@@ -706,4 +908,20 @@ mod tests {
 			assert_eq!(fixed_summer.offset().local_minus_utc(), -4 * 3600);
 		}
 	}
+
+	#[cfg(all(feature = "chrono", feature = "std"))]
+	#[test]
+	fn local_round_trip() {
+		let now = chrono::Local::now();
+		let datetime = DateTime::from_local(now);
+
+		assert!(matches!(
+			datetime.time_offset,
+			Some(TimeOffset::UtcOffset(_))
+		));
+		assert_eq!(datetime.to_local_datetime().unwrap(), now);
+
+		let timestamp = crate::Timestamp::from(now.with_timezone(&chrono::Utc));
+		assert_eq!(timestamp.to_local_datetime().unwrap(), now);
+	}
 }
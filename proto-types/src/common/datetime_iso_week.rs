@@ -0,0 +1,245 @@
+//! ISO 8601 week dates (`2024-W20-2`) and ordinal dates (`2024-141`) for [`DateTime`].
+
+use core::fmt::Write;
+
+use crate::{
+	Duration, String, ToString, calendar,
+	common::{DateTime, date_time::TimeOffset, datetime::DateTimeError},
+};
+
+impl DateTime {
+	/// Returns the calendar fields as unsigned values, after checking that the instance is valid and has a year.
+	fn checked_date(&self) -> Result<(i32, u8, u8), DateTimeError> {
+		self.validate()?;
+
+		if self.year == 0 {
+			return Err(DateTimeError::InvalidYear);
+		}
+
+		// SAFETY: The month and day have been validated
+		#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+		Ok((self.year, self.month as u8, self.day as u8))
+	}
+
+	/// Returns the day of the year, starting from 1, or `None` if the instance is invalid or has no year.
+	#[must_use]
+	pub fn day_of_year(&self) -> Option<u16> {
+		let (year, month, day) = self.checked_date().ok()?;
+
+		Some(calendar::day_of_year(year, month, day))
+	}
+
+	/// Returns the ISO week-numbering year, the week number and the weekday (from 1 for Monday to 7 for Sunday), or `None` if the instance is invalid or has no year.
+	///
+	/// The week-numbering year can differ from the calendar year for the first and last days of the year.
+	#[must_use]
+	pub fn iso_week_date(&self) -> Option<(i32, u8, u8)> {
+		let (year, month, day) = self.checked_date().ok()?;
+
+		Some(calendar::iso_week_date(year, month, day))
+	}
+
+	/// Formats the instance as an ISO 8601 week date, such as `2024-W20-2T10:00:00Z`.
+	///
+	/// The time and the UTC offset are written as in the [`Display`](core::fmt::Display) output.
+	pub fn format_iso_week_date(&self) -> Result<String, DateTimeError> {
+		let (year, month, day) = self.checked_date()?;
+		let (iso_year, week, weekday) = calendar::iso_week_date(year, month, day);
+
+		let mut output = String::new();
+		let _ = write!(output, "{iso_year:04}-W{week:02}-{weekday}");
+		let _ = self.write_time_and_offset(&mut output);
+
+		Ok(output)
+	}
+
+	/// Formats the instance as an ISO 8601 ordinal date, such as `2024-141T10:00:00Z`.
+	///
+	/// The time and the UTC offset are written as in the [`Display`](core::fmt::Display) output.
+	pub fn format_ordinal_date(&self) -> Result<String, DateTimeError> {
+		let (year, month, day) = self.checked_date()?;
+
+		let mut output = String::new();
+		let _ = write!(
+			output,
+			"{year:04}-{:03}",
+			calendar::day_of_year(year, month, day)
+		);
+		let _ = self.write_time_and_offset(&mut output);
+
+		Ok(output)
+	}
+
+	/// Parses an ISO 8601 week date, such as `2024-W20-2`, `2024-W20-2T10:00:00` or `2024-W20-2T10:00:00+02:00`.
+	///
+	/// The time defaults to midnight, and a missing offset produces a local [`DateTime`].
+	pub fn parse_iso_week_date(value: &str) -> Result<Self, DateTimeError> {
+		let invalid = || DateTimeError::ParseError(value.to_string());
+
+		let bytes = value.as_bytes();
+		if bytes.len() < 10 || bytes[4] != b'-' || bytes[5] != b'W' || bytes[8] != b'-' {
+			return Err(invalid());
+		}
+
+		let iso_year = parse_number(&value[..4]).ok_or_else(invalid)?;
+		let week = parse_number(&value[6..8]).ok_or_else(invalid)?;
+		let weekday = parse_number(&value[9..10]).ok_or_else(invalid)?;
+
+		// SAFETY: The numbers have at most 2 digits
+		#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+		let (year, month, day) = calendar::from_iso_week_date(iso_year, week as u8, weekday as u8)
+			.ok_or_else(invalid)?;
+
+		build(year, month, day, &value[10..]).ok_or_else(invalid)
+	}
+
+	/// Parses an ISO 8601 ordinal date, such as `2024-141`, `2024-141T10:00:00` or `2024-141T10:00:00Z`.
+	///
+	/// The time defaults to midnight, and a missing offset produces a local [`DateTime`].
+	pub fn parse_ordinal_date(value: &str) -> Result<Self, DateTimeError> {
+		let invalid = || DateTimeError::ParseError(value.to_string());
+
+		if value.len() < 8 || value.as_bytes()[4] != b'-' {
+			return Err(invalid());
+		}
+
+		let year = parse_number(&value[..4]).ok_or_else(invalid)?;
+		let ordinal = parse_number(&value[5..8]).ok_or_else(invalid)?;
+
+		// SAFETY: The number has at most 3 digits
+		#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+		let (month, day) = calendar::from_ordinal(year, ordinal as u16).ok_or_else(invalid)?;
+
+		build(year, month, day, &value[8..]).ok_or_else(invalid)
+	}
+}
+
+fn parse_number(digits: &str) -> Option<i32> {
+	if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+		return None;
+	}
+
+	digits.parse().ok()
+}
+
+/// Builds the instance from the date and the remaining `THH:MM:SS[.fffffffff][Z|±HH:MM]` suffix, if any.
+fn build(year: i32, month: u8, day: u8, rest: &str) -> Option<DateTime> {
+	let mut datetime = DateTime {
+		year,
+		month: month.into(),
+		day: day.into(),
+		..Default::default()
+	};
+
+	if rest.is_empty() {
+		return Some(datetime);
+	}
+
+	let time = rest.strip_prefix('T')?;
+	if time.len() < 8 || &time[2..3] != ":" || &time[5..6] != ":" {
+		return None;
+	}
+
+	datetime.hours = parse_number(&time[..2])?;
+	datetime.minutes = parse_number(&time[3..5])?;
+	datetime.seconds = parse_number(&time[6..8])?;
+
+	let mut rest = &time[8..];
+
+	if let Some(fraction) = rest.strip_prefix('.') {
+		let end = fraction
+			.find(|c: char| !c.is_ascii_digit())
+			.unwrap_or(fraction.len());
+		if end == 0 || end > 9 {
+			return None;
+		}
+
+		// SAFETY: The length has just been checked to be at most 9
+		#[allow(clippy::cast_possible_truncation)]
+		let padding = 9 - end as u32;
+		datetime.nanos = parse_number(&fraction[..end])? * 10i32.pow(padding);
+		rest = &fraction[end..];
+	}
+
+	datetime.time_offset = match rest.as_bytes().first() {
+		None => None,
+		Some(b'Z') if rest.len() == 1 => Some(TimeOffset::UtcOffset(Duration::default())),
+		Some(sign @ (b'+' | b'-')) if rest.len() == 6 && &rest[3..4] == ":" => {
+			let seconds =
+				i64::from(parse_number(&rest[1..3])? * 3600 + parse_number(&rest[4..6])? * 60);
+
+			Some(TimeOffset::UtcOffset(Duration::new(
+				if *sign == b'-' { -seconds } else { seconds },
+				0,
+			)))
+		}
+		_ => return None,
+	};
+
+	datetime.validate().ok()?;
+
+	Some(datetime)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn datetime(year: i32, month: i32, day: i32, hours: i32) -> DateTime {
+		DateTime {
+			year,
+			month,
+			day,
+			hours,
+			time_offset: Some(TimeOffset::UtcOffset(Duration::default())),
+			..Default::default()
+		}
+	}
+
+	#[test]
+	fn week_dates() {
+		let value = datetime(2024, 5, 14, 10);
+
+		assert_eq!(value.iso_week_date(), Some((2024, 20, 2)));
+		assert_eq!(
+			value.format_iso_week_date().unwrap(),
+			"2024-W20-2T10:00:00Z"
+		);
+		assert_eq!(
+			DateTime::parse_iso_week_date("2024-W20-2T10:00:00Z").unwrap(),
+			value
+		);
+
+		let local = DateTime::parse_iso_week_date("2020-W53-5").unwrap();
+		assert_eq!((local.year, local.month, local.day), (2021, 1, 1));
+		assert!(local.is_local());
+
+		assert!(DateTime::parse_iso_week_date("2024-W53-1").is_err());
+		assert!(DateTime::parse_iso_week_date("2024-W20-8").is_err());
+	}
+
+	#[test]
+	fn ordinal_dates() {
+		let value = datetime(2024, 5, 20, 10);
+
+		assert_eq!(value.day_of_year(), Some(141));
+		assert_eq!(value.format_ordinal_date().unwrap(), "2024-141T10:00:00Z");
+		assert_eq!(
+			DateTime::parse_ordinal_date("2024-141T10:00:00Z").unwrap(),
+			value
+		);
+
+		let offset = DateTime::parse_ordinal_date("2024-141T10:00:00.5-02:30").unwrap();
+		assert_eq!(offset.nanos, 500_000_000);
+		assert_eq!(
+			offset.time_offset,
+			Some(TimeOffset::UtcOffset(Duration::new(-9_000, 0)))
+		);
+
+		assert!(DateTime::parse_ordinal_date("2023-366").is_err());
+		assert_eq!(
+			DateTime { year: 0, ..value }.format_ordinal_date(),
+			Err(DateTimeError::InvalidYear)
+		);
+	}
+}
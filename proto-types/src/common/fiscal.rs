@@ -0,0 +1,210 @@
+use crate::{Date, Month};
+
+/// A fiscal calendar, made of fiscal years of twelve months that start on the first day of `start_month`.
+///
+/// Fiscal years are named after the calendar year in which they end, so with a fiscal calendar starting in October,
+/// the fiscal year 2024 goes from October 1st, 2023 to September 30th, 2024.
+/// A fiscal calendar starting in January (or with an unspecified month) matches the civil calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FiscalCalendar {
+	pub start_month: Month,
+}
+
+impl Default for FiscalCalendar {
+	#[inline]
+	fn default() -> Self {
+		Self::new(Month::January)
+	}
+}
+
+impl FiscalCalendar {
+	/// Creates a new fiscal calendar with fiscal years starting in the given month.
+	#[must_use]
+	#[inline]
+	pub const fn new(start_month: Month) -> Self {
+		Self { start_month }
+	}
+
+	/// The first month of the fiscal year, from 1 to 12.
+	const fn first_month(self) -> i32 {
+		match self.start_month {
+			Month::Unspecified => 1,
+			month => month as i32,
+		}
+	}
+
+	/// Returns the fiscal year that the date falls in, or `None` if the date does not have a year and a month.
+	#[must_use]
+	pub const fn fiscal_year_of(&self, date: &Date) -> Option<i32> {
+		if date.year == 0 || date.month < 1 || date.month > 12 {
+			return None;
+		}
+
+		let first_month = self.first_month();
+
+		if first_month != 1 && date.month >= first_month {
+			Some(date.year + 1)
+		} else {
+			Some(date.year)
+		}
+	}
+
+	/// Returns the fiscal quarter (from 1 to 4) that the date falls in, or `None` if the date does not have a month.
+	#[must_use]
+	pub const fn fiscal_quarter(&self, date: &Date) -> Option<u8> {
+		if date.month < 1 || date.month > 12 {
+			return None;
+		}
+
+		let months_into_year = (date.month - self.first_month() + 12) % 12;
+
+		// SAFETY: The result is always between 1 and 4
+		#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+		let quarter = (months_into_year / 3 + 1) as u8;
+
+		Some(quarter)
+	}
+
+	/// Returns the first day of the given fiscal year, or `None` if it falls outside of the years supported by [`Date`].
+	#[must_use]
+	pub fn fiscal_year_start(&self, fiscal_year: i32) -> Option<Date> {
+		self.month_start(fiscal_year, 0)
+	}
+
+	/// Returns the first day of the given fiscal quarter, or `None` if the quarter is not between 1 and 4 or if the date falls outside of the years supported by [`Date`].
+	#[must_use]
+	pub fn fiscal_quarter_start(&self, fiscal_year: i32, quarter: u8) -> Option<Date> {
+		if !(1..=4).contains(&quarter) {
+			return None;
+		}
+
+		self.month_start(fiscal_year, (i32::from(quarter) - 1) * 3)
+	}
+
+	/// Returns the first day of the month that comes `offset` months after the start of the fiscal year.
+	fn month_start(self, fiscal_year: i32, offset: i32) -> Option<Date> {
+		let first_month = self.first_month();
+		let start_year = if first_month == 1 {
+			fiscal_year
+		} else {
+			fiscal_year.checked_sub(1)?
+		};
+
+		let months = start_year
+			.checked_mul(12)?
+			.checked_add(first_month - 1 + offset)?;
+
+		Date::new(months.div_euclid(12), months.rem_euclid(12) + 1, 1).ok()
+	}
+}
+
+#[cfg(feature = "interval")]
+impl FiscalCalendar {
+	/// Returns the [`Interval`](crate::Interval) covering the given fiscal year, from the start of its first day (inclusive) to the start of the next fiscal year (exclusive).
+	///
+	/// Returns `None` if the fiscal year falls outside of the years supported by [`Date`].
+	#[must_use]
+	pub fn fiscal_year_interval(&self, fiscal_year: i32) -> Option<crate::Interval> {
+		let start = self.fiscal_year_start(fiscal_year)?;
+		let end = self.month_start(fiscal_year, 12)?;
+
+		date_interval(start, end)
+	}
+
+	/// Returns the [`Interval`](crate::Interval) covering the given fiscal quarter, from the start of its first day (inclusive) to the start of the next quarter (exclusive).
+	///
+	/// Returns `None` if the quarter is not between 1 and 4 or if it falls outside of the years supported by [`Date`].
+	#[must_use]
+	pub fn fiscal_quarter_interval(
+		&self,
+		fiscal_year: i32,
+		quarter: u8,
+	) -> Option<crate::Interval> {
+		let start = self.fiscal_quarter_start(fiscal_year, quarter)?;
+		let end = self.month_start(fiscal_year, i32::from(quarter) * 3)?;
+
+		date_interval(start, end)
+	}
+}
+
+#[cfg(feature = "interval")]
+fn date_interval(start: Date, end: Date) -> Option<crate::Interval> {
+	let to_timestamp = |date: Date| {
+		crate::Timestamp::date(
+			i64::from(date.year),
+			u8::try_from(date.month).ok()?,
+			u8::try_from(date.day).ok()?,
+		)
+		.ok()
+	};
+
+	crate::Interval::new(Some(to_timestamp(start)?), Some(to_timestamp(end)?)).ok()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn date(year: i32, month: i32, day: i32) -> Date {
+		Date::new(year, month, day).unwrap()
+	}
+
+	#[test]
+	fn fiscal_years_and_quarters() {
+		let october = FiscalCalendar::new(Month::October);
+
+		assert_eq!(october.fiscal_year_of(&date(2023, 10, 1)), Some(2024));
+		assert_eq!(october.fiscal_year_of(&date(2024, 9, 30)), Some(2024));
+		assert_eq!(october.fiscal_quarter(&date(2023, 10, 1)), Some(1));
+		assert_eq!(october.fiscal_quarter(&date(2024, 2, 29)), Some(2));
+		assert_eq!(october.fiscal_quarter(&date(2024, 9, 30)), Some(4));
+		assert_eq!(october.fiscal_year_start(2024), Some(date(2023, 10, 1)));
+		assert_eq!(
+			october.fiscal_quarter_start(2024, 3),
+			Some(date(2024, 4, 1))
+		);
+		assert_eq!(october.fiscal_quarter_start(2024, 5), None);
+
+		let civil = FiscalCalendar::default();
+		assert_eq!(civil.fiscal_year_of(&date(2024, 12, 31)), Some(2024));
+		assert_eq!(civil.fiscal_quarter(&date(2024, 12, 31)), Some(4));
+		assert_eq!(civil.fiscal_year_of(&date(0, 12, 31)), None);
+	}
+
+	#[cfg(feature = "interval")]
+	#[test]
+	fn period_intervals() {
+		use crate::Timestamp;
+
+		let april = FiscalCalendar::new(Month::April);
+
+		let year = april.fiscal_year_interval(2025).unwrap();
+		assert_eq!(
+			year.start_time,
+			Some(
+				"2024-04-01T00:00:00Z"
+					.parse::<Timestamp>()
+					.unwrap()
+			)
+		);
+		assert_eq!(
+			year.end_time,
+			Some(
+				"2025-04-01T00:00:00Z"
+					.parse::<Timestamp>()
+					.unwrap()
+			)
+		);
+
+		let q4 = april.fiscal_quarter_interval(2025, 4).unwrap();
+		assert_eq!(
+			q4.start_time,
+			Some(
+				"2025-01-01T00:00:00Z"
+					.parse::<Timestamp>()
+					.unwrap()
+			)
+		);
+		assert_eq!(q4.end_time, year.end_time);
+	}
+}
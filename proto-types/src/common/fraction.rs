@@ -20,6 +20,8 @@ pub enum FractionError {
 	Overflow,
 	#[error("Fraction arithmetic operation resulted in an undefined state")]
 	Undefined,
+	#[error("The fraction cannot be represented exactly")]
+	Inexact,
 }
 
 impl Fraction {
@@ -252,6 +254,8 @@ impl Fraction {
 	/// but can occur if a [`Fraction`] is constructed directly in an invalid state.
 	///
 	/// For a fallible conversion that returns a `Result`, use `TryFrom<Fraction> for f64`.
+	/// This method is not available with the `no-panic` feature.
+	#[cfg(not(feature = "no-panic"))]
 	#[must_use]
 	#[inline]
 	pub fn to_f64_unchecked(self) -> f64 {
@@ -0,0 +1,116 @@
+use core::fmt::Display;
+
+use crate::common::Fraction;
+
+/// Conventional names of the common aspect ratios, keyed by their reduced form.
+const NAMED_RATIOS: [(i64, i64, &str); 12] = [
+	(1, 1, "1:1"),
+	(5, 4, "5:4"),
+	(4, 3, "4:3"),
+	(3, 2, "3:2"),
+	(8, 5, "16:10"),
+	(16, 9, "16:9"),
+	(37, 20, "1.85:1"),
+	(64, 27, "21:9"),
+	(239, 100, "2.39:1"),
+	(2, 3, "2:3"),
+	(3, 4, "3:4"),
+	(9, 16, "9:16"),
+];
+
+/// An aspect ratio, as the reduced ratio between a width and a height, with its conventional name where applicable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AspectRatio {
+	/// The reduced ratio between the width and the height.
+	pub ratio: Fraction,
+	/// The conventional name of the ratio (such as `16:9` or `16:10`), if it is a common one.
+	pub name: Option<&'static str>,
+}
+
+impl Display for AspectRatio {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self.name {
+			Some(name) => f.write_str(name),
+			None => write!(f, "{}:{}", self.ratio.numerator, self.ratio.denominator),
+		}
+	}
+}
+
+impl Fraction {
+	/// Interprets the fraction as `width/height` and reduces it to an [`AspectRatio`], with its conventional name if it is a common one.
+	///
+	/// For example, `1920/1080` becomes `16:9` and `1920/1200` becomes `16:10` (whose reduced form is `8/5`).
+	/// Only exact matches are named, so `1366/768` is not named `16:9`.
+	/// Returns `None` if the width or the height are not positive.
+	#[must_use]
+	pub fn simplify_to_aspect_ratio(&self) -> Option<AspectRatio> {
+		if self.numerator <= 0 || self.denominator <= 0 {
+			return None;
+		}
+
+		let ratio = self.reduced();
+		let name = NAMED_RATIOS
+			.iter()
+			.find(|(width, height, _)| *width == ratio.numerator && *height == ratio.denominator)
+			.map(|(_, _, name)| *name);
+
+		Some(AspectRatio { ratio, name })
+	}
+
+	/// Interprets both fractions as `width/height` resolutions, and checks if this one fits within `bounds`, without being scaled or rotated.
+	///
+	/// The components are compared as they are, without being reduced, so `1280/720` fits within `1920/1080`, but not within `1080/1920`.
+	#[must_use]
+	#[inline]
+	pub const fn fits_within(&self, bounds: &Self) -> bool {
+		self.numerator <= bounds.numerator && self.denominator <= bounds.denominator
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::ToString;
+
+	fn resolution(width: i64, height: i64) -> Fraction {
+		Fraction {
+			numerator: width,
+			denominator: height,
+		}
+	}
+
+	#[test]
+	fn aspect_ratios() {
+		let full_hd = resolution(1920, 1080)
+			.simplify_to_aspect_ratio()
+			.unwrap();
+		assert_eq!(full_hd.ratio, resolution(16, 9));
+		assert_eq!(full_hd.to_string(), "16:9");
+
+		assert_eq!(
+			resolution(1920, 1200)
+				.simplify_to_aspect_ratio()
+				.unwrap()
+				.to_string(),
+			"16:10"
+		);
+
+		let unnamed = resolution(1366, 768)
+			.simplify_to_aspect_ratio()
+			.unwrap();
+		assert_eq!(unnamed.name, None);
+		assert_eq!(unnamed.to_string(), "683:384");
+
+		assert_eq!(resolution(0, 1080).simplify_to_aspect_ratio(), None);
+		assert_eq!(resolution(1920, -1080).simplify_to_aspect_ratio(), None);
+	}
+
+	#[test]
+	fn fits_within() {
+		let bounds = resolution(1920, 1080);
+
+		assert!(resolution(1280, 720).fits_within(&bounds));
+		assert!(bounds.fits_within(&bounds));
+		assert!(!resolution(1280, 720).fits_within(&resolution(720, 1280)));
+	}
+}
@@ -0,0 +1,209 @@
+use crate::{
+	Duration,
+	common::{Fraction, fraction::FractionError},
+	constants::NANOS_PER_SECOND,
+	integer::gcd_i128,
+};
+
+const NANOS_PER_SECOND_I128: i128 = NANOS_PER_SECOND as i128;
+
+impl Duration {
+	/// Returns the exact number of seconds per item when this duration is spread over `count` items, as a reduced [`Fraction`].
+	///
+	/// For example, 1 second over 3 items is `1/3` of a second per item.
+	/// Returns an error if `count` is 0, or if the reduced fraction does not fit in `i64`.
+	pub fn per(&self, count: u64) -> Result<Fraction, FractionError> {
+		if count == 0 {
+			return Err(FractionError::ZeroDenominator);
+		}
+
+		let numerator = self.total_nanos();
+		let denominator = i128::from(count) * NANOS_PER_SECOND_I128;
+
+		// SAFETY: the gcd is at most the denominator, which fits in i128
+		let common_divisor = gcd_i128(numerator, denominator).cast_signed();
+
+		let numerator =
+			i64::try_from(numerator / common_divisor).map_err(|_| FractionError::Overflow)?;
+		let denominator =
+			i64::try_from(denominator / common_divisor).map_err(|_| FractionError::Overflow)?;
+
+		Fraction::new(numerator, denominator)
+	}
+}
+
+impl Duration {
+	/// Returns the exact ratio between this duration and `total`, as a reduced [`Fraction`].
+	///
+	/// For example, 15 minutes out of an hour is `1/4`.
+	/// Returns an error if `total` is zero, or if the reduced fraction does not fit in `i64`.
+	pub fn fraction_of(&self, total: &Self) -> Result<Fraction, FractionError> {
+		let numerator = self.total_nanos();
+		let denominator = total.total_nanos();
+
+		if denominator == 0 {
+			return Err(FractionError::ZeroDenominator);
+		}
+
+		// The gcd is at most the magnitude of the denominator, which is far below i128::MAX for a Duration
+		let common_divisor = gcd_i128(numerator, denominator).cast_signed();
+
+		let numerator =
+			i64::try_from(numerator / common_divisor).map_err(|_| FractionError::Overflow)?;
+		let denominator =
+			i64::try_from(denominator / common_divisor).map_err(|_| FractionError::Overflow)?;
+
+		Fraction::new(numerator, denominator)
+	}
+}
+
+impl Duration {
+	/// Creates a duration from a period expressed in seconds as a [`Fraction`], such as `1/48_000` for a 48 kHz sampling rate.
+	///
+	/// Returns [`FractionError::Inexact`] if the period is not a whole number of nanoseconds, rather than rounding it.
+	pub fn from_period_fraction(period: Fraction) -> Result<Self, FractionError> {
+		if period.denominator == 0 {
+			return Err(FractionError::ZeroDenominator);
+		}
+
+		let scaled = i128::from(period.numerator) * NANOS_PER_SECOND_I128;
+		let denominator = i128::from(period.denominator);
+
+		if scaled % denominator != 0 {
+			return Err(FractionError::Inexact);
+		}
+
+		Self::from_total_nanos(scaled / denominator).ok_or(FractionError::Overflow)
+	}
+
+	/// Creates the period of a frequency expressed in hertz as a [`Fraction`], such as `30_000/1_001` for NTSC video.
+	///
+	/// Returns [`FractionError::Inexact`] if the period is not a whole number of nanoseconds, rather than rounding it.
+	pub fn from_hz_fraction(frequency: Fraction) -> Result<Self, FractionError> {
+		if frequency.numerator == 0 {
+			return Err(FractionError::ZeroDenominator);
+		}
+
+		Self::from_period_fraction(Fraction {
+			numerator: frequency.denominator,
+			denominator: frequency.numerator,
+		})
+	}
+}
+
+impl Fraction {
+	/// Multiplies a [`Duration`] by this fraction, using exact integer arithmetic.
+	///
+	/// The result is truncated towards zero to the nanosecond. Returns an error if the denominator is not positive or if the result overflows.
+	pub fn of_duration(&self, duration: Duration) -> Result<Duration, FractionError> {
+		if self.denominator <= 0 {
+			return Err(FractionError::ZeroDenominator);
+		}
+
+		let total_nanos = duration
+			.total_nanos()
+			.checked_mul(i128::from(self.numerator))
+			.ok_or(FractionError::Overflow)?
+			/ i128::from(self.denominator);
+
+		Duration::from_total_nanos(total_nanos).ok_or(FractionError::Overflow)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn duration_per_count() {
+		assert_eq!(Duration::new(1, 0).per(3), Fraction::new(1, 3));
+		assert_eq!(Duration::new(0, 500_000_000).per(2), Fraction::new(1, 4));
+		assert_eq!(Duration::new(-6, 0).per(4), Fraction::new(-3, 2));
+		assert_eq!(
+			Duration::new(1, 0).per(0),
+			Err(FractionError::ZeroDenominator)
+		);
+		assert_eq!(
+			Duration::new(0, 1).per(u64::MAX),
+			Err(FractionError::Overflow)
+		);
+	}
+
+	#[test]
+	fn duration_fraction_of() {
+		let hour = Duration::new(3_600, 0);
+
+		assert_eq!(
+			Duration::new(900, 0).fraction_of(&hour),
+			Fraction::new(1, 4)
+		);
+		assert_eq!(
+			Duration::new(0, 1).fraction_of(&hour),
+			Fraction::new(1, 3_600_000_000_000)
+		);
+		assert_eq!(
+			Duration::new(-7_200, 0).fraction_of(&hour),
+			Fraction::new(-2, 1)
+		);
+		assert_eq!(
+			hour.fraction_of(&Duration::default()),
+			Err(FractionError::ZeroDenominator)
+		);
+		assert_eq!(
+			Duration::new(315_576_000_000, 0).fraction_of(&Duration::new(0, 7)),
+			Err(FractionError::Overflow)
+		);
+	}
+
+	#[test]
+	fn exact_periods() {
+		assert_eq!(
+			Duration::from_hz_fraction(Fraction::new(1_000, 1).unwrap()),
+			Ok(Duration::new(0, 1_000_000))
+		);
+		assert_eq!(
+			Duration::from_period_fraction(Fraction::new(1, 3).unwrap()),
+			Err(FractionError::Inexact)
+		);
+		assert_eq!(
+			Duration::from_hz_fraction(Fraction::new(0, 1).unwrap()),
+			Err(FractionError::ZeroDenominator)
+		);
+		assert_eq!(
+			Duration::from_period_fraction(Fraction::new(-5, 2).unwrap()),
+			Ok(Duration::new(-2, -500_000_000))
+		);
+	}
+
+	#[test]
+	fn fraction_of_duration() {
+		let third = Fraction::new(1, 3).unwrap();
+
+		assert_eq!(
+			third.of_duration(Duration::new(3, 0)),
+			Ok(Duration::new(1, 0))
+		);
+		assert_eq!(
+			third.of_duration(Duration::new(1, 0)),
+			Ok(Duration::new(0, 333_333_333))
+		);
+		assert_eq!(
+			Fraction::new(-3, 2)
+				.unwrap()
+				.of_duration(Duration::new(10, 0)),
+			Ok(Duration::new(-15, 0))
+		);
+
+		// Round trip over a billion events without accumulated error
+		let per_event = Duration::new(1_000_000, 0)
+			.per(1_000_000_000)
+			.unwrap();
+		assert_eq!(per_event, Fraction::new(1, 1_000).unwrap());
+		assert_eq!(
+			Fraction::new(per_event.numerator * 1_000_000_000, per_event.denominator)
+				.unwrap()
+				.of_duration(Duration::new(1, 0)),
+			Ok(Duration::new(1_000_000, 0))
+		);
+	}
+}
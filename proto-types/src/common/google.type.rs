@@ -185,6 +185,7 @@ impl CalendarPeriod {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+#[prost(skip_debug)]
 pub struct Money {
   /// The three-letter currency code defined in ISO 4217.
   #[prost(string, tag = "1")]
@@ -228,6 +229,7 @@ pub struct Money {
 /// document and validate your application's limitations.
 #[cfg(feature = "datetime")]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+#[prost(skip_debug)]
 #[cfg_attr(
   any(feature = "diesel-postgres", feature = "diesel-sqlite"),
   derive(diesel::QueryId, diesel::AsExpression, diesel::FromSqlRow),
@@ -497,8 +499,14 @@ pub struct Date {
 /// When both start and end are unspecified, the interval matches any time.
 #[cfg(feature = "interval")]
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+  all(feature = "serde", not(feature = "canonical-serde")),
+  derive(serde::Serialize, serde::Deserialize)
+)]
+#[cfg_attr(
+  all(feature = "serde", not(feature = "canonical-serde")),
+  serde(rename_all = "camelCase")
+)]
 pub struct Interval {
   /// Optional. Inclusive start of the interval.
   ///
@@ -2,7 +2,14 @@ use core::cmp::Ordering;
 
 use thiserror::Error;
 
-use crate::{Duration, String, Timestamp, ToString, common::Interval, constants::NANOS_PER_SECOND};
+use crate::{
+	Duration, ProtoValidate, String, Timestamp, ToString,
+	common::Interval,
+	constants::NANOS_PER_SECOND,
+	duration::DurationError,
+	format,
+	timestamp::{TimestampError, quote_sql_identifier},
+};
 
 /// Errors that can occur during the creation, conversion or validation of an [`Interval`].
 #[derive(Debug, Error, PartialEq, Eq, Clone)]
@@ -40,6 +47,34 @@ impl Interval {
 		})
 	}
 
+	/// Creates a new [`Interval`] between the two timestamps, swapping them if they are in reverse order.
+	#[must_use]
+	#[inline]
+	pub fn new_ordered(a: Timestamp, b: Timestamp) -> Self {
+		let (start, end) = if b < a { (b, a) } else { (a, b) };
+
+		Self {
+			start_time: Some(start),
+			end_time: Some(end),
+		}
+	}
+
+	/// Creates the smallest [`Interval`] going from the earliest to the latest of the given timestamps, in any order.
+	///
+	/// Since the end of an interval is exclusive, the latest timestamp is not [contained](Self::contains_with_skew) in the result.
+	/// Returns `None` if the iterator is empty.
+	#[must_use]
+	pub fn spanning<I: IntoIterator<Item = Timestamp>>(timestamps: I) -> Option<Self> {
+		let mut timestamps = timestamps.into_iter();
+		let first = timestamps.next()?;
+
+		let (start, end) = timestamps.fold((first, first), |(start, end), ts| {
+			(start.min(ts), end.max(ts))
+		});
+
+		Some(Self::new_ordered(start, end))
+	}
+
 	#[cfg(any(feature = "std", feature = "chrono-wasm"))]
 	/// Creates an [`Interval`] going from now to the `end_time` specified.
 	#[must_use]
@@ -78,6 +113,44 @@ impl Interval {
 			.map_or_else(|| false, |(start, end)| start == end)
 	}
 
+	/// Returns `true` if the timestamp falls within the interval (inclusive start, exclusive end), allowing for a clock skew of `skew` on both ends.
+	///
+	/// In other words, it checks that `start_time - |skew| <= timestamp < end_time + |skew|`.
+	/// Missing bounds are treated as unbounded. The comparisons are made on the total nanoseconds, so non-normalized timestamps are handled correctly.
+	#[must_use]
+	pub fn contains_with_skew(&self, timestamp: Timestamp, skew: Duration) -> bool {
+		let (timestamp, skew) = (timestamp.total_nanos(), skew.total_nanos().abs());
+
+		self.start_time
+			.is_none_or(|start| timestamp >= start.total_nanos() - skew)
+			&& self
+				.end_time
+				.is_none_or(|end| timestamp < end.total_nanos() + skew)
+	}
+
+	/// Returns how far the timestamp is through the interval, from 0.0 at `start_time` to 1.0 at `end_time`, clamped to that range.
+	///
+	/// Returns `None` if a bound is missing or if the interval is empty or invalid.
+	#[must_use]
+	pub fn progress_at(&self, timestamp: Timestamp) -> Option<f64> {
+		let start = self.start_time?.total_nanos();
+		let end = self.end_time?.total_nanos();
+
+		// Timestamps span less than 2^96 nanoseconds, so the differences cannot overflow
+		let length = end - start;
+
+		if length <= 0 {
+			return None;
+		}
+
+		let elapsed = (timestamp.total_nanos() - start).clamp(0, length);
+
+		#[allow(clippy::cast_precision_loss)]
+		let progress = elapsed as f64 / length as f64;
+
+		Some(progress)
+	}
+
 	/// Returns `true` if the `Interval` is unspecified (no `start_time` and no `end_time`)
 	#[must_use]
 	#[inline]
@@ -86,6 +159,54 @@ impl Interval {
 	}
 }
 
+impl Interval {
+	/// Builds an SQL condition that checks if `column` falls within this interval, such as `"created_at" >= TIMESTAMP '2024-01-01 00:00:00+00:00' AND "created_at" < TIMESTAMP '2024-02-01 00:00:00+00:00'`.
+	///
+	/// The column name is quoted as an identifier. Since the end of an [`Interval`] is exclusive, the condition uses `>=` and `<` rather than `BETWEEN`.
+	/// Missing bounds are left out of the condition, and an unbounded interval produces `TRUE`.
+	pub fn to_sql_between_clause(&self, column: &str) -> Result<String, IntervalError> {
+		let column = quote_sql_identifier(column);
+
+		let start = match self.start_time {
+			Some(start) => Some(format!("{column} >= {}", start.to_sql_timestamp_literal()?)),
+			None => None,
+		};
+		let end = match self.end_time {
+			Some(end) => Some(format!("{column} < {}", end.to_sql_timestamp_literal()?)),
+			None => None,
+		};
+
+		Ok(match (start, end) {
+			(Some(start), Some(end)) => format!("{start} AND {end}"),
+			(Some(condition), None) | (None, Some(condition)) => condition,
+			(None, None) => "TRUE".to_string(),
+		})
+	}
+}
+
+impl From<TimestampError> for IntervalError {
+	#[inline]
+	fn from(value: TimestampError) -> Self {
+		Self::ConversionError(value.to_string())
+	}
+}
+
+impl From<DurationError> for IntervalError {
+	#[inline]
+	fn from(value: DurationError) -> Self {
+		Self::ConversionError(value.to_string())
+	}
+}
+
+impl ProtoValidate for Interval {
+	type Error = IntervalError;
+
+	#[inline]
+	fn validate(&self) -> Result<(), Self::Error> {
+		validate_interval(self.start_time, self.end_time)
+	}
+}
+
 impl TryFrom<Interval> for Duration {
 	type Error = IntervalError;
 	fn try_from(value: Interval) -> Result<Self, Self::Error> {
@@ -160,6 +281,92 @@ impl PartialOrd for Interval {
 mod tests {
 	use super::*;
 
+	#[test]
+	fn sql_between_clause() {
+		let interval = Interval::new(Some(ts(0)), Some(ts(86_400))).unwrap();
+
+		assert_eq!(
+			interval
+				.to_sql_between_clause("created\"at")
+				.unwrap(),
+			"\"created\"\"at\" >= TIMESTAMP '1970-01-01 00:00:00+00:00' AND \"created\"\"at\" < TIMESTAMP '1970-01-02 00:00:00+00:00'"
+		);
+		assert_eq!(
+			Interval::new(None, None)
+				.unwrap()
+				.to_sql_between_clause("t")
+				.unwrap(),
+			"TRUE"
+		);
+	}
+
+	#[test]
+	fn unordered_construction() {
+		assert_eq!(
+			Interval::new_ordered(ts(200), ts(100)),
+			Interval::new(Some(ts(100)), Some(ts(200))).unwrap()
+		);
+		assert_eq!(
+			Interval::spanning([ts(150), ts(300), ts(100), ts(200)]),
+			Some(Interval::new_ordered(ts(100), ts(300)))
+		);
+		assert_eq!(
+			Interval::spanning([ts(5)]),
+			Some(Interval::new_ordered(ts(5), ts(5)))
+		);
+		assert_eq!(Interval::spanning([]), None);
+	}
+
+	#[test]
+	fn contains_with_skew() {
+		let interval = Interval::new(Some(ts(100)), Some(ts(200))).unwrap();
+		let skew = Duration::new(5, 0);
+
+		assert!(interval.contains_with_skew(ts(100), Duration::default()));
+		assert!(!interval.contains_with_skew(ts(200), Duration::default()));
+		assert!(interval.contains_with_skew(ts(95), skew));
+		assert!(interval.contains_with_skew(ts(204), skew));
+		assert!(!interval.contains_with_skew(ts(94), skew));
+		assert!(!interval.contains_with_skew(ts(205), skew));
+		assert!(interval.contains_with_skew(ts(95), Duration::new(-5, 0)));
+
+		// The same instant as the start, without normalization
+		let denormalized_start = Timestamp {
+			seconds: 99,
+			nanos: 1_000_000_000,
+		};
+		assert!(interval.contains_with_skew(denormalized_start, Duration::default()));
+
+		let open_ended = Interval::new(Some(ts(100)), None).unwrap();
+		assert!(open_ended.contains_with_skew(ts(i64::MAX), skew));
+	}
+
+	#[test]
+	fn progress_at() {
+		let interval = Interval::new(Some(ts(100)), Some(ts(200))).unwrap();
+
+		assert_eq!(interval.progress_at(ts(100)), Some(0.0));
+		assert_eq!(interval.progress_at(ts(125)), Some(0.25));
+		assert_eq!(interval.progress_at(ts(50)), Some(0.0));
+		assert_eq!(interval.progress_at(ts(500)), Some(1.0));
+
+		let extreme = Interval::new(Some(ts(i64::MIN)), Some(ts(i64::MAX))).unwrap();
+		assert_eq!(extreme.progress_at(ts(i64::MAX)), Some(1.0));
+
+		assert_eq!(
+			Interval::new(Some(ts(100)), None)
+				.unwrap()
+				.progress_at(ts(150)),
+			None
+		);
+		assert_eq!(
+			Interval::new(Some(ts(100)), Some(ts(100)))
+				.unwrap()
+				.progress_at(ts(100)),
+			None
+		);
+	}
+
 	fn ts(s: i64) -> Timestamp {
 		Timestamp {
 			seconds: s,
@@ -1,6 +1,6 @@
 use thiserror::Error;
 
-use crate::LatLng;
+use crate::{LatLng, ProtoValidate, String, Vec};
 
 /// Errors that can occur during the creation or validation of a [`LatLng`].
 #[derive(Debug, Error, PartialEq, Eq, Clone)]
@@ -10,6 +10,8 @@ pub enum LatLngError {
 	InvalidLatitude,
 	#[error("Longitude out of valid range (-180.0, +180.0)")]
 	InvalidLongitude,
+	#[error("Malformed encoded polyline")]
+	InvalidPolyline,
 }
 
 fn validate_latlng(latitude: f64, longitude: f64) -> Result<(), LatLngError> {
@@ -53,3 +55,219 @@ impl core::fmt::Display for LatLng {
 		write!(f, "{:.6},{:.6}", self.latitude, self.longitude)
 	}
 }
+
+impl ProtoValidate for LatLng {
+	type Error = LatLngError;
+
+	#[inline]
+	fn validate(&self) -> Result<(), Self::Error> {
+		validate_latlng(self.latitude, self.longitude)
+	}
+}
+
+/// The mean radius of the Earth in meters, as defined by the IUGG.
+#[cfg(feature = "std")]
+const EARTH_RADIUS_M: f64 = 6_371_008.8;
+
+#[cfg(feature = "std")]
+impl LatLng {
+	/// Returns the great-circle distance in meters between the two points, using the haversine formula.
+	#[must_use]
+	pub fn distance_m(&self, other: &Self) -> f64 {
+		let (lat1, lat2) = (self.latitude.to_radians(), other.latitude.to_radians());
+		let d_lat = lat2 - lat1;
+		let d_lng = (other.longitude - self.longitude).to_radians();
+
+		let a = (d_lat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (d_lng / 2.0).sin().powi(2);
+
+		2.0 * EARTH_RADIUS_M * a.sqrt().min(1.0).asin()
+	}
+}
+
+/// Returns the total length in meters of the path that goes through the given points in order.
+#[cfg(feature = "std")]
+#[must_use]
+pub fn path_length_m(path: &[LatLng]) -> f64 {
+	path.windows(2)
+		.map(|pair| pair[0].distance_m(&pair[1]))
+		.sum()
+}
+
+const POLYLINE_FACTOR: f64 = 1e5;
+
+/// Rounds half away from zero, as required by the polyline algorithm.
+///
+/// The value must be a valid coordinate, so that the result and the differences between two results cannot overflow.
+fn to_polyline_units(value: f64) -> i64 {
+	let scaled = value * POLYLINE_FACTOR;
+
+	// SAFETY: The callers validate the coordinates first, so the scaled value is finite and at most 180 * 10^5 in absolute value
+	#[allow(clippy::cast_possible_truncation)]
+	let units = if scaled < 0.0 {
+		(scaled - 0.5) as i64
+	} else {
+		(scaled + 0.5) as i64
+	};
+
+	units
+}
+
+fn encode_polyline_value(value: i64, out: &mut String) {
+	let mut value = if value < 0 { !(value << 1) } else { value << 1 };
+
+	while value >= 0x20 {
+		// SAFETY: The value is masked to 5 bits and offset by 63, so it is always printable ASCII
+		#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+		out.push(char::from((((value & 0x1f) | 0x20) + 63) as u8));
+		value >>= 5;
+	}
+
+	// SAFETY: The remaining value is below 0x20
+	#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+	out.push(char::from((value + 63) as u8));
+}
+
+/// Encodes the given points with the [Google polyline algorithm](https://developers.google.com/maps/documentation/utilities/polylinealgorithm), with a precision of 5 decimal places.
+///
+/// Fails if any of the points contains coordinates outside of the allowed ranges (including NaN and infinite values).
+pub fn encode_polyline(path: &[LatLng]) -> Result<String, LatLngError> {
+	let mut out = String::new();
+	let (mut prev_lat, mut prev_lng) = (0, 0);
+
+	for point in path {
+		point.validate()?;
+
+		let (lat, lng) = (
+			to_polyline_units(point.latitude),
+			to_polyline_units(point.longitude),
+		);
+
+		encode_polyline_value(lat - prev_lat, &mut out);
+		encode_polyline_value(lng - prev_lng, &mut out);
+
+		(prev_lat, prev_lng) = (lat, lng);
+	}
+
+	Ok(out)
+}
+
+fn decode_polyline_value(bytes: &mut impl Iterator<Item = u8>) -> Option<Result<i64, LatLngError>> {
+	let mut result: i64 = 0;
+	let mut shift = 0;
+
+	let mut byte = bytes.next()?;
+
+	loop {
+		let Some(chunk) = byte.checked_sub(63).filter(|_| shift < 64) else {
+			return Some(Err(LatLngError::InvalidPolyline));
+		};
+
+		result |= i64::from(chunk & 0x1f) << shift;
+		shift += 5;
+
+		if chunk < 0x20 {
+			break;
+		}
+
+		match bytes.next() {
+			Some(next) => byte = next,
+			None => return Some(Err(LatLngError::InvalidPolyline)),
+		}
+	}
+
+	Some(Ok(if result & 1 == 1 {
+		!(result >> 1)
+	} else {
+		result >> 1
+	}))
+}
+
+/// Decodes a path encoded with the [Google polyline algorithm](https://developers.google.com/maps/documentation/utilities/polylinealgorithm), with a precision of 5 decimal places.
+///
+/// Fails if the string is malformed or if it contains coordinates outside of the allowed ranges.
+pub fn decode_polyline(encoded: &str) -> Result<Vec<LatLng>, LatLngError> {
+	let mut bytes = encoded.bytes();
+	let mut path = Vec::new();
+	let (mut lat, mut lng) = (0i64, 0i64);
+
+	while let Some(d_lat) = decode_polyline_value(&mut bytes) {
+		let d_lng = decode_polyline_value(&mut bytes).ok_or(LatLngError::InvalidPolyline)??;
+
+		lat = lat
+			.checked_add(d_lat?)
+			.ok_or(LatLngError::InvalidPolyline)?;
+		lng = lng
+			.checked_add(d_lng)
+			.ok_or(LatLngError::InvalidPolyline)?;
+
+		// SAFETY: Values outside of the exactly representable range are rejected by the validation
+		#[allow(clippy::cast_precision_loss)]
+		path.push(LatLng::new(
+			lat as f64 / POLYLINE_FACTOR,
+			lng as f64 / POLYLINE_FACTOR,
+		)?);
+	}
+
+	Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn point(latitude: f64, longitude: f64) -> LatLng {
+		LatLng::new(latitude, longitude).unwrap()
+	}
+
+	#[test]
+	fn polyline_round_trip() {
+		// The example from the algorithm's documentation
+		let path = [
+			point(38.5, -120.2),
+			point(40.7, -120.95),
+			point(43.252, -126.453),
+		];
+		let encoded = "_p~iF~ps|U_ulLnnqC_mqNvxq`@";
+
+		assert_eq!(encode_polyline(&path).as_deref(), Ok(encoded));
+		assert_eq!(decode_polyline(encoded).unwrap(), path);
+		assert_eq!(decode_polyline("").unwrap(), []);
+		assert_eq!(decode_polyline("_p~iF"), Err(LatLngError::InvalidPolyline));
+		assert_eq!(
+			decode_polyline("_p~iF~ps|"),
+			Err(LatLngError::InvalidPolyline)
+		);
+	}
+
+	#[test]
+	fn polyline_rejects_invalid_points() {
+		let invalid = |latitude: f64, longitude: f64| LatLng {
+			latitude,
+			longitude,
+		};
+
+		assert_eq!(
+			encode_polyline(&[point(0.0, 0.0), invalid(f64::NAN, 0.0)]),
+			Err(LatLngError::InvalidLatitude)
+		);
+		assert_eq!(
+			encode_polyline(&[invalid(f64::MAX, 0.0), invalid(f64::MIN, 0.0)]),
+			Err(LatLngError::InvalidLatitude)
+		);
+		assert_eq!(
+			encode_polyline(&[invalid(0.0, f64::INFINITY)]),
+			Err(LatLngError::InvalidLongitude)
+		);
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn path_length() {
+		let path = [point(0.0, 0.0), point(0.0, 1.0), point(1.0, 1.0)];
+		let length = path_length_m(&path);
+
+		// One degree along a great circle is about 111.2 km
+		assert!((length - 2.0 * 111_195.0).abs() < 10.0);
+		assert!(path_length_m(&path[..1]).abs() < f64::EPSILON);
+	}
+}
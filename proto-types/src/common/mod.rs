@@ -7,6 +7,8 @@ include!("./google.type.rs");
 
 #[cfg(feature = "serde")]
 mod common_serde_impls;
+#[cfg(all(feature = "serde", feature = "datetime"))]
+pub(crate) use common_serde_impls::DateTimeVisitor;
 
 #[cfg(feature = "cel")]
 mod cel_common_types_impls;
@@ -23,10 +25,21 @@ pub mod color;
 #[cfg(feature = "date")]
 pub mod date;
 
+/// Fiscal calendars with a configurable starting month.
+#[cfg(feature = "date")]
+pub mod fiscal;
+
+/// Business hours calendars, for measuring working time within intervals.
+#[cfg(all(feature = "date", feature = "timeofday", feature = "interval"))]
+pub mod business_calendar;
+
 /// Implementations for the google.type.DateTime message.
 #[cfg(feature = "datetime")]
 pub mod datetime;
 
+#[cfg(feature = "datetime")]
+mod datetime_iso_week;
+
 /// Implementations for the google.type.Decimal message.
 #[cfg(feature = "decimal")]
 pub mod decimal;
@@ -35,6 +48,14 @@ pub mod decimal;
 #[cfg(feature = "fraction")]
 pub mod fraction;
 
+#[cfg(feature = "fraction")]
+mod fraction_duration;
+
+#[cfg(feature = "fraction")]
+mod fraction_aspect;
+#[cfg(feature = "fraction")]
+pub use fraction_aspect::AspectRatio;
+
 /// Implementations for the google.type.Interval message.
 #[cfg(feature = "interval")]
 pub mod interval;
@@ -45,6 +66,34 @@ mod localized_text;
 #[cfg(feature = "money")]
 pub mod money;
 
+/// A registry of ISO 4217 currencies, used for formatting and parsing [`Money`] amounts.
+#[cfg(feature = "money")]
+pub mod currency;
+
+/// Locale-aware formatting for the google.type.Money message.
+#[cfg(feature = "money")]
+pub mod money_locale;
+
+/// Exact parsing of decimal strings for the google.type.Money message.
+#[cfg(feature = "money")]
+pub mod money_decimal;
+
+#[cfg(all(feature = "money", feature = "fraction"))]
+mod money_interest;
+
+#[cfg(feature = "money")]
+mod money_rate;
+#[cfg(feature = "money")]
+pub use money_rate::PricePerDuration;
+
+#[cfg(feature = "money")]
+mod money_minor_units;
+#[cfg(feature = "money")]
+pub use money_minor_units::MinorUnits;
+
+#[cfg(all(feature = "money-ops", not(feature = "no-panic")))]
+mod money_ops;
+
 #[cfg(feature = "postal_address")]
 mod postal_address;
 
@@ -52,6 +101,45 @@ mod postal_address;
 #[cfg(feature = "timeofday")]
 pub mod time_of_day;
 
+/// Recurring daily ranges of time, which can wrap past midnight.
+#[cfg(feature = "timeofday")]
+pub mod time_range;
+
+macro_rules! impl_name {
+	($($feature:literal => $name:ident),* $(,)?) => {
+		$(
+			#[cfg(feature = $feature)]
+			impl prost::Name for $name {
+				const PACKAGE: &'static str = "google.type";
+
+				const NAME: &'static str = stringify!($name);
+
+				fn type_url() -> crate::String {
+					crate::type_url_for::<Self>()
+				}
+			}
+		)*
+	};
+}
+
+impl_name!(
+	"color" => Color,
+	"date" => Date,
+	"datetime" => DateTime,
+	"datetime" => TimeZone,
+	"decimal" => Decimal,
+	"expr" => Expr,
+	"fraction" => Fraction,
+	"interval" => Interval,
+	"latlng" => LatLng,
+	"localized_text" => LocalizedText,
+	"money" => Money,
+	"phone_number" => PhoneNumber,
+	"postal_address" => PostalAddress,
+	"quaternion" => Quaternion,
+	"timeofday" => TimeOfDay,
+);
+
 #[cfg(feature = "phone_number")]
 impl PhoneNumber {
 	/// Returns a new [`PhoneNumber`] instance. Ensures that `kind` is always set, as required by the spec.
@@ -6,9 +6,11 @@
 use core::cmp::Ordering;
 use core::fmt::Write;
 
+#[cfg(not(feature = "std"))]
+use num_traits::float::FloatCore;
 use thiserror::Error;
 
-use crate::{String, ToString, common::Money};
+use crate::{ProtoValidate, String, ToString, common::Money, format};
 
 const NANO_FACTOR: i32 = 1_000_000_000;
 
@@ -20,13 +22,33 @@ pub enum MoneyError {
 	CurrencyMismatch { expected: String, found: String },
 	#[error("Money arithmetic operation failed (overflow, underflow, or invalid operand)")]
 	OutOfRange,
+	#[error("Invalid monetary amount: {0}")]
+	InvalidAmount(String),
+	#[error("Unknown currency symbol '{0}'")]
+	UnknownCurrency(String),
+	#[error(
+		"Invalid currency code '{0}': expected three uppercase letters, as defined in ISO 4217"
+	)]
+	InvalidCurrencyCode(String),
+	#[error("Amount '{0}' has more than 9 decimal places")]
+	ExcessPrecision(String),
+	#[error(
+		"Interest rates must be non-negative, and the amount of periods must be greater than zero"
+	)]
+	InvalidInterestParameters,
+	#[error("The duration of a rate must be positive")]
+	InvalidRate,
+	#[error(
+		"The amount cannot be represented exactly in the minor units of {currency} ({minor_units} decimal places)"
+	)]
+	InexactMinorUnits { currency: String, minor_units: u32 },
 }
 
 fn normalize_money_fields_checked(
 	mut units: i64,
 	mut nanos: i32,
 ) -> Result<(i64, i32), MoneyError> {
-	if nanos.abs() >= NANO_FACTOR {
+	if nanos.unsigned_abs() >= NANO_FACTOR.unsigned_abs() {
 		let units_carry = i64::from(nanos / (NANO_FACTOR));
 		units = units
 			.checked_add(units_carry)
@@ -53,6 +75,37 @@ fn normalize_money_fields_checked(
 	Ok((units, nanos))
 }
 
+impl ProtoValidate for Money {
+	type Error = MoneyError;
+
+	/// Checks that the currency code is made of three uppercase letters, that `nanos` is within `-999_999_999..=999_999_999` and that `units` and `nanos` have consistent signs.
+	fn validate(&self) -> Result<(), Self::Error> {
+		validate_currency_code(&self.currency_code)?;
+		validate_amount(self.units, self.nanos)
+	}
+}
+
+fn validate_currency_code(code: &str) -> Result<(), MoneyError> {
+	if code.len() != 3 || !code.bytes().all(|b| b.is_ascii_uppercase()) {
+		return Err(MoneyError::InvalidCurrencyCode(code.to_string()));
+	}
+
+	Ok(())
+}
+
+fn validate_amount(units: i64, nanos: i32) -> Result<(), MoneyError> {
+	if nanos.unsigned_abs() >= NANO_FACTOR.unsigned_abs()
+		|| (units > 0 && nanos < 0)
+		|| (units < 0 && nanos > 0)
+	{
+		return Err(MoneyError::InvalidAmount(format!(
+			"units ({units}) and nanos ({nanos}) are out of range or have different signs"
+		)));
+	}
+
+	Ok(())
+}
+
 impl PartialOrd for Money {
 	#[inline]
 	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
@@ -65,6 +118,32 @@ impl PartialOrd for Money {
 	}
 }
 
+impl core::fmt::Debug for Money {
+	/// The alternate form (`{:#?}`) shows both the formatted amount and the raw fields.
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		if f.alternate() {
+			let amount = self.to_formatted_string("", 9);
+			let amount = if amount.contains('.') {
+				amount.trim_end_matches('0').trim_end_matches('.')
+			} else {
+				&amount
+			};
+
+			write!(
+				f,
+				"Money({} {amount}; units={}, nanos={})",
+				self.currency_code, self.units, self.nanos
+			)
+		} else {
+			f.debug_struct("Money")
+				.field("currency_code", &self.currency_code)
+				.field("units", &self.units)
+				.field("nanos", &self.nanos)
+				.finish()
+		}
+	}
+}
+
 fn fields_from_total_nanos(total: i128) -> Result<(i64, i32), MoneyError> {
 	let factor = i128::from(NANO_FACTOR);
 
@@ -170,6 +249,31 @@ impl Money {
 		formatted_string
 	}
 
+	/// Formats the amount with the specified amount of decimal places (rounded like in [`to_formatted_string`](Self::to_formatted_string)), followed by the currency code and right-aligned to `width` characters.
+	///
+	/// The digits of the units are grouped in threes with a thin space (e.g. `1 234.50 USD`), so that amounts line up when printed in columns.
+	/// Values that are wider than `width` are not truncated.
+	#[must_use]
+	pub fn to_aligned_string(&self, decimal_places: u32, width: usize) -> String {
+		let amount = self.to_formatted_string("", decimal_places);
+		let (sign, amount) = amount
+			.strip_prefix('-')
+			.map_or(("", amount.as_str()), |rest| ("-", rest));
+		let (units, fraction) = amount
+			.split_once('.')
+			.map_or((amount, None), |(units, fraction)| (units, Some(fraction)));
+
+		let mut formatted = format!("{sign}{}", crate::tabulate::group_digits(units));
+		if let Some(fraction) = fraction {
+			formatted.push('.');
+			formatted.push_str(fraction);
+		}
+		formatted.push(' ');
+		formatted.push_str(&self.currency_code);
+
+		format!("{formatted:>width$}")
+	}
+
 	/// Normalizes units and nanos. Fails in case of overflow.
 	pub fn normalize(mut self) -> Result<Self, MoneyError> {
 		let (normalized_units, normalized_nanos) =
@@ -180,6 +284,28 @@ impl Money {
 		Ok(self)
 	}
 
+	/// Sets the currency code, checking that it is made of three uppercase letters.
+	pub fn set_currency_code_checked(&mut self, code: impl Into<String>) -> Result<(), MoneyError> {
+		let code = code.into();
+		validate_currency_code(&code)?;
+		self.currency_code = code;
+		Ok(())
+	}
+
+	/// Sets the units, checking that their sign is consistent with the nanos.
+	pub fn set_units_checked(&mut self, units: i64) -> Result<(), MoneyError> {
+		validate_amount(units, self.nanos)?;
+		self.units = units;
+		Ok(())
+	}
+
+	/// Sets the nanos, checking that they are within `-999_999_999..=999_999_999` and that their sign is consistent with the units.
+	pub fn set_nanos_checked(&mut self, nanos: i32) -> Result<(), MoneyError> {
+		validate_amount(self.units, nanos)?;
+		self.nanos = nanos;
+		Ok(())
+	}
+
 	/// Creates a new instance, if the normalization does not return errors like Overflow or Underflow.
 	pub fn new(
 		currency_code: impl Into<String>,
@@ -276,6 +402,18 @@ impl Money {
 		Self::new(currency_code, units, final_nanos)
 	}
 
+	/// Compares this amount with another one, returning an error if their currencies do not match.
+	pub fn try_cmp(&self, other: &Self) -> Result<Ordering, MoneyError> {
+		if self.currency_code != other.currency_code {
+			return Err(MoneyError::CurrencyMismatch {
+				expected: self.currency_code.clone(),
+				found: other.currency_code.clone(),
+			});
+		}
+
+		Ok(self.total_nanos().cmp(&other.total_nanos()))
+	}
+
 	/// Attempts to add another [`Money`] amount to this one, returning a new [`Money`] instance.
 	/// Returns an error if currencies mismatch or if addition causes an overflow/underflow.
 	pub fn try_add(&self, other: &Self) -> Result<Self, MoneyError> {
@@ -506,15 +644,76 @@ impl Money {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::format;
 
 	fn usd(u: i64, n: i32) -> Money {
 		Money::new("USD", u, n).unwrap()
 	}
 
+	#[test]
+	fn aligned_string() {
+		assert_eq!(
+			usd(1234, 500_000_000).to_aligned_string(2, 14),
+			"  1\u{2009}234.50 USD"
+		);
+		assert_eq!(
+			usd(-1_234_567, 0).to_aligned_string(0, 0),
+			"-1\u{2009}234\u{2009}567 USD"
+		);
+		assert_eq!(usd(0, -5_000_000).to_aligned_string(2, 0), "-0.01 USD");
+	}
+
 	fn eur(u: i64, n: i32) -> Money {
 		Money::new("EUR", u, n).unwrap()
 	}
 
+	#[test]
+	fn validate() {
+		assert!(usd(5, 500_000_000).is_valid());
+		assert!(usd(-5, -500_000_000).is_valid());
+		let raw = |units, nanos| Money {
+			currency_code: "USD".to_string(),
+			units,
+			nanos,
+		};
+		assert!(matches!(
+			raw(5, -500_000_000).validate(),
+			Err(MoneyError::InvalidAmount(_))
+		));
+		assert!(matches!(
+			raw(0, NANO_FACTOR).validate(),
+			Err(MoneyError::InvalidAmount(_))
+		));
+		assert!(matches!(
+			raw(0, i32::MIN).validate(),
+			Err(MoneyError::InvalidAmount(_))
+		));
+
+		let mut lowercase = usd(1, 0);
+		lowercase.currency_code = "usd".to_string();
+		assert_eq!(
+			lowercase.validate(),
+			Err(MoneyError::InvalidCurrencyCode("usd".to_string()))
+		);
+	}
+
+	#[test]
+	fn checked_setters() {
+		let mut money = usd(5, 0);
+
+		assert!(money.set_nanos_checked(250_000_000).is_ok());
+		assert!(matches!(
+			money.set_units_checked(-1),
+			Err(MoneyError::InvalidAmount(_))
+		));
+		assert!(matches!(
+			money.set_currency_code_checked("eu"),
+			Err(MoneyError::InvalidCurrencyCode(_))
+		));
+		assert!(money.set_currency_code_checked("EUR").is_ok());
+		assert_eq!(money, eur(5, 250_000_000));
+	}
+
 	#[test]
 	fn test_normalization_carry() {
 		// 1. Simple positive carry
@@ -795,4 +994,22 @@ mod tests {
 		let f = m.to_rounded_imprecise_f64(2).unwrap();
 		assert!((f - 10.56).abs() < f64::EPSILON);
 	}
+
+	#[test]
+	fn test_debug_formatting() {
+		let m = usd(10, 500_000_000);
+
+		assert_eq!(
+			format!("{m:?}"),
+			"Money { currency_code: \"USD\", units: 10, nanos: 500000000 }"
+		);
+		assert_eq!(
+			format!("{m:#?}"),
+			"Money(USD 10.5; units=10, nanos=500000000)"
+		);
+		assert_eq!(
+			format!("{:#?}", usd(-3, 0)),
+			"Money(USD -3; units=-3, nanos=0)"
+		);
+	}
 }
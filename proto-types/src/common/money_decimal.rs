@@ -0,0 +1,157 @@
+//! Exact parsing of plain decimal strings into [`Money`], with an explicit policy for digits beyond nano precision.
+
+use crate::{
+	String, ToString,
+	common::{Money, money::MoneyError},
+};
+
+/// What to do with the digits of a decimal amount that go beyond the 9 decimal places that [`Money`] can hold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OverflowPolicy {
+	/// Drops the extra digits, rounding towards zero.
+	Truncate,
+	/// Rounds to the nearest nano, and ties to the even one.
+	RoundHalfEven,
+	/// Returns [`MoneyError::ExcessPrecision`] if any of the extra digits is not zero.
+	Reject,
+}
+
+/// Describes how the amount parsed by [`Money::from_decimal_str`] relates to the original string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PrecisionOutcome {
+	/// The amount was represented exactly.
+	Exact,
+	/// Non-zero digits beyond nano precision were dropped.
+	Truncated,
+	/// Non-zero digits beyond nano precision were rounded half to even.
+	Rounded,
+}
+
+impl Money {
+	/// Parses a plain decimal string such as `12.3456789012` or `-0.5` into a [`Money`] amount.
+	///
+	/// Digits beyond nano precision are handled according to `policy`, and the returned [`PrecisionOutcome`] reports whether the policy had to alter the amount.
+	/// Grouping separators and currency symbols are not accepted: use [`Money::parse_localized`] for those.
+	pub fn from_decimal_str(
+		currency: impl Into<String>,
+		value: &str,
+		policy: OverflowPolicy,
+	) -> Result<(Self, PrecisionOutcome), MoneyError> {
+		let invalid = || MoneyError::InvalidAmount(value.to_string());
+
+		let (is_negative, digits) = match value.as_bytes().first() {
+			Some(b'-') => (true, &value[1..]),
+			Some(b'+') => (false, &value[1..]),
+			_ => (false, value),
+		};
+
+		let (integer, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+		let is_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+
+		if (integer.is_empty() && fraction.is_empty())
+			|| !is_digits(integer)
+			|| !is_digits(fraction)
+		{
+			return Err(invalid());
+		}
+
+		let (kept, extra) = fraction.split_at(fraction.len().min(9));
+
+		let mut magnitude = integer.bytes().try_fold(0i128, |acc, b| {
+			acc.checked_mul(10)?
+				.checked_add(i128::from(b - b'0'))
+		});
+		for b in kept
+			.bytes()
+			.chain(core::iter::repeat(b'0'))
+			.take(9)
+		{
+			magnitude = magnitude
+				.and_then(|acc| acc.checked_mul(10))
+				.and_then(|acc| acc.checked_add(i128::from(b - b'0')));
+		}
+		let mut magnitude = magnitude.ok_or(MoneyError::OutOfRange)?;
+
+		let outcome = if extra.bytes().all(|b| b == b'0') {
+			PrecisionOutcome::Exact
+		} else {
+			match policy {
+				OverflowPolicy::Reject => {
+					return Err(MoneyError::ExcessPrecision(value.to_string()));
+				}
+				OverflowPolicy::Truncate => PrecisionOutcome::Truncated,
+				OverflowPolicy::RoundHalfEven => {
+					let first = extra.as_bytes()[0];
+					let rest_is_zero = extra[1..].bytes().all(|b| b == b'0');
+
+					if first > b'5' || (first == b'5' && (!rest_is_zero || magnitude % 2 == 1)) {
+						magnitude = magnitude
+							.checked_add(1)
+							.ok_or(MoneyError::OutOfRange)?;
+					}
+
+					PrecisionOutcome::Rounded
+				}
+			}
+		};
+
+		let total = if is_negative { -magnitude } else { magnitude };
+
+		Ok((Self::from_total_nanos(currency, total)?, outcome))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::testkit::money;
+
+	fn parse(value: &str, policy: OverflowPolicy) -> Result<(Money, PrecisionOutcome), MoneyError> {
+		Money::from_decimal_str("USD", value, policy)
+	}
+
+	#[test]
+	fn exact_amounts() {
+		assert_eq!(
+			parse("12.5", OverflowPolicy::Reject),
+			Ok((money("USD", 12, 500_000_000), PrecisionOutcome::Exact))
+		);
+		assert_eq!(
+			parse("-0.123456789000", OverflowPolicy::Reject),
+			Ok((money("USD", 0, -123_456_789), PrecisionOutcome::Exact))
+		);
+		assert!(parse("1.2.3", OverflowPolicy::Reject).is_err());
+		assert!(parse("-", OverflowPolicy::Reject).is_err());
+	}
+
+	#[test]
+	fn sub_nano_digits() {
+		let value = "12.3456789015";
+
+		assert_eq!(
+			parse(value, OverflowPolicy::Truncate),
+			Ok((money("USD", 12, 345_678_901), PrecisionOutcome::Truncated))
+		);
+		assert_eq!(
+			parse(value, OverflowPolicy::RoundHalfEven),
+			Ok((money("USD", 12, 345_678_902), PrecisionOutcome::Rounded))
+		);
+		assert_eq!(
+			parse("-0.0000000025", OverflowPolicy::RoundHalfEven),
+			Ok((money("USD", 0, -2), PrecisionOutcome::Rounded))
+		);
+		assert_eq!(
+			parse(value, OverflowPolicy::Reject),
+			Err(MoneyError::ExcessPrecision(value.to_string()))
+		);
+
+		// Rounding up the largest representable magnitude overflows
+		assert_eq!(
+			parse(
+				"170141183460469231731687303715.8841057279",
+				OverflowPolicy::RoundHalfEven
+			),
+			Err(MoneyError::OutOfRange)
+		);
+	}
+}
@@ -0,0 +1,214 @@
+//! Interest and installment calculations for [`Money`], using [`Fraction`] rates for exact arithmetic.
+
+use crate::{
+	RoundingMode, Vec,
+	common::{Fraction, Money, currency::Currency, money::MoneyError},
+};
+
+/// Validates the rate and returns it as an `(numerator, denominator)` pair.
+fn rate_parts(rate: Fraction) -> Result<(i128, i128), MoneyError> {
+	if rate.numerator < 0 || rate.denominator <= 0 {
+		return Err(MoneyError::InvalidInterestParameters);
+	}
+
+	Ok((i128::from(rate.numerator), i128::from(rate.denominator)))
+}
+
+/// Computes the interest on `balance` for one period, rounded half to even to a multiple of `unit`.
+fn period_interest(balance: i128, rate: (i128, i128), unit: i128) -> Result<i128, MoneyError> {
+	let scaled = balance
+		.checked_mul(rate.0)
+		.ok_or(MoneyError::OutOfRange)?;
+
+	let units = RoundingMode::HalfEven.div(
+		scaled,
+		rate.1
+			.checked_mul(unit)
+			.ok_or(MoneyError::OutOfRange)?,
+	);
+
+	units
+		.checked_mul(unit)
+		.ok_or(MoneyError::OutOfRange)
+}
+
+/// Simulates the schedule with a regular payment of `payment`, passing each installment to `on_installment`, and returns the last one.
+fn schedule(
+	principal: i128,
+	rate: (i128, i128),
+	periods: u32,
+	unit: i128,
+	payment: i128,
+	mut on_installment: impl FnMut(i128) -> Result<(), MoneyError>,
+) -> Result<i128, MoneyError> {
+	let mut balance = principal;
+	let mut installment = 0;
+
+	for period in 0..periods {
+		let due = balance
+			.checked_add(period_interest(balance, rate, unit)?)
+			.ok_or(MoneyError::OutOfRange)?;
+
+		installment = if period + 1 == periods {
+			due
+		} else {
+			payment.min(due)
+		};
+
+		balance = due - installment;
+		on_installment(installment)?;
+	}
+
+	Ok(installment)
+}
+
+impl Money {
+	/// Splits this principal into `periods` installments with the annuity formula, using the given interest rate per period.
+	///
+	/// The interest of each period is rounded half to even to the minor unit of the currency (taken from the [`Currency`] registry, or 2 decimal places if unknown).
+	/// All installments are equal, except for the last one, which absorbs the rounding differences so that the loan is repaid exactly.
+	pub fn amortize(&self, periods: u32, rate: Fraction) -> Result<Vec<Self>, MoneyError> {
+		let rate = rate_parts(rate)?;
+
+		if periods == 0 || self.is_negative() {
+			return Err(MoneyError::InvalidInterestParameters);
+		}
+
+		let minor_units = Currency::from_code(&self.currency_code).map_or(2, |c| c.minor_units);
+		let unit = 10_i128.pow(9_u32.saturating_sub(minor_units));
+
+		let principal = self.total_nanos();
+
+		// The regular payment can never exceed the principal plus one period of interest.
+		let mut low = 0;
+		let mut high = principal
+			.checked_add(period_interest(principal, rate, unit)?)
+			.ok_or(MoneyError::OutOfRange)?
+			.div_euclid(unit)
+			+ 1;
+
+		// Finds the smallest payment for which the last installment is not greater than the others.
+		while low < high {
+			let mid = low + (high - low) / 2;
+			let payment = mid * unit;
+
+			// Only the last installment is needed here, so the schedule is not collected
+			let last = schedule(principal, rate, periods, unit, payment, |_| Ok(()))?;
+
+			if last <= payment {
+				high = mid;
+			} else {
+				low = mid + 1;
+			}
+		}
+
+		let mut installments = Vec::new();
+
+		schedule(principal, rate, periods, unit, low * unit, |installment| {
+			installments.push(Self::from_total_nanos(
+				self.currency_code.clone(),
+				installment,
+			)?);
+
+			Ok(())
+		})?;
+
+		Ok(installments)
+	}
+
+	/// Returns the simple interest accrued by this amount over the given number of periods, with the given rate per period.
+	///
+	/// The result is rounded half to even to the nanos.
+	pub fn accrue_simple_interest(&self, rate: Fraction, periods: u32) -> Result<Self, MoneyError> {
+		let (numerator, denominator) = rate_parts(rate)?;
+
+		let scaled = self
+			.total_nanos()
+			.checked_mul(numerator)
+			.and_then(|v| v.checked_mul(i128::from(periods)))
+			.ok_or(MoneyError::OutOfRange)?;
+
+		Self::from_total_nanos(
+			self.currency_code.clone(),
+			RoundingMode::HalfEven.div(scaled, denominator),
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::String;
+	use crate::testkit::money;
+
+	fn sum(installments: &[Money]) -> i128 {
+		installments.iter().map(Money::total_nanos).sum()
+	}
+
+	#[test]
+	fn amortization() {
+		// 1000 USD over 12 months at 1% per month: the payment is 88.85
+		let installments = money("USD", 1000, 0)
+			.amortize(12, Fraction::new(1, 100).unwrap())
+			.unwrap();
+
+		assert_eq!(installments.len(), 12);
+		assert!(
+			installments[..11]
+				.iter()
+				.all(|m| *m == money("USD", 88, 850_000_000))
+		);
+		assert_eq!(installments[11], money("USD", 88, 840_000_000));
+		assert_eq!(
+			sum(&installments),
+			money("USD", 1066, 190_000_000).total_nanos()
+		);
+	}
+
+	#[test]
+	fn amortization_without_interest() {
+		let installments = money("USD", 100, 0)
+			.amortize(3, Fraction::new(0, 1).unwrap())
+			.unwrap();
+
+		assert_eq!(
+			installments,
+			[
+				money("USD", 33, 340_000_000),
+				money("USD", 33, 340_000_000),
+				money("USD", 33, 320_000_000)
+			]
+		);
+		assert_eq!(sum(&installments), money("USD", 100, 0).total_nanos());
+	}
+
+	#[test]
+	fn invalid_parameters() {
+		let rate = Fraction::new(1, 100).unwrap();
+
+		assert_eq!(
+			money("USD", 100, 0).amortize(0, rate),
+			Err(MoneyError::InvalidInterestParameters)
+		);
+		assert_eq!(
+			money("USD", 100, 0).amortize(3, Fraction::new(-1, 100).unwrap()),
+			Err(MoneyError::InvalidInterestParameters)
+		);
+	}
+
+	#[test]
+	fn simple_interest() {
+		let interest = money("USD", 1000, 0)
+			.accrue_simple_interest(Fraction::new(5, 100).unwrap(), 3)
+			.unwrap();
+
+		assert_eq!(interest, money("USD", 150, 0));
+
+		let interest = Money::new(String::from("EUR"), 0, 1)
+			.unwrap()
+			.accrue_simple_interest(Fraction::new(1, 2).unwrap(), 1)
+			.unwrap();
+
+		assert_eq!(interest.total_nanos(), 0);
+	}
+}
@@ -0,0 +1,323 @@
+//! Locale-aware formatting and parsing for [`Money`], without depending on ICU data.
+
+use crate::{
+	String, ToString,
+	common::{Money, currency::Currency, money::MoneyError},
+};
+
+/// The position of the currency symbol relative to the amount.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SymbolPosition {
+	/// The symbol goes before the amount, as in `$1,234.56`.
+	Prefix,
+	/// The symbol goes after the amount, as in `1.234,56 €`.
+	Suffix,
+}
+
+/// The formatting conventions of a locale for monetary amounts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LocaleSpec {
+	/// The separator for groups of thousands, if any.
+	pub grouping_separator: Option<char>,
+	/// The separator between the integer and fractional parts.
+	pub decimal_separator: char,
+	/// Where the currency symbol is placed.
+	pub symbol_position: SymbolPosition,
+	/// Whether a space separates the symbol from the amount.
+	pub symbol_spacing: bool,
+	/// The ISO 4217 code of the currency to use when parsing amounts without a symbol.
+	pub currency: Option<&'static str>,
+}
+
+impl LocaleSpec {
+	/// United States English: `$1,234.56`.
+	pub const EN_US: Self = Self::new(Some(','), '.', SymbolPosition::Prefix, false);
+	/// British English: `£1,234.56`.
+	pub const EN_GB: Self = Self::EN_US;
+	/// German: `1.234,56 €`.
+	pub const DE_DE: Self = Self::new(Some('.'), ',', SymbolPosition::Suffix, true);
+	/// Italian: `1.234,56 €`.
+	pub const IT_IT: Self = Self::DE_DE;
+	/// Spanish: `1.234,56 €`.
+	pub const ES_ES: Self = Self::DE_DE;
+	/// French, which groups thousands with a narrow no-break space: `1 234,56 €`.
+	pub const FR_FR: Self = Self::new(Some('\u{202F}'), ',', SymbolPosition::Suffix, true);
+	/// Dutch: `€ 1.234,56`.
+	pub const NL_NL: Self = Self::new(Some('.'), ',', SymbolPosition::Prefix, true);
+	/// Swiss German: `CHF 1’234.56`.
+	pub const DE_CH: Self = Self::new(Some('’'), '.', SymbolPosition::Prefix, true);
+	/// Japanese: `¥1,234`.
+	pub const JA_JP: Self = Self::EN_US;
+
+	/// Creates a new instance.
+	#[must_use]
+	#[inline]
+	pub const fn new(
+		grouping_separator: Option<char>,
+		decimal_separator: char,
+		symbol_position: SymbolPosition,
+		symbol_spacing: bool,
+	) -> Self {
+		Self {
+			grouping_separator,
+			decimal_separator,
+			symbol_position,
+			symbol_spacing,
+			currency: None,
+		}
+	}
+
+	/// Sets the currency to use when parsing amounts without a symbol.
+	#[must_use]
+	#[inline]
+	pub const fn with_currency(mut self, currency: &'static str) -> Self {
+		self.currency = Some(currency);
+		self
+	}
+}
+
+impl Default for LocaleSpec {
+	#[inline]
+	fn default() -> Self {
+		Self::EN_US
+	}
+}
+
+impl Money {
+	/// Formats the amount following the conventions of the given locale, such as `1.234,56 €`.
+	///
+	/// The symbol and the number of decimal places are taken from the [`Currency`] registry. Unknown currencies use their code as the symbol, with 2 decimal places.
+	#[must_use]
+	pub fn to_localized_string(&self, locale: &LocaleSpec) -> String {
+		let currency = Currency::from_code(&self.currency_code);
+		let symbol = currency.map_or(self.currency_code.as_str(), |c| c.symbol);
+		let decimal_places = currency.map_or(2, |c| c.minor_units);
+
+		let plain = self.to_formatted_string("", decimal_places);
+		let (is_negative, plain) = match plain.strip_prefix('-') {
+			Some(amount) => (true, amount),
+			None => (false, plain.as_str()),
+		};
+		let (integer, fraction) = plain.split_once('.').unwrap_or((plain, ""));
+
+		let mut amount = String::new();
+
+		for (i, digit) in integer.chars().enumerate() {
+			if i > 0
+				&& (integer.len() - i) % 3 == 0
+				&& let Some(separator) = locale.grouping_separator
+			{
+				amount.push(separator);
+			}
+
+			amount.push(digit);
+		}
+
+		if !fraction.is_empty() {
+			amount.push(locale.decimal_separator);
+			amount.push_str(fraction);
+		}
+
+		let spacing = if locale.symbol_spacing { " " } else { "" };
+		let sign = if is_negative { "-" } else { "" };
+
+		match locale.symbol_position {
+			SymbolPosition::Prefix => crate::format!("{sign}{symbol}{spacing}{amount}"),
+			SymbolPosition::Suffix => crate::format!("{sign}{amount}{spacing}{symbol}"),
+		}
+	}
+}
+
+impl Money {
+	/// Parses an amount formatted with the conventions of the given locale, such as `1.234,56 €`, `$1,234.56` or `(1,234.56)`.
+	///
+	/// Negative amounts can be written with a leading `-` or in accounting notation, between parentheses.
+	/// The symbol is resolved to an ISO code through the [`Currency`] registry, falling back to [`LocaleSpec::currency`] if it is missing.
+	/// The parsing is exact: amounts with more than 9 decimal places are rejected rather than rounded.
+	pub fn parse_localized(value: &str, locale: &LocaleSpec) -> Result<Self, MoneyError> {
+		let invalid = || MoneyError::InvalidAmount(value.to_string());
+
+		let text = value.trim();
+
+		let (text, mut is_negative) = match text
+			.strip_prefix('(')
+			.and_then(|t| t.strip_suffix(')'))
+		{
+			Some(inner) => (inner.trim(), true),
+			None => (text, false),
+		};
+
+		let is_amount_char = |c: char| {
+			c.is_ascii_digit()
+				|| c == locale.decimal_separator
+				|| locale.grouping_separator == Some(c)
+		};
+
+		let start = text.find(is_amount_char).ok_or_else(invalid)?;
+		let end = text.rfind(is_amount_char).map_or(start, |i| {
+			i + text[i..].chars().next().map_or(1, char::len_utf8)
+		});
+
+		let mut prefix = text[..start].trim();
+		let suffix = text[end..].trim();
+		let amount = &text[start..end];
+
+		if let Some(rest) = prefix.strip_prefix('-') {
+			prefix = rest.trim();
+			is_negative = !is_negative;
+		} else if let Some(rest) = prefix.strip_suffix('-') {
+			prefix = rest.trim();
+			is_negative = !is_negative;
+		}
+
+		let symbol = match (prefix, suffix) {
+			("", "") => None,
+			(symbol, "") | ("", symbol) => Some(symbol),
+			_ => return Err(invalid()),
+		};
+
+		let currency_code = match symbol {
+			Some(symbol) => {
+				Currency::from_symbol(symbol)
+					.ok_or_else(|| MoneyError::UnknownCurrency(symbol.to_string()))?
+					.code
+			}
+			None => locale
+				.currency
+				.ok_or_else(|| MoneyError::UnknownCurrency(String::new()))?,
+		};
+
+		let (integer, fraction) = amount
+			.split_once(locale.decimal_separator)
+			.unwrap_or((amount, ""));
+
+		let mut groups = integer.split(|c| locale.grouping_separator == Some(c));
+		let first_group = groups.next().unwrap_or_default();
+		let is_digits = |s: &str| s.chars().all(|c| c.is_ascii_digit());
+
+		let valid_grouping = if integer.len() == first_group.len() {
+			!first_group.is_empty()
+		} else {
+			(1..=3).contains(&first_group.len()) && groups.clone().all(|group| group.len() == 3)
+		};
+
+		if !valid_grouping
+			|| !is_digits(first_group)
+			|| !groups.all(is_digits)
+			|| fraction.len() > 9
+			|| !is_digits(fraction)
+		{
+			return Err(invalid());
+		}
+
+		let integer: String = integer
+			.chars()
+			.filter(char::is_ascii_digit)
+			.collect();
+
+		let units: i64 = integer
+			.parse()
+			.map_err(|_| MoneyError::OutOfRange)?;
+		let nanos: i32 = if fraction.is_empty() {
+			0
+		} else {
+			crate::format!("{fraction:0<9}")
+				.parse()
+				.map_err(|_| invalid())?
+		};
+
+		let (units, nanos) = if is_negative {
+			(-units, -nanos)
+		} else {
+			(units, nanos)
+		};
+
+		Self::new(currency_code, units, nanos)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::testkit::money;
+
+	#[test]
+	fn localized_strings() {
+		let eur = money("EUR", 1234, 560_000_000);
+
+		assert_eq!(eur.to_localized_string(&LocaleSpec::DE_DE), "1.234,56 €");
+		assert_eq!(
+			eur.to_localized_string(&LocaleSpec::FR_FR),
+			"1\u{202F}234,56 €"
+		);
+		assert_eq!(eur.to_localized_string(&LocaleSpec::NL_NL), "€ 1.234,56");
+		assert_eq!(
+			money("USD", -1_234_567, -500_000_000).to_localized_string(&LocaleSpec::EN_US),
+			"-$1,234,567.50"
+		);
+		assert_eq!(
+			money("JPY", 1234, 0).to_localized_string(&LocaleSpec::JA_JP),
+			"¥1,234"
+		);
+		assert_eq!(
+			money("CHF", 999, 0).to_localized_string(&LocaleSpec::DE_CH),
+			"CHF 999.00"
+		);
+		assert_eq!(
+			money("XYZ", 1000, 0).to_localized_string(&LocaleSpec::EN_US),
+			"XYZ1,000.00"
+		);
+	}
+
+	#[test]
+	fn parse_localized() {
+		assert_eq!(
+			Money::parse_localized("1.234,56 €", &LocaleSpec::DE_DE),
+			Ok(money("EUR", 1234, 560_000_000))
+		);
+		assert_eq!(
+			Money::parse_localized("$1,234.56", &LocaleSpec::EN_US),
+			Ok(money("USD", 1234, 560_000_000))
+		);
+		assert_eq!(
+			Money::parse_localized("-$0.5", &LocaleSpec::EN_US),
+			Ok(money("USD", 0, -500_000_000))
+		);
+		assert_eq!(
+			Money::parse_localized("(1,234.56)", &LocaleSpec::EN_US.with_currency("USD")),
+			Ok(money("USD", -1234, -560_000_000))
+		);
+		assert_eq!(
+			Money::parse_localized("1\u{202F}234,5 €", &LocaleSpec::FR_FR),
+			Ok(money("EUR", 1234, 500_000_000))
+		);
+		assert_eq!(
+			Money::parse_localized("CHF 1’000.05", &LocaleSpec::DE_CH),
+			Ok(money("CHF", 1000, 50_000_000))
+		);
+	}
+
+	#[test]
+	fn parse_localized_failures() {
+		assert_eq!(
+			Money::parse_localized("(1,234.56)", &LocaleSpec::EN_US),
+			Err(MoneyError::UnknownCurrency(String::new()))
+		);
+		assert_eq!(
+			Money::parse_localized("1,234.56 ?", &LocaleSpec::EN_US),
+			Err(MoneyError::UnknownCurrency("?".into()))
+		);
+		assert!(matches!(
+			Money::parse_localized("$1.234,56", &LocaleSpec::EN_US),
+			Err(MoneyError::InvalidAmount(_))
+		));
+		assert!(matches!(
+			Money::parse_localized("$1.1234567891", &LocaleSpec::EN_US),
+			Err(MoneyError::InvalidAmount(_))
+		));
+		assert!(matches!(
+			Money::parse_localized("$ €", &LocaleSpec::EN_US),
+			Err(MoneyError::InvalidAmount(_))
+		));
+	}
+}
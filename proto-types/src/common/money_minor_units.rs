@@ -0,0 +1,353 @@
+//! Amounts expressed as an integer number of the smallest unit of an ISO 4217 currency, such as cents.
+
+use crate::{
+	ProtoValidate, RoundingMode, ToString,
+	common::{Money, currency::Currency, money::MoneyError},
+};
+
+const NANO_FACTOR: i128 = 1_000_000_000;
+
+/// An amount in the smallest unit of a known ISO 4217 currency, such as `1234` cents for `USD 12.34`.
+///
+/// This is the representation used by most payment processors. The conversions to and from [`Money`] use the minor units from the [`Currency`] registry, so that the scale is never guessed.
+/// Conversions from [`Money`] are exact, and fail rather than silently dropping the fractions of a minor unit (use [`from_money_rounded`](Self::from_money_rounded) to round them instead).
+///
+/// With the `serde` feature, it is (de)serialized as `{ "currency": "USD", "amount": 1234 }`, and unknown currencies are rejected.
+///
+/// # Examples
+/// ```rust
+/// use proto_types::{MinorUnits, Money};
+///
+/// let price = MinorUnits::new("USD", 1_234).unwrap();
+/// assert_eq!(Money::from(price), Money::new("USD", 12, 340_000_000).unwrap());
+///
+/// let yen = Money::new("JPY", 500, 0).unwrap();
+/// assert_eq!(MinorUnits::try_from(&yen).unwrap().amount(), 500);
+///
+/// // Fractions of a cent cannot be represented
+/// assert!(MinorUnits::try_from(&Money::new("USD", 0, 1_000_000).unwrap()).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+	feature = "serde",
+	derive(serde::Serialize, serde::Deserialize),
+	serde(try_from = "MinorUnitsRepr", into = "MinorUnitsRepr")
+)]
+pub struct MinorUnits {
+	currency: &'static Currency,
+	amount: i64,
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct MinorUnitsRepr {
+	currency: crate::String,
+	amount: i64,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<MinorUnitsRepr> for MinorUnits {
+	type Error = MoneyError;
+
+	#[inline]
+	fn try_from(value: MinorUnitsRepr) -> Result<Self, Self::Error> {
+		Self::new(&value.currency, value.amount)
+	}
+}
+
+#[cfg(feature = "serde")]
+impl From<MinorUnits> for MinorUnitsRepr {
+	#[inline]
+	fn from(value: MinorUnits) -> Self {
+		Self {
+			currency: value.currency.code.to_string(),
+			amount: value.amount,
+		}
+	}
+}
+
+impl MinorUnits {
+	/// Creates a new amount, checking that the currency code (case-insensitive) is in the [`Currency`] registry.
+	pub fn new(currency_code: &str, amount: i64) -> Result<Self, MoneyError> {
+		let currency = Currency::from_code(currency_code)
+			.ok_or_else(|| MoneyError::UnknownCurrency(currency_code.to_string()))?;
+
+		Ok(Self { currency, amount })
+	}
+
+	/// Creates a new amount for a currency taken from the registry.
+	#[must_use]
+	#[inline]
+	pub const fn from_currency(currency: &'static Currency, amount: i64) -> Self {
+		Self { currency, amount }
+	}
+
+	/// Returns the currency of this amount.
+	#[must_use]
+	#[inline]
+	pub const fn currency(&self) -> &'static Currency {
+		self.currency
+	}
+
+	/// Returns the ISO 4217 code of the currency of this amount.
+	#[must_use]
+	#[inline]
+	pub const fn currency_code(&self) -> &'static str {
+		self.currency.code
+	}
+
+	/// Returns the amount, in minor units.
+	#[must_use]
+	#[inline]
+	pub const fn amount(&self) -> i64 {
+		self.amount
+	}
+
+	/// The amount of nanos in one minor unit of the currency.
+	const fn nanos_per_minor_unit(currency: &Currency) -> i128 {
+		// The registry never goes beyond 9 decimal places, but the exponent saturates so that it cannot underflow
+		10_i128.pow(9_u32.saturating_sub(currency.minor_units))
+	}
+
+	/// Converts a [`Money`] amount into minor units, rounding the fractions of a minor unit with the given mode.
+	pub fn from_money_rounded(money: &Money, rounding: RoundingMode) -> Result<Self, MoneyError> {
+		let (currency, total_nanos) = Self::checked_money(money)?;
+		let amount = rounding.div(total_nanos, Self::nanos_per_minor_unit(currency));
+
+		Ok(Self {
+			currency,
+			amount: i64::try_from(amount).map_err(|_| MoneyError::OutOfRange)?,
+		})
+	}
+
+	fn checked_money(money: &Money) -> Result<(&'static Currency, i128), MoneyError> {
+		money.validate()?;
+
+		let currency = Currency::from_code(&money.currency_code)
+			.ok_or_else(|| MoneyError::UnknownCurrency(money.currency_code.clone()))?;
+
+		Ok((currency, money.total_nanos()))
+	}
+
+	fn check_currency(&self, other: &Self) -> Result<(), MoneyError> {
+		if self.currency != other.currency {
+			return Err(MoneyError::CurrencyMismatch {
+				expected: self.currency.code.to_string(),
+				found: other.currency.code.to_string(),
+			});
+		}
+
+		Ok(())
+	}
+
+	/// Adds another amount to this one. Returns an error if the currencies do not match or if the result overflows.
+	pub fn try_add(&self, other: &Self) -> Result<Self, MoneyError> {
+		self.check_currency(other)?;
+
+		Ok(Self {
+			currency: self.currency,
+			amount: self
+				.amount
+				.checked_add(other.amount)
+				.ok_or(MoneyError::OutOfRange)?,
+		})
+	}
+
+	/// Subtracts another amount from this one. Returns an error if the currencies do not match or if the result overflows.
+	pub fn try_sub(&self, other: &Self) -> Result<Self, MoneyError> {
+		self.check_currency(other)?;
+
+		Ok(Self {
+			currency: self.currency,
+			amount: self
+				.amount
+				.checked_sub(other.amount)
+				.ok_or(MoneyError::OutOfRange)?,
+		})
+	}
+
+	/// Multiplies the amount by an integer. Returns an error if the result overflows.
+	pub fn try_mul_i64(&self, rhs: i64) -> Result<Self, MoneyError> {
+		Ok(Self {
+			currency: self.currency,
+			amount: self
+				.amount
+				.checked_mul(rhs)
+				.ok_or(MoneyError::OutOfRange)?,
+		})
+	}
+
+	/// Negates the amount. Returns an error if the result overflows.
+	pub fn try_neg(&self) -> Result<Self, MoneyError> {
+		Ok(Self {
+			currency: self.currency,
+			amount: self
+				.amount
+				.checked_neg()
+				.ok_or(MoneyError::OutOfRange)?,
+		})
+	}
+}
+
+impl From<MinorUnits> for Money {
+	/// Converts the amount into [`Money`]. This is always exact.
+	fn from(value: MinorUnits) -> Self {
+		let nanos_per_minor_unit = MinorUnits::nanos_per_minor_unit(value.currency);
+		let minor_units_per_unit = NANO_FACTOR / nanos_per_minor_unit;
+		let amount = i128::from(value.amount);
+
+		// SAFETY: The units are at most the amount itself, and the nanos are always below 10^9 in absolute value
+		#[allow(clippy::cast_possible_truncation)]
+		let (units, nanos) = (
+			(amount / minor_units_per_unit) as i64,
+			((amount % minor_units_per_unit) * nanos_per_minor_unit) as i32,
+		);
+
+		Self {
+			currency_code: value.currency.code.to_string(),
+			units,
+			nanos,
+		}
+	}
+}
+
+impl TryFrom<&Money> for MinorUnits {
+	type Error = MoneyError;
+
+	/// Converts the amount into minor units, failing if it contains fractions of a minor unit or if the currency is unknown.
+	fn try_from(money: &Money) -> Result<Self, Self::Error> {
+		let (currency, total_nanos) = Self::checked_money(money)?;
+
+		if total_nanos % Self::nanos_per_minor_unit(currency) != 0 {
+			return Err(MoneyError::InexactMinorUnits {
+				currency: currency.code.to_string(),
+				minor_units: currency.minor_units,
+			});
+		}
+
+		Self::from_money_rounded(money, RoundingMode::Floor)
+	}
+}
+
+impl TryFrom<Money> for MinorUnits {
+	type Error = MoneyError;
+
+	#[inline]
+	fn try_from(money: Money) -> Result<Self, Self::Error> {
+		Self::try_from(&money)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn money_conversions() {
+		for (code, amount, units, nanos) in [
+			("USD", 1_234, 12, 340_000_000),
+			("USD", -5, 0, -50_000_000),
+			("JPY", 500, 500, 0),
+			("KWD", -1_001, -1, -1_000_000),
+			("EUR", i64::MAX, i64::MAX / 100, 70_000_000),
+		] {
+			let minor = MinorUnits::new(code, amount).unwrap();
+			let money = Money::new(code, units, nanos).unwrap();
+
+			assert_eq!(Money::from(minor), money);
+			assert_eq!(MinorUnits::try_from(&money), Ok(minor));
+		}
+
+		let fraction_of_cent = Money::new("USD", 1, 5_000_000).unwrap();
+		assert_eq!(
+			MinorUnits::try_from(&fraction_of_cent),
+			Err(MoneyError::InexactMinorUnits {
+				currency: "USD".into(),
+				minor_units: 2
+			})
+		);
+		assert_eq!(
+			MinorUnits::from_money_rounded(&fraction_of_cent, RoundingMode::HalfEven)
+				.unwrap()
+				.amount(),
+			100
+		);
+		assert_eq!(
+			MinorUnits::from_money_rounded(&fraction_of_cent, RoundingMode::Ceil)
+				.unwrap()
+				.amount(),
+			101
+		);
+
+		assert_eq!(
+			MinorUnits::try_from(Money::new("JPY", i64::MAX, 0).unwrap()).map(|m| m.amount()),
+			Ok(i64::MAX)
+		);
+		assert_eq!(
+			MinorUnits::try_from(Money::new("USD", i64::MAX, 0).unwrap()),
+			Err(MoneyError::OutOfRange)
+		);
+		assert!(matches!(
+			MinorUnits::try_from(Money::new("XYZ", 1, 0).unwrap()),
+			Err(MoneyError::UnknownCurrency(_))
+		));
+		assert!(matches!(
+			MinorUnits::try_from(Money::new("usd", 1, 0).unwrap()),
+			Err(MoneyError::InvalidCurrencyCode(_))
+		));
+		assert_eq!(
+			MinorUnits::new("usd", 1).map(|m| m.currency_code()),
+			Ok("USD")
+		);
+	}
+
+	#[test]
+	fn checked_arithmetic() {
+		let a = MinorUnits::new("usd", 150).unwrap();
+		let b = MinorUnits::new("USD", 75).unwrap();
+
+		assert_eq!(a.currency_code(), "USD");
+		assert_eq!(a.try_add(&b).unwrap().amount(), 225);
+		assert_eq!(a.try_sub(&b).unwrap().amount(), 75);
+		assert_eq!(a.try_mul_i64(-3).unwrap().amount(), -450);
+		assert_eq!(b.try_neg().unwrap().amount(), -75);
+
+		assert!(matches!(
+			a.try_add(&MinorUnits::new("EUR", 1).unwrap()),
+			Err(MoneyError::CurrencyMismatch { .. })
+		));
+		assert_eq!(
+			MinorUnits::new("USD", i64::MAX)
+				.unwrap()
+				.try_add(&b),
+			Err(MoneyError::OutOfRange)
+		);
+		assert_eq!(
+			MinorUnits::new("USD", i64::MIN)
+				.unwrap()
+				.try_neg(),
+			Err(MoneyError::OutOfRange)
+		);
+	}
+
+	#[cfg(feature = "serde")]
+	#[test]
+	fn serde_representation() {
+		let amount = MinorUnits::new("EUR", 999).unwrap();
+
+		let json = serde_json::to_value(amount).unwrap();
+		assert_eq!(
+			json,
+			serde_json::json!({ "currency": "EUR", "amount": 999 })
+		);
+		assert_eq!(serde_json::from_value::<MinorUnits>(json).unwrap(), amount);
+
+		assert!(
+			serde_json::from_value::<MinorUnits>(
+				serde_json::json!({ "currency": "XYZ", "amount": 1 })
+			)
+			.is_err()
+		);
+	}
+}
@@ -0,0 +1,105 @@
+//! Operator implementations for [`Money`], which panic on currency mismatches and overflows.
+//!
+//! Only use these after checking that the currencies of the operands match. The `try_*` methods are the non-panicking alternative.
+
+use core::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+use crate::common::Money;
+
+impl Add for Money {
+	type Output = Self;
+
+	#[inline]
+	fn add(self, rhs: Self) -> Self::Output {
+		self.try_add(&rhs)
+			.expect("currency mismatch or overflow in money addition")
+	}
+}
+
+impl AddAssign for Money {
+	#[inline]
+	fn add_assign(&mut self, rhs: Self) {
+		self.try_add_assign(&rhs)
+			.expect("currency mismatch or overflow in money addition");
+	}
+}
+
+impl Sub for Money {
+	type Output = Self;
+
+	#[inline]
+	fn sub(self, rhs: Self) -> Self::Output {
+		self.try_sub(&rhs)
+			.expect("currency mismatch or overflow in money subtraction")
+	}
+}
+
+impl SubAssign for Money {
+	#[inline]
+	fn sub_assign(&mut self, rhs: Self) {
+		self.try_sub_assign(&rhs)
+			.expect("currency mismatch or overflow in money subtraction");
+	}
+}
+
+impl Neg for Money {
+	type Output = Self;
+
+	#[inline]
+	fn neg(self) -> Self::Output {
+		self.try_neg()
+			.expect("overflow in money negation")
+	}
+}
+
+impl Mul<i64> for Money {
+	type Output = Self;
+
+	#[inline]
+	fn mul(self, rhs: i64) -> Self::Output {
+		self.try_mul_i64(rhs)
+			.expect("Money multiplication by i64 overflowed")
+	}
+}
+
+impl Div<i64> for Money {
+	type Output = Self;
+
+	#[inline]
+	fn div(self, rhs: i64) -> Self::Output {
+		self.try_div_i64(rhs)
+			.expect("Money division by i64 overflowed or divided by zero")
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::testkit::money;
+
+	#[test]
+	fn operators() {
+		assert_eq!(
+			money("USD", 1, 500_000_000) + money("USD", 2, 600_000_000),
+			money("USD", 4, 100_000_000)
+		);
+		assert_eq!(
+			money("USD", 1, 0) - money("USD", 2, 500_000_000),
+			money("USD", -1, -500_000_000)
+		);
+		assert_eq!(-money("USD", 3, 0), money("USD", -3, 0));
+		assert_eq!(money("USD", 1, 250_000_000) * 4, money("USD", 5, 0));
+		assert_eq!(money("USD", 5, 0) / 2, money("USD", 2, 500_000_000));
+
+		let mut total = money("USD", 0, 0);
+		total += money("USD", 10, 0);
+		total -= money("USD", 2, 0);
+		assert_eq!(total, money("USD", 8, 0));
+	}
+
+	#[test]
+	#[should_panic(expected = "currency mismatch")]
+	fn mismatched_currencies() {
+		let _ = money("USD", 1, 0) + Money::new("EUR", 1, 0).unwrap();
+	}
+}
@@ -0,0 +1,192 @@
+//! Usage-based prices for [`Money`], expressed as an amount per [`Duration`].
+
+use crate::{
+	Duration, ProtoValidate, RoundingMode,
+	common::{Money, currency::Currency, money::MoneyError},
+	integer::gcd_i128,
+};
+
+/// A price that is charged for every `per` of usage, such as `USD 0.000016` per second.
+///
+/// Costs are computed with exact integer arithmetic on the nanos of both the amount and the durations,
+/// and the result is rounded with the configured [`RoundingMode`] (by default, [`RoundingMode::HalfEven`]).
+///
+/// # Examples
+/// ```rust
+/// use proto_types::{Duration, Money, PricePerDuration};
+///
+/// let rate = PricePerDuration::new(Money::new("USD", 0, 16_000).unwrap(), Duration::new(1, 0)).unwrap();
+///
+/// assert_eq!(
+///   rate.cost_of(&Duration::new(3_600, 0)).unwrap(),
+///   Money::new("USD", 0, 57_600_000).unwrap()
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PricePerDuration {
+	money: Money,
+	per: Duration,
+	rounding: RoundingMode,
+}
+
+impl PricePerDuration {
+	/// Creates a new rate, checking that the amount is valid and that the duration is positive.
+	pub fn new(money: Money, per: Duration) -> Result<Self, MoneyError> {
+		money.validate()?;
+
+		if per.total_nanos() <= 0 {
+			return Err(MoneyError::InvalidRate);
+		}
+
+		Ok(Self {
+			money,
+			per,
+			rounding: RoundingMode::HalfEven,
+		})
+	}
+
+	/// Sets the rounding mode used for the costs computed with this rate.
+	#[must_use]
+	#[inline]
+	pub const fn with_rounding(mut self, rounding: RoundingMode) -> Self {
+		self.rounding = rounding;
+		self
+	}
+
+	/// Returns the amount charged for every [`per`](Self::per) of usage.
+	#[must_use]
+	#[inline]
+	pub const fn money(&self) -> &Money {
+		&self.money
+	}
+
+	/// Returns the unit of usage that the amount refers to.
+	#[must_use]
+	#[inline]
+	pub const fn per(&self) -> Duration {
+		self.per
+	}
+
+	/// Returns the rounding mode used for the costs computed with this rate.
+	#[must_use]
+	#[inline]
+	pub const fn rounding(&self) -> RoundingMode {
+		self.rounding
+	}
+
+	/// Returns the cost of the given usage, rounded to the nanos.
+	pub fn cost_of(&self, usage: &Duration) -> Result<Money, MoneyError> {
+		self.cost_in_units_of(usage, 1)
+	}
+
+	/// Returns the cost of the given usage, rounded to the minor unit of the currency (taken from the [`Currency`] registry, or 2 decimal places if unknown).
+	pub fn cost_of_in_minor_units(&self, usage: &Duration) -> Result<Money, MoneyError> {
+		let minor_units =
+			Currency::from_code(&self.money.currency_code).map_or(2, |c| c.minor_units);
+
+		self.cost_in_units_of(usage, 10_i128.pow(9_u32.saturating_sub(minor_units)))
+	}
+
+	/// Computes `money * usage / per`, rounded to a multiple of `unit` nanos.
+	fn cost_in_units_of(&self, usage: &Duration, unit: i128) -> Result<Money, MoneyError> {
+		let (usage, per) = (usage.total_nanos(), self.per.total_nanos());
+
+		// Reducing the ratio first keeps the intermediate product small for common rates, such as per second or per hour
+		// SAFETY: The divisor is at most the magnitude of `per`, which fits in an i128
+		let divisor = gcd_i128(usage, per).cast_signed();
+		let (usage, per) = (usage / divisor, per / divisor);
+
+		let scaled = self
+			.money
+			.total_nanos()
+			.checked_mul(usage)
+			.ok_or(MoneyError::OutOfRange)?;
+
+		let units = self.rounding.div(
+			scaled,
+			per.checked_mul(unit)
+				.ok_or(MoneyError::OutOfRange)?,
+		);
+
+		Money::from_total_nanos(
+			self.money.currency_code.clone(),
+			units
+				.checked_mul(unit)
+				.ok_or(MoneyError::OutOfRange)?,
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::testkit::money;
+
+	#[test]
+	fn exact_costs() {
+		let per_second =
+			PricePerDuration::new(money("USD", 0, 16_000), Duration::new(1, 0)).unwrap();
+
+		assert_eq!(
+			per_second.cost_of(&Duration::new(3_600, 0)),
+			Ok(money("USD", 0, 57_600_000))
+		);
+		assert_eq!(
+			per_second.cost_of(&Duration::new(0, 500_000_000)),
+			Ok(money("USD", 0, 8_000))
+		);
+		assert_eq!(
+			per_second.cost_of_in_minor_units(&Duration::new(3_600, 0)),
+			Ok(money("USD", 0, 60_000_000))
+		);
+
+		let per_year =
+			PricePerDuration::new(money("USD", 1_000_000, 0), Duration::new(31_536_000, 0))
+				.unwrap();
+		assert_eq!(
+			per_year.cost_of(&Duration::new(315_360_000, 0)),
+			Ok(money("USD", 10_000_000, 0))
+		);
+	}
+
+	#[test]
+	fn rounding_modes() {
+		let rate = PricePerDuration::new(money("USD", 1, 0), Duration::new(3, 0)).unwrap();
+		let second = Duration::new(1, 0);
+
+		assert_eq!(rate.cost_of(&second), Ok(money("USD", 0, 333_333_333)));
+		assert_eq!(
+			rate.clone()
+				.with_rounding(RoundingMode::Ceil)
+				.cost_of(&second),
+			Ok(money("USD", 0, 333_333_334))
+		);
+		assert_eq!(
+			rate.cost_of_in_minor_units(&second),
+			Ok(money("USD", 0, 330_000_000))
+		);
+		assert_eq!(
+			rate.with_rounding(RoundingMode::Ceil)
+				.cost_of_in_minor_units(&second),
+			Ok(money("USD", 0, 340_000_000))
+		);
+	}
+
+	#[test]
+	fn invalid_rates() {
+		assert_eq!(
+			PricePerDuration::new(money("USD", 1, 0), Duration::default()),
+			Err(MoneyError::InvalidRate)
+		);
+		assert_eq!(
+			PricePerDuration::new(money("USD", 1, 0), Duration::new(-1, 0)),
+			Err(MoneyError::InvalidRate)
+		);
+
+		let rate = PricePerDuration::new(money("USD", i64::MAX, 0), Duration::new(0, 1)).unwrap();
+		assert_eq!(
+			rate.cost_of(&Duration::new(i64::MAX, 0)),
+			Err(MoneyError::OutOfRange)
+		);
+	}
+}
@@ -2,7 +2,7 @@ use core::{cmp::Ordering, fmt::Display};
 
 use thiserror::Error;
 
-use crate::{common::TimeOfDay, constants::NANOS_PER_SECOND};
+use crate::{ProtoValidate, common::TimeOfDay, constants::NANOS_PER_SECOND};
 
 const NANOS_PER_MINUTE: i64 = NANOS_PER_SECOND as i64 * 60;
 const NANOS_PER_HOUR: i64 = NANOS_PER_MINUTE * 60;
@@ -80,6 +80,15 @@ impl TryFrom<TimeOfDay> for chrono::NaiveTime {
 	}
 }
 
+impl ProtoValidate for TimeOfDay {
+	type Error = TimeOfDayError;
+
+	#[inline]
+	fn validate(&self) -> Result<(), Self::Error> {
+		validate_time_of_day(self.hours, self.minutes, self.seconds, self.nanos)
+	}
+}
+
 impl PartialOrd for TimeOfDay {
 	#[inline]
 	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
@@ -149,6 +158,41 @@ impl TimeOfDay {
 		validate_time_of_day(self.hours, self.minutes, self.seconds, self.nanos).is_ok()
 	}
 
+	/// Sets the hours, checking that they are within `0..=23`.
+	pub fn set_hours_checked(&mut self, hours: i32) -> Result<(), TimeOfDayError> {
+		validate_time_of_day(hours, self.minutes, self.seconds, self.nanos)?;
+		self.hours = hours;
+		Ok(())
+	}
+
+	/// Sets the minutes, checking that they are within `0..=59`.
+	pub fn set_minutes_checked(&mut self, minutes: i32) -> Result<(), TimeOfDayError> {
+		validate_time_of_day(self.hours, minutes, self.seconds, self.nanos)?;
+		self.minutes = minutes;
+		Ok(())
+	}
+
+	/// Sets the seconds, checking that they are within `0..=59`.
+	pub fn set_seconds_checked(&mut self, seconds: i32) -> Result<(), TimeOfDayError> {
+		validate_time_of_day(self.hours, self.minutes, seconds, self.nanos)?;
+		self.seconds = seconds;
+		Ok(())
+	}
+
+	/// Sets the nanos, checking that they are within `0..=999_999_999`.
+	pub fn set_nanos_checked(&mut self, nanos: i32) -> Result<(), TimeOfDayError> {
+		validate_time_of_day(self.hours, self.minutes, self.seconds, nanos)?;
+		self.nanos = nanos;
+		Ok(())
+	}
+
+	/// The latest time of day that can be represented, `23:59:59.999999999`.
+	pub const MAX: Self = Self {
+		hours: 23,
+		minutes: 59,
+		seconds: 59,
+		nanos: 999_999_999,
+	};
 	pub const MIDNIGHT: Self = Self {
 		hours: 0,
 		minutes: 0,
@@ -337,6 +381,10 @@ mod tests {
 		assert_eq!(noon.hours, 12);
 		// 12 * 60 * 60 * 1e9
 		assert_eq!(noon.nanos_since_midnight(), 12 * 3600 * 1_000_000_000);
+
+		let max = TimeOfDay::MAX;
+		assert!(TimeOfDay::new(max.hours, max.minutes, max.seconds, max.nanos).is_ok());
+		assert_eq!(max.nanos_since_midnight(), 86_400 * 1_000_000_000 - 1);
 	}
 
 	#[test]
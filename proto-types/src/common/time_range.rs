@@ -0,0 +1,209 @@
+use crate::{
+	Duration, ProtoValidate, Vec,
+	common::{TimeOfDay, time_of_day::TimeOfDayError},
+	constants::NANOS_PER_SECOND,
+};
+
+const NANOS_PER_DAY: i64 = 86_400 * NANOS_PER_SECOND as i64;
+
+/// A recurring daily range of time, from `start` (inclusive) to `end` (exclusive), such as opening hours or a maintenance window.
+///
+/// If `end` is before `start`, the range wraps past midnight (so `22:00-06:00` covers the night).
+/// If `end` is equal to `start`, the range covers the whole day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimeRange {
+	pub start: TimeOfDay,
+	pub end: TimeOfDay,
+}
+
+impl TimeRange {
+	/// Creates a new [`TimeRange`], checking that both times of day are valid.
+	pub fn new(start: TimeOfDay, end: TimeOfDay) -> Result<Self, TimeOfDayError> {
+		start.validate()?;
+		end.validate()?;
+
+		Ok(Self { start, end })
+	}
+
+	/// The range covering the whole day.
+	pub const FULL_DAY: Self = Self {
+		start: TimeOfDay::MIDNIGHT,
+		end: TimeOfDay::MIDNIGHT,
+	};
+
+	/// Checks if the range wraps past midnight, which is also the case for ranges that cover the whole day.
+	#[must_use]
+	#[inline]
+	pub fn wraps_midnight(&self) -> bool {
+		self.end <= self.start
+	}
+
+	/// Checks if the range contains the given time of day.
+	#[must_use]
+	#[inline]
+	pub fn contains(&self, time: TimeOfDay) -> bool {
+		if self.wraps_midnight() {
+			time >= self.start || time < self.end
+		} else {
+			time >= self.start && time < self.end
+		}
+	}
+
+	/// Returns the length of the range, which is 24 hours for ranges that cover the whole day.
+	#[must_use]
+	pub fn duration(&self) -> Duration {
+		let nanos = (self.end.nanos_since_midnight() - self.start.nanos_since_midnight())
+			.rem_euclid(NANOS_PER_DAY);
+
+		let nanos = if nanos == 0 { NANOS_PER_DAY } else { nanos };
+
+		// SAFETY: The remainder is always within 0..NANOS_PER_SECOND
+		#[allow(clippy::cast_possible_truncation)]
+		let subsec_nanos = (nanos % i64::from(NANOS_PER_SECOND)) as i32;
+
+		Duration::new(nanos / i64::from(NANOS_PER_SECOND), subsec_nanos)
+	}
+
+	/// Returns the ranges of time that are contained in both ranges, sorted by their start.
+	///
+	/// The result is empty if the ranges do not overlap, and it can contain two ranges if both of them wrap past midnight
+	/// (for example, `22:00-06:00` and `05:00-23:00` intersect in `05:00-06:00` and `22:00-23:00`).
+	#[must_use]
+	pub fn intersect(&self, other: &Self) -> Vec<Self> {
+		let mut pieces: Vec<(i64, i64)> = Vec::new();
+
+		for (a_start, a_end) in self.segments().into_iter().flatten() {
+			for (b_start, b_end) in other.segments().into_iter().flatten() {
+				let (start, end) = (a_start.max(b_start), a_end.min(b_end));
+
+				if start < end {
+					pieces.push((start, end));
+				}
+			}
+		}
+
+		pieces.sort_unstable();
+
+		// Join a piece that ends at midnight with one that starts at midnight into a single wrapping range
+		if pieces.len() > 1 && pieces[0].0 == 0 && pieces[pieces.len() - 1].1 == NANOS_PER_DAY {
+			let (_, end) = pieces.remove(0);
+			let last = pieces.len() - 1;
+			pieces[last].1 = end;
+		}
+
+		pieces
+			.into_iter()
+			.map(|(start, end)| Self {
+				start: time_of_day(start),
+				end: time_of_day(end % NANOS_PER_DAY),
+			})
+			.collect()
+	}
+
+	/// Splits the range into at most two non-wrapping segments of nanoseconds since midnight, with exclusive ends.
+	fn segments(&self) -> [Option<(i64, i64)>; 2] {
+		let (start, end) = (
+			self.start.nanos_since_midnight(),
+			self.end.nanos_since_midnight(),
+		);
+
+		if start < end {
+			[Some((start, end)), None]
+		} else {
+			[Some((start, NANOS_PER_DAY)), (end > 0).then_some((0, end))]
+		}
+	}
+}
+
+/// Converts nanoseconds since midnight (within a day) into a [`TimeOfDay`].
+const fn time_of_day(nanos: i64) -> TimeOfDay {
+	let seconds = nanos / NANOS_PER_SECOND as i64;
+
+	// SAFETY: All the components are bounded by the length of a day
+	#[allow(clippy::cast_possible_truncation)]
+	TimeOfDay {
+		hours: (seconds / 3_600) as i32,
+		minutes: (seconds / 60 % 60) as i32,
+		seconds: (seconds % 60) as i32,
+		nanos: (nanos % NANOS_PER_SECOND as i64) as i32,
+	}
+}
+
+impl ProtoValidate for TimeRange {
+	type Error = TimeOfDayError;
+
+	#[inline]
+	fn validate(&self) -> Result<(), Self::Error> {
+		self.start.validate()?;
+		self.end.validate()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use alloc::vec;
+
+	use super::*;
+
+	fn range(start: i32, end: i32) -> TimeRange {
+		TimeRange::new(
+			TimeOfDay::new(start, 0, 0, 0).unwrap(),
+			TimeOfDay::new(end, 0, 0, 0).unwrap(),
+		)
+		.unwrap()
+	}
+
+	fn hour(hours: i32) -> TimeOfDay {
+		TimeOfDay::new(hours, 0, 0, 0).unwrap()
+	}
+
+	#[test]
+	fn contains_and_duration() {
+		let night = range(22, 6);
+
+		assert!(night.contains(hour(23)));
+		assert!(night.contains(hour(0)));
+		assert!(!night.contains(hour(6)));
+		assert!(!night.contains(hour(12)));
+		assert_eq!(night.duration(), Duration::new(8 * 3_600, 0));
+
+		let office = range(9, 17);
+		assert!(office.contains(hour(9)));
+		assert!(!office.contains(hour(17)));
+		assert_eq!(office.duration(), Duration::new(8 * 3_600, 0));
+
+		assert!(TimeRange::FULL_DAY.contains(hour(12)));
+		assert_eq!(range(5, 5).duration(), Duration::new(86_400, 0));
+	}
+
+	#[test]
+	fn intersections() {
+		assert_eq!(range(9, 17).intersect(&range(12, 20)), vec![range(12, 17)]);
+		assert_eq!(range(9, 17).intersect(&range(18, 20)), vec![]);
+		assert_eq!(range(22, 6).intersect(&range(4, 8)), vec![range(4, 6)]);
+		assert_eq!(
+			range(22, 6).intersect(&range(5, 23)),
+			vec![range(5, 6), range(22, 23)]
+		);
+		assert_eq!(range(22, 6).intersect(&range(23, 2)), vec![range(23, 2)]);
+		assert_eq!(
+			range(22, 6).intersect(&TimeRange::FULL_DAY),
+			vec![range(22, 6)]
+		);
+		assert_eq!(range(20, 0).intersect(&range(21, 3)), vec![range(21, 0)]);
+	}
+
+	#[test]
+	fn invalid_times() {
+		let invalid = TimeOfDay {
+			hours: 24,
+			..Default::default()
+		};
+
+		assert_eq!(
+			TimeRange::new(invalid, hour(1)),
+			Err(TimeOfDayError::InvalidHours)
+		);
+	}
+}
@@ -6,7 +6,10 @@
 
 use core::fmt;
 
-use crate::{Duration, Timestamp, constants::NANOS_PER_SECOND, timestamp::TimestampError};
+use crate::{
+	Duration, Timestamp, calendar::days_in_month, constants::NANOS_PER_SECOND,
+	timestamp::TimestampError,
+};
 
 /// A point in time, represented as a date and time in the UTC timezone.
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
@@ -83,22 +86,10 @@ impl DateTime {
 	}
 }
 
-impl fmt::Display for DateTime {
-	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-		// 1. Year formatting (RFC 3339 requires 4 digits, extended years have sign)
-		if self.year > 9999 {
-			write!(f, "+{}", self.year)?;
-		} else if self.year < 0 {
-			write!(f, "{:05}", self.year)?; // e.g., -0055
-		} else {
-			write!(f, "{:04}", self.year)?;
-		};
-
-		write!(
-			f,
-			"-{:02}-{:02}T{:02}:{:02}:{:02}",
-			self.month, self.day, self.hour, self.minute, self.second,
-		)?;
+impl DateTime {
+	/// Writes the RFC 3339 representation of this date and time, without allocating.
+	pub(crate) fn write_rfc3339<W: fmt::Write + ?Sized>(&self, w: &mut W) -> fmt::Result {
+		self.write_date_and_time(w)?;
 
 		// 2. Nanoseconds formatting (Fully trimmed)
 		if self.nanos > 0 {
@@ -112,11 +103,51 @@ impl fmt::Display for DateTime {
 				width -= 1;
 			}
 
-			write!(f, ".{n:0width$}Z")
+			write!(w, ".{n:0width$}Z")
 		} else {
-			write!(f, "Z")
+			write!(w, "Z")
 		}
 	}
+
+	/// Writes the RFC 3339 representation of this date and time with exactly `digits` fractional digits (at most 9), truncating the nanos.
+	pub(crate) fn write_rfc3339_fixed<W: fmt::Write + ?Sized>(
+		&self,
+		w: &mut W,
+		digits: u32,
+	) -> fmt::Result {
+		self.write_date_and_time(w)?;
+
+		if digits == 0 {
+			return write!(w, "Z");
+		}
+
+		let digits = digits.min(9);
+		let n = self.nanos / 10u32.pow(9 - digits);
+		write!(w, ".{n:0width$}Z", width = digits as usize)
+	}
+
+	fn write_date_and_time<W: fmt::Write + ?Sized>(&self, w: &mut W) -> fmt::Result {
+		// 1. Year formatting (RFC 3339 requires 4 digits, extended years have sign)
+		if self.year > 9999 {
+			write!(w, "+{}", self.year)?;
+		} else if self.year < 0 {
+			write!(w, "{:05}", self.year)?; // e.g., -0055
+		} else {
+			write!(w, "{:04}", self.year)?;
+		};
+
+		write!(
+			w,
+			"-{:02}-{:02}T{:02}:{:02}:{:02}",
+			self.month, self.day, self.hour, self.minute, self.second,
+		)
+	}
+}
+
+impl fmt::Display for DateTime {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		self.write_rfc3339(f)
+	}
 }
 
 impl From<Timestamp> for DateTime {
@@ -241,15 +272,6 @@ impl From<Timestamp> for DateTime {
 	}
 }
 
-/// Returns the number of days in the month.
-fn days_in_month(year: i64, month: u8) -> u8 {
-	const DAYS_IN_MONTH: [u8; 12] = [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
-
-	let (_, is_leap) = year_to_seconds(year);
-
-	DAYS_IN_MONTH[usize::from(month - 1)] + u8::from(is_leap && month == 2)
-}
-
 macro_rules! ensure {
 	($expr:expr) => {{
 		if !$expr {
@@ -0,0 +1,135 @@
+use bytes::Buf;
+use thiserror::Error;
+
+use crate::{DecodeError, Message, ProtoValidate, Validated};
+
+/// Errors that can occur while decoding and validating a message with [`decode_validated`].
+#[derive(Debug, Error, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum DecodeValidatedError<E> {
+	#[error("Failed to decode the message: {0}")]
+	Decode(#[from] DecodeError),
+	#[error("The decoded message is not valid: {0}")]
+	Invalid(E),
+}
+
+#[cfg(feature = "rpc")]
+impl<E: crate::Display> DecodeValidatedError<E> {
+	/// Converts the error into an `INVALID_ARGUMENT` [`Status`](crate::Status) that only carries the error message.
+	///
+	/// Errors that contain [`Violations`](crate::protovalidate::Violations) can be converted with [`From`] instead, which keeps the violations as a detail of the status.
+	#[must_use]
+	pub fn into_status(self) -> crate::Status {
+		crate::Status::builder(
+			crate::Code::InvalidArgument,
+			crate::ToString::to_string(&self),
+		)
+		.build()
+	}
+}
+
+#[cfg(all(feature = "rpc", feature = "protovalidate"))]
+impl From<DecodeValidatedError<crate::protovalidate::Violations>> for crate::Status {
+	fn from(value: DecodeValidatedError<crate::protovalidate::Violations>) -> Self {
+		match value {
+			DecodeValidatedError::Invalid(violations) => violations.into(),
+			DecodeValidatedError::Decode(error) => Self::builder(
+				crate::Code::InvalidArgument,
+				crate::format!("Failed to decode the message: {error}"),
+			)
+			.build(),
+		}
+	}
+}
+
+/// Decodes a message from the buffer and validates it right away, returning it wrapped in [`Validated`].
+///
+/// # Examples
+/// ```rust
+/// use prost::Message;
+/// use proto_types::{Timestamp, decode_validated, DecodeValidatedError};
+///
+/// let valid = Timestamp::new(1_714_557_600, 0).encode_to_vec();
+/// assert!(decode_validated::<Timestamp>(valid.as_slice()).is_ok());
+///
+/// let invalid = Timestamp { seconds: 0, nanos: -1 }.encode_to_vec();
+/// assert!(matches!(
+///   decode_validated::<Timestamp>(invalid.as_slice()),
+///   Err(DecodeValidatedError::Invalid(_))
+/// ));
+/// ```
+#[inline]
+pub fn decode_validated<M>(buf: impl Buf) -> Result<Validated<M>, DecodeValidatedError<M::Error>>
+where
+	M: Message + Default + ProtoValidate,
+{
+	Validated::new(M::decode(buf)?).map_err(DecodeValidatedError::Invalid)
+}
+
+/// An extension trait for messages that can be decoded and validated in a single call.
+pub trait DecodeValidatedExt: Message + Default + ProtoValidate + Sized {
+	/// Decodes a message from the buffer and validates it right away. See [`decode_validated`].
+	#[inline]
+	fn decode_validated(
+		buf: impl Buf,
+	) -> Result<Validated<Self>, DecodeValidatedError<Self::Error>> {
+		decode_validated(buf)
+	}
+
+	/// Decodes a length-delimited message from the buffer and validates it right away.
+	#[inline]
+	fn decode_length_delimited_validated(
+		buf: impl Buf,
+	) -> Result<Validated<Self>, DecodeValidatedError<Self::Error>> {
+		Validated::new(Self::decode_length_delimited(buf)?).map_err(DecodeValidatedError::Invalid)
+	}
+}
+
+impl<M: Message + Default + ProtoValidate> DecodeValidatedExt for M {}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{Duration, Timestamp};
+
+	#[test]
+	fn decode_then_validate() {
+		let valid = Duration::new(5, 0).encode_to_vec();
+		assert_eq!(
+			Duration::decode_validated(valid.as_slice())
+				.unwrap()
+				.into_inner(),
+			Duration::new(5, 0)
+		);
+
+		let invalid = Timestamp {
+			seconds: 0,
+			nanos: -1,
+		}
+		.encode_length_delimited_to_vec();
+		assert!(matches!(
+			Timestamp::decode_length_delimited_validated(invalid.as_slice()),
+			Err(DecodeValidatedError::Invalid(report)) if !report.is_ok()
+		));
+
+		assert!(matches!(
+			decode_validated::<Timestamp>([0xff_u8].as_slice()),
+			Err(DecodeValidatedError::Decode(_))
+		));
+	}
+
+	#[cfg(all(feature = "rpc", feature = "protovalidate"))]
+	#[test]
+	fn status_conversion() {
+		use crate::{Code, Status, protovalidate::Violations};
+
+		let decode_error = decode_validated::<Timestamp>([0xff_u8].as_slice()).unwrap_err();
+		let status = decode_error.into_status();
+		assert_eq!(status.code, Code::InvalidArgument as i32);
+		assert!(status.details.is_empty());
+
+		let status: Status = DecodeValidatedError::Invalid(Violations::default()).into();
+		assert_eq!(status.code, Code::InvalidArgument as i32);
+		assert_eq!(status.details.len(), 1);
+	}
+}
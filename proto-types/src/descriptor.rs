@@ -0,0 +1,274 @@
+use ::prost::alloc::collections::BTreeMap;
+use prost::encoding::{DecodeContext, decode_key, skip_field};
+
+use crate::{
+	Any, DecodeError, DescriptorProto, FileDescriptorProto, FileDescriptorSet, Message, String,
+	TypeUrl, Vec, format,
+};
+
+/// A collection of message descriptors, indexed by their fully qualified name (without the leading dot).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DescriptorRegistry {
+	messages: BTreeMap<String, DescriptorProto>,
+}
+
+impl DescriptorRegistry {
+	/// Creates a new, empty registry.
+	#[must_use]
+	#[inline]
+	pub const fn new() -> Self {
+		Self {
+			messages: BTreeMap::new(),
+		}
+	}
+
+	/// Decodes a serialized [`FileDescriptorSet`] (such as the output of `protoc --descriptor_set_out`) and registers all of its messages.
+	pub fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+		Ok(Self::from(FileDescriptorSet::decode(bytes)?))
+	}
+
+	/// Registers all the messages (including the nested ones) defined in the given file.
+	pub fn add_file(&mut self, file: FileDescriptorProto) {
+		let prefix = file.package.unwrap_or_default();
+
+		for message in file.message_type {
+			self.add_message(&prefix, message);
+		}
+	}
+
+	fn add_message(&mut self, prefix: &str, mut message: DescriptorProto) {
+		let name = message.name.as_deref().unwrap_or_default();
+
+		let full_name = if prefix.is_empty() {
+			String::from(name)
+		} else {
+			format!("{prefix}.{name}")
+		};
+
+		for nested in core::mem::take(&mut message.nested_type) {
+			self.add_message(&full_name, nested);
+		}
+
+		self.messages.insert(full_name, message);
+	}
+
+	/// Returns the descriptor for the message with the given fully qualified name.
+	///
+	/// A leading dot, as found in the `type_name` of field descriptors, is accepted.
+	#[must_use]
+	pub fn get_message(&self, full_name: &str) -> Option<&DescriptorProto> {
+		self.messages
+			.get(full_name.strip_prefix('.').unwrap_or(full_name))
+	}
+
+	/// Returns the descriptor for the message identified by the given type URL.
+	#[must_use]
+	pub fn resolve_type_url(&self, type_url: &str) -> Option<&DescriptorProto> {
+		self.get_message(TypeUrl::parse(type_url)?.full_name())
+	}
+
+	/// Returns the type URL for the message with the given fully qualified name, if it is registered.
+	#[must_use]
+	pub fn type_url(&self, full_name: &str) -> Option<String> {
+		let full_name = full_name.strip_prefix('.').unwrap_or(full_name);
+
+		self.messages
+			.contains_key(full_name)
+			.then(|| format!("type.googleapis.com/{full_name}"))
+	}
+
+	/// Returns an iterator over the fully qualified names of the registered messages.
+	pub fn message_names(&self) -> impl Iterator<Item = &str> {
+		self.messages.keys().map(String::as_str)
+	}
+}
+
+impl From<FileDescriptorSet> for DescriptorRegistry {
+	fn from(value: FileDescriptorSet) -> Self {
+		let mut registry = Self::new();
+
+		for file in value.file {
+			registry.add_file(file);
+		}
+
+		registry
+	}
+}
+
+/// Presence information about a single field found in the payload of a [`DynamicAny`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DynamicField<'a> {
+	/// The field number.
+	pub number: u32,
+	/// The name of the field, if it is present in the message descriptor.
+	pub name: Option<&'a str>,
+	/// The total size in bytes of the encoded field, including tags.
+	pub size: usize,
+	/// The amount of times the field appeared in the payload (greater than 1 for unpacked repeated fields).
+	pub occurrences: usize,
+}
+
+/// A minimal, reflection-like view of an [`Any`] message, which reports the presence and sizes of its fields.
+///
+/// The field names are resolved using a [`DescriptorRegistry`], if the message type is registered.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DynamicAny<'a> {
+	/// The fully qualified name of the message type.
+	pub type_name: &'a str,
+	/// The descriptor of the message, if it was found in the registry.
+	pub descriptor: Option<&'a DescriptorProto>,
+	fields: Vec<DynamicField<'a>>,
+}
+
+impl<'a> DynamicAny<'a> {
+	/// Scans the payload of the given [`Any`] message, resolving its type with the registry.
+	pub fn new(any: &'a Any, registry: &'a DescriptorRegistry) -> Result<Self, DecodeError> {
+		#[allow(deprecated)]
+		let type_url = TypeUrl::parse(&any.type_url)
+			.ok_or_else(|| DecodeError::new(format!("invalid type URL: \"{}\"", any.type_url)))?;
+
+		let descriptor = registry.get_message(type_url.full_name());
+
+		let mut fields: Vec<DynamicField<'a>> = Vec::new();
+		let mut buf = any.value.as_slice();
+
+		while !buf.is_empty() {
+			let remaining = buf.len();
+
+			let (number, wire_type) = decode_key(&mut buf)?;
+			skip_field(wire_type, number, &mut buf, DecodeContext::default())?;
+
+			let size = remaining - buf.len();
+
+			if let Some(field) = fields.iter_mut().find(|f| f.number == number) {
+				field.size += size;
+				field.occurrences += 1;
+			} else {
+				let name = descriptor.and_then(|desc| {
+					desc.field
+						.iter()
+						.find(|f| f.number.and_then(|n| u32::try_from(n).ok()) == Some(number))
+						.and_then(|f| f.name.as_deref())
+				});
+
+				fields.push(DynamicField {
+					number,
+					name,
+					size,
+					occurrences: 1,
+				});
+			}
+		}
+
+		Ok(Self {
+			type_name: type_url.full_name(),
+			descriptor,
+			fields,
+		})
+	}
+
+	/// Returns the fields found in the payload, in order of first appearance.
+	#[must_use]
+	#[inline]
+	pub fn fields(&self) -> &[DynamicField<'a>] {
+		&self.fields
+	}
+
+	/// Returns the field with the given name, if it is present in the payload.
+	#[must_use]
+	pub fn field(&self, name: &str) -> Option<&DynamicField<'a>> {
+		self.fields.iter().find(|f| f.name == Some(name))
+	}
+
+	/// Returns the field with the given number, if it is present in the payload.
+	#[must_use]
+	pub fn field_by_number(&self, number: u32) -> Option<&DynamicField<'a>> {
+		self.fields.iter().find(|f| f.number == number)
+	}
+
+	/// Returns `true` if the field with the given name is present in the payload.
+	#[must_use]
+	#[inline]
+	pub fn has_field(&self, name: &str) -> bool {
+		self.field(name).is_some()
+	}
+
+	/// Returns the encoded size of the field with the given name, or `None` if it's not present.
+	#[must_use]
+	#[inline]
+	pub fn field_size(&self, name: &str) -> Option<usize> {
+		self.field(name).map(|f| f.size)
+	}
+
+	/// Returns an iterator over the fields that are not known to the message descriptor.
+	pub fn unknown_fields(&self) -> impl Iterator<Item = &DynamicField<'a>> {
+		self.fields.iter().filter(|f| f.name.is_none())
+	}
+
+	/// Returns the total size of the payload.
+	#[must_use]
+	pub fn encoded_len(&self) -> usize {
+		self.fields.iter().map(|f| f.size).sum()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use alloc::vec;
+
+	use super::*;
+	use crate::{FieldDescriptorProto, Timestamp};
+
+	fn registry() -> DescriptorRegistry {
+		let field = |name: &str, number: i32| FieldDescriptorProto {
+			name: Some(name.into()),
+			number: Some(number),
+			..Default::default()
+		};
+
+		let file = FileDescriptorProto {
+			package: Some("google.protobuf".into()),
+			message_type: vec![DescriptorProto {
+				name: Some("Timestamp".into()),
+				field: vec![field("seconds", 1), field("nanos", 2)],
+				..Default::default()
+			}],
+			..Default::default()
+		};
+
+		DescriptorRegistry::from(FileDescriptorSet { file: vec![file] })
+	}
+
+	#[test]
+	fn resolves_type_urls() {
+		let registry = registry();
+
+		assert!(
+			registry
+				.resolve_type_url("type.googleapis.com/google.protobuf.Timestamp")
+				.is_some()
+		);
+		assert_eq!(
+			registry
+				.type_url(".google.protobuf.Timestamp")
+				.as_deref(),
+			Some("type.googleapis.com/google.protobuf.Timestamp")
+		);
+		assert_eq!(registry.type_url("google.protobuf.Duration"), None);
+	}
+
+	#[test]
+	fn dynamic_any_fields() {
+		let registry = registry();
+		let any = Any::from_msg(&Timestamp::new(10, 0)).unwrap();
+
+		let dynamic = DynamicAny::new(&any, &registry).unwrap();
+
+		assert_eq!(dynamic.type_name, "google.protobuf.Timestamp");
+		assert!(dynamic.has_field("seconds"));
+		assert!(!dynamic.has_field("nanos"));
+		assert_eq!(dynamic.field_size("seconds"), Some(2));
+		assert_eq!(dynamic.encoded_len(), any.value.len());
+		assert_eq!(dynamic.unknown_fields().count(), 0);
+	}
+}
@@ -8,7 +8,20 @@ impl Duration {
 	/// Based on [`google::protobuf::util::CreateNormalized`][1].
 	///
 	/// [1]: https://github.com/google/protobuf/blob/v3.3.2/src/google/protobuf/util/time_util.cc#L79-L100
+	#[inline]
 	pub fn normalize(&mut self) {
+		// Fast path for values that are already normalized, which is the case for almost every decoded message
+		if self.nanos.unsigned_abs() < NANOS_PER_SECOND.unsigned_abs()
+			&& self.seconds.signum() * i64::from(self.nanos.signum()) >= 0
+		{
+			return;
+		}
+
+		self.normalize_slow();
+	}
+
+	#[cold]
+	fn normalize_slow(&mut self) {
 		// Make sure nanos is in the range.
 		if self.nanos <= -NANOS_PER_SECOND || self.nanos >= NANOS_PER_SECOND {
 			if let Some(seconds) = self
@@ -227,3 +240,36 @@ mod chrono {
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn fast_path_matches_full_normalization() {
+		let seconds = [i64::MIN, -2, -1, 0, 1, 2, i64::MAX];
+		let nanos = [
+			i32::MIN,
+			-NANOS_PER_SECOND,
+			-TIME_NANOS_MAX,
+			-1,
+			0,
+			1,
+			TIME_NANOS_MAX,
+			NANOS_PER_SECOND,
+			i32::MAX,
+		];
+
+		for seconds in seconds {
+			for nanos in nanos {
+				let mut fast = Duration { seconds, nanos };
+				let mut slow = fast;
+
+				fast.normalize();
+				slow.normalize_slow();
+
+				assert_eq!(fast, slow, "{seconds}s {nanos}ns");
+			}
+		}
+	}
+}
@@ -0,0 +1,151 @@
+use crate::{Duration, String, Vec, constants::NANOS_PER_SECOND, format};
+
+/// A sorted list of bucket boundaries for latency histograms, in the style of Prometheus.
+///
+/// Each boundary is the inclusive upper bound (`le`) of a bucket. Values above the last boundary fall in the implicit `+Inf` bucket.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct DurationBuckets {
+	bounds: Vec<Duration>,
+}
+
+impl DurationBuckets {
+	/// Creates `count` boundaries, where the first one is `start` and each subsequent one is `factor` times the previous one.
+	///
+	/// Returns `None` if `start` is not positive, if `factor` is not greater than 1, if `count` is 0 or if a boundary overflows.
+	#[must_use]
+	pub fn exponential(start: Duration, factor: f64, count: usize) -> Option<Self> {
+		if start <= Duration::default() || factor.is_nan() || factor <= 1.0 || count == 0 {
+			return None;
+		}
+
+		let mut bounds = Vec::with_capacity(count);
+		let mut current = start;
+		bounds.push(current);
+
+		for _ in 1..count {
+			// SAFETY: the precision loss is acceptable for bucket boundaries, and the result is checked for overflow
+			#[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
+			let next = (current.total_nanos() as f64 * factor) as i128;
+
+			current = Duration::from_total_nanos(next).filter(|next| *next > current)?;
+			bounds.push(current);
+		}
+
+		Some(Self { bounds })
+	}
+
+	/// Creates buckets from the given boundaries, which are sorted and deduplicated.
+	#[must_use]
+	pub fn from_bounds(mut bounds: Vec<Duration>) -> Self {
+		bounds.sort_unstable();
+		bounds.dedup();
+
+		Self { bounds }
+	}
+
+	/// Returns the boundaries of the buckets.
+	#[must_use]
+	#[inline]
+	pub fn bounds(&self) -> &[Duration] {
+		&self.bounds
+	}
+
+	/// Returns the index of the bucket for the given duration. See [`Duration::bucket_index`].
+	#[must_use]
+	#[inline]
+	pub fn bucket_index(&self, duration: &Duration) -> usize {
+		duration.bucket_index(&self.bounds)
+	}
+
+	/// Returns the `le` labels for all the buckets, including the final `+Inf` bucket.
+	#[must_use]
+	pub fn le_labels(&self) -> Vec<String> {
+		self.bounds
+			.iter()
+			.map(Duration::le_label)
+			.chain(core::iter::once(String::from("+Inf")))
+			.collect()
+	}
+}
+
+impl Duration {
+	/// Returns the index of the first boundary greater than or equal to this duration, in a sorted list of bucket boundaries.
+	///
+	/// Returns `bounds.len()` if the duration exceeds all the boundaries, which corresponds to the `+Inf` bucket.
+	#[must_use]
+	pub fn bucket_index(&self, bounds: &[Self]) -> usize {
+		bounds.partition_point(|bound| bound < self)
+	}
+
+	/// Formats the duration as a number of seconds without trailing zeros, as used by the `le` label of Prometheus histograms (e.g. `0.25`).
+	#[must_use]
+	pub fn le_label(&self) -> String {
+		let total_nanos = self.total_nanos();
+		let sign = if total_nanos < 0 { "-" } else { "" };
+
+		let abs_nanos = total_nanos.unsigned_abs();
+		let nanos_per_second = u128::from(NANOS_PER_SECOND.unsigned_abs());
+
+		let seconds = abs_nanos / nanos_per_second;
+		let nanos = abs_nanos % nanos_per_second;
+
+		if nanos == 0 {
+			format!("{sign}{seconds}")
+		} else {
+			let fraction = format!("{nanos:09}");
+
+			format!("{sign}{seconds}.{}", fraction.trim_end_matches('0'))
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use alloc::vec;
+
+	use super::*;
+
+	fn millis(millis: i32) -> Duration {
+		Duration::new(0, millis * 1_000_000)
+	}
+
+	#[test]
+	fn exponential_buckets() {
+		let buckets = DurationBuckets::exponential(millis(5), 2.0, 4).unwrap();
+
+		assert_eq!(
+			buckets.bounds(),
+			&[millis(5), millis(10), millis(20), millis(40)]
+		);
+		assert_eq!(
+			buckets.le_labels(),
+			vec!["0.005", "0.01", "0.02", "0.04", "+Inf"]
+		);
+
+		assert_eq!(DurationBuckets::exponential(millis(5), 1.0, 4), None);
+		assert_eq!(
+			DurationBuckets::exponential(Duration::default(), 2.0, 4),
+			None
+		);
+		assert_eq!(DurationBuckets::exponential(millis(5), 2.0, 0), None);
+	}
+
+	#[test]
+	fn bucket_indexes() {
+		let buckets = DurationBuckets::from_bounds(vec![millis(100), millis(10), millis(50)]);
+
+		assert_eq!(buckets.bucket_index(&millis(1)), 0);
+		assert_eq!(buckets.bucket_index(&millis(10)), 0);
+		assert_eq!(buckets.bucket_index(&millis(11)), 1);
+		assert_eq!(buckets.bucket_index(&millis(100)), 2);
+		assert_eq!(buckets.bucket_index(&Duration::new(1, 0)), 3);
+	}
+
+	#[test]
+	fn le_labels() {
+		assert_eq!(Duration::new(2, 0).le_label(), "2");
+		assert_eq!(Duration::new(1, 500_000_000).le_label(), "1.5");
+		assert_eq!(Duration::new(0, 1).le_label(), "0.000000001");
+		assert_eq!(Duration::new(0, -250_000_000).le_label(), "-0.25");
+	}
+}
@@ -0,0 +1,97 @@
+use crate::{Duration, fixed_bytes, invariants::InvariantViolation};
+
+impl Duration {
+	/// The size of the fixed-size binary encoding of a [`Duration`].
+	pub const BYTES_LEN: usize = fixed_bytes::BYTES_LEN;
+
+	/// Encodes the duration into 12 big-endian bytes, for compact storage in custom binary formats or FFI.
+	///
+	/// The layout is the normalized seconds as an `i64`, followed by the nanos as an `i32` (with the same sign as the seconds), both in big-endian order.
+	/// This is unrelated to the protobuf wire encoding.
+	#[must_use]
+	pub fn to_be_bytes(&self) -> [u8; Self::BYTES_LEN] {
+		let normalized = self.normalized();
+
+		fixed_bytes::to_be_bytes(normalized.seconds, normalized.nanos)
+	}
+
+	/// Encodes the duration into 12 little-endian bytes, with the same layout as [`to_be_bytes`](Self::to_be_bytes).
+	#[must_use]
+	pub fn to_le_bytes(&self) -> [u8; Self::BYTES_LEN] {
+		let normalized = self.normalized();
+
+		fixed_bytes::to_le_bytes(normalized.seconds, normalized.nanos)
+	}
+
+	/// Decodes a duration from the 12 big-endian bytes produced by [`to_be_bytes`](Self::to_be_bytes).
+	///
+	/// Fails if the nanos are not within `-999_999_999..=999_999_999` or if their sign does not match the seconds.
+	pub fn from_be_bytes(bytes: [u8; Self::BYTES_LEN]) -> Result<Self, InvariantViolation> {
+		let (seconds, nanos) = fixed_bytes::from_be_bytes(bytes);
+
+		Self::from_parts_checked(seconds, nanos)
+	}
+
+	/// Decodes a duration from the 12 little-endian bytes produced by [`to_le_bytes`](Self::to_le_bytes).
+	///
+	/// Fails if the nanos are not within `-999_999_999..=999_999_999` or if their sign does not match the seconds.
+	pub fn from_le_bytes(bytes: [u8; Self::BYTES_LEN]) -> Result<Self, InvariantViolation> {
+		let (seconds, nanos) = fixed_bytes::from_le_bytes(bytes);
+
+		Self::from_parts_checked(seconds, nanos)
+	}
+
+	/// Checks the decoded nanos with the same rules as [`set_nanos_checked`](Self::set_nanos_checked).
+	const fn from_parts_checked(seconds: i64, nanos: i32) -> Result<Self, InvariantViolation> {
+		let mut value = Self { seconds, nanos: 0 };
+
+		match value.set_nanos_checked(nanos) {
+			Ok(()) => Ok(value),
+			Err(e) => Err(e),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn bytes_round_trip() {
+		for duration in [
+			Duration::new(90, 500_000_000),
+			Duration::new(-90, -500_000_000),
+			Duration::default(),
+		] {
+			assert_eq!(
+				Duration::from_be_bytes(duration.to_be_bytes()),
+				Ok(duration)
+			);
+			assert_eq!(
+				Duration::from_le_bytes(duration.to_le_bytes()),
+				Ok(duration)
+			);
+		}
+
+		let mut min_nanos = Duration::default().to_le_bytes();
+		min_nanos[8..].copy_from_slice(&i32::MIN.to_le_bytes());
+		assert_eq!(
+			Duration::from_le_bytes(min_nanos),
+			Err(InvariantViolation::NanosOutOfRange { nanos: i32::MIN })
+		);
+
+		let bytes = Duration::new(-1, -5).to_le_bytes();
+		assert_eq!(&bytes[..8], &(-1_i64).to_le_bytes());
+		assert_eq!(&bytes[8..], &(-5_i32).to_le_bytes());
+
+		let mut invalid = Duration::new(1, 0).to_be_bytes();
+		invalid[8..].copy_from_slice(&(-5_i32).to_be_bytes());
+		assert_eq!(
+			Duration::from_be_bytes(invalid),
+			Err(InvariantViolation::SignMismatch {
+				seconds: 1,
+				nanos: -5
+			})
+		);
+	}
+}
@@ -20,30 +20,14 @@ mod serde {
 
 	use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
 
-	use crate::{Duration, ToString, format};
+	use crate::Duration;
 
 	impl Serialize for Duration {
 		fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
 		where
 			S: Serializer,
 		{
-			let self_normalized = self.normalized();
-
-			let seconds = self_normalized.seconds;
-			let nanos = self_normalized.nanos;
-
-			let formatted_string = if nanos == 0 {
-				// If nanos are zero, just "Xs"
-				format!("{seconds}s")
-			} else {
-				let fractional_seconds_str = format!("{nanos:09}");
-
-				let trimmed_fractional_seconds = fractional_seconds_str.trim_end_matches('0');
-
-				format!("{seconds}.{trimmed_fractional_seconds}s")
-			};
-
-			serializer.serialize_str(&formatted_string)
+			serializer.collect_str(self)
 		}
 	}
 
@@ -65,45 +49,7 @@ mod serde {
 				where
 					E: de::Error,
 				{
-					if !value.ends_with('s') {
-						return Err(de::Error::custom("Duration should end with 's'"));
-					}
-
-					let duration_str = &value[..value.len() - 1]; // Remove 's' from the end
-
-					let mut parts = duration_str.split('.'); // Split seconds and fractional seconds
-
-					let seconds: i64 = parts
-						.next()
-						.ok_or_else(|| de::Error::custom("Missing seconds"))?
-						.parse()
-						.map_err(de::Error::custom)?;
-
-					let nanos: i32 = match parts.next() {
-						Some(fraction) => {
-							let mut fraction_str = fraction.to_string(); // Need to own it for modification
-							// Pad fraction to 9 digits (nanoseconds)
-							if fraction_str.len() > 9 {
-								// Handle too many fractional digits
-								return Err(de::Error::custom(format!(
-									"Fractional part has more than 9 digits: {}",
-									fraction_str.len()
-								)));
-							}
-							fraction_str.reserve(9 - fraction_str.len()); // Pre-allocate to avoid reallocations
-							for _ in fraction_str.len()..9 {
-								fraction_str.push('0');
-							}
-
-							fraction_str.parse().map_err(de::Error::custom)?
-						}
-						None => 0,
-					};
-
-					let mut duration = Duration { seconds, nanos };
-					duration.normalize(); // Normalize after creation
-
-					Ok(duration)
+					value.parse().map_err(de::Error::custom)
 				}
 			}
 
@@ -111,3 +57,24 @@ mod serde {
 		}
 	}
 }
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn negative_fractions_json_round_trip() {
+		let duration = Duration::new(-3, -123_000_000);
+
+		assert_eq!(serde_json::to_string(&duration).unwrap(), r#""-3.123s""#);
+		assert_eq!(
+			serde_json::from_str::<Duration>(r#""-3.123s""#).unwrap(),
+			duration
+		);
+		assert_eq!(
+			serde_json::from_str::<Duration>(r#""-0.5s""#).unwrap(),
+			Duration::new(0, -500_000_000)
+		);
+		assert!(serde_json::from_str::<Duration>(r#""-3.-123s""#).is_err());
+	}
+}
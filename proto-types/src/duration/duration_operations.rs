@@ -1,5 +1,6 @@
-use crate::Duration;
+use crate::{Duration, RoundingMode};
 use core::cmp::Ordering;
+#[cfg(not(feature = "no-panic"))]
 use core::ops::{Add, Div, Mul, Sub};
 use core::time::Duration as StdDuration;
 
@@ -72,19 +73,18 @@ impl PartialOrd<Duration> for chrono::TimeDelta {
 	}
 }
 
+#[cfg(not(feature = "no-panic"))]
 impl Add<StdDuration> for Duration {
 	type Output = Self;
 
 	#[inline]
 	fn add(self, rhs: StdDuration) -> Self::Output {
-		let rhs_s = i64::try_from(rhs.as_secs()).expect("overflow in duration addition");
-		let rhs_n = i64::from(rhs.subsec_nanos());
-
-		self.checked_add_raw(rhs_s, rhs_n)
+		self.checked_add_std(rhs)
 			.expect("overflow in duration addition")
 	}
 }
 
+#[cfg(not(feature = "no-panic"))]
 impl Add for Duration {
 	type Output = Self;
 	#[inline]
@@ -94,6 +94,7 @@ impl Add for Duration {
 	}
 }
 
+#[cfg(not(feature = "no-panic"))]
 impl Sub for Duration {
 	type Output = Self;
 
@@ -104,41 +105,40 @@ impl Sub for Duration {
 	}
 }
 
+#[cfg(not(feature = "no-panic"))]
 impl Sub<StdDuration> for Duration {
 	type Output = Self;
 
 	#[inline]
 	fn sub(self, rhs: StdDuration) -> Self::Output {
-		let rhs_s = i64::try_from(rhs.as_secs()).expect("overflow in duration subtraction");
-		let rhs_n = i64::from(rhs.subsec_nanos());
-
-		self.checked_sub_raw(rhs_s, rhs_n)
+		self.checked_sub_std(rhs)
 			.expect("overflow in duration subtraction")
 	}
 }
 
-#[cfg(feature = "chrono")]
+#[cfg(all(feature = "chrono", not(feature = "no-panic")))]
 impl Add<chrono::TimeDelta> for Duration {
 	type Output = Self;
 
 	#[inline]
 	fn add(self, rhs: chrono::TimeDelta) -> Self::Output {
-		self.checked_add_raw(rhs.num_seconds(), i64::from(rhs.subsec_nanos()))
+		self.checked_add_time_delta(rhs)
 			.expect("overflow in duration addition")
 	}
 }
 
-#[cfg(feature = "chrono")]
+#[cfg(all(feature = "chrono", not(feature = "no-panic")))]
 impl Sub<chrono::TimeDelta> for Duration {
 	type Output = Self;
 
 	#[inline]
 	fn sub(self, rhs: chrono::TimeDelta) -> Self::Output {
-		self.checked_sub_raw(rhs.num_seconds(), i64::from(rhs.subsec_nanos()))
+		self.checked_sub_time_delta(rhs)
 			.expect("overflow in duration subtraction")
 	}
 }
 
+#[cfg(not(feature = "no-panic"))]
 impl Mul<i64> for Duration {
 	type Output = Self;
 
@@ -149,6 +149,7 @@ impl Mul<i64> for Duration {
 	}
 }
 
+#[cfg(not(feature = "no-panic"))]
 impl Mul<i32> for Duration {
 	type Output = Self;
 
@@ -159,6 +160,7 @@ impl Mul<i32> for Duration {
 	}
 }
 
+#[cfg(not(feature = "no-panic"))]
 impl Div<i64> for Duration {
 	type Output = Self;
 
@@ -169,6 +171,7 @@ impl Div<i64> for Duration {
 	}
 }
 
+#[cfg(not(feature = "no-panic"))]
 impl Div<i32> for Duration {
 	type Output = Self;
 
@@ -247,6 +250,47 @@ impl Duration {
 		(self.seconds as i128) * (Self::NANOS_PER_SEC_I128) + (self.nanos as i128)
 	}
 
+	/// Returns the absolute value of this duration as a [`core::time::Duration`], which cannot represent negative values.
+	///
+	/// Use this (or [`split_sign`](Self::split_sign)) instead of casting the fields when bridging to APIs that only accept unsigned durations, such as timers or `sleep`.
+	#[must_use]
+	#[inline]
+	pub const fn abs_std(&self) -> StdDuration {
+		let total = self.total_nanos().unsigned_abs();
+
+		// SAFETY: The absolute value of an i64 multiplied by 10^9 and divided back fits in a u64,
+		// and the remainder is always below 10^9
+		#[allow(clippy::cast_possible_truncation)]
+		let (secs, nanos) = (
+			(total / Self::NANOS_PER_SEC_I128.unsigned_abs()) as u64,
+			(total % Self::NANOS_PER_SEC_I128.unsigned_abs()) as u32,
+		);
+
+		StdDuration::new(secs, nanos)
+	}
+
+	/// Returns `-1` if the duration is negative, `0` if it is zero and `1` if it is positive.
+	#[must_use]
+	#[inline]
+	pub const fn signum(&self) -> i32 {
+		let total = self.total_nanos();
+
+		if total < 0 {
+			-1
+		} else if total > 0 {
+			1
+		} else {
+			0
+		}
+	}
+
+	/// Splits the duration into its sign (`true` if negative) and its absolute value as a [`core::time::Duration`].
+	#[must_use]
+	#[inline]
+	pub const fn split_sign(&self) -> (bool, StdDuration) {
+		(self.total_nanos() < 0, self.abs_std())
+	}
+
 	/// Creates a new normalized instance from a given amount of nanoseconds.
 	#[must_use]
 	#[inline]
@@ -288,6 +332,42 @@ impl Duration {
 		self.checked_sub_raw(other.seconds, other.nanos.into())
 	}
 
+	/// Adds a [`core::time::Duration`] to this one, returning `Some(Duration)` or `None` on overflow.
+	#[must_use]
+	#[inline]
+	pub fn checked_add_std(&self, rhs: StdDuration) -> Option<Self> {
+		self.checked_add_raw(
+			i64::try_from(rhs.as_secs()).ok()?,
+			i64::from(rhs.subsec_nanos()),
+		)
+	}
+
+	/// Subtracts a [`core::time::Duration`] from this one, returning `Some(Duration)` or `None` on overflow.
+	#[must_use]
+	#[inline]
+	pub fn checked_sub_std(&self, rhs: StdDuration) -> Option<Self> {
+		self.checked_sub_raw(
+			i64::try_from(rhs.as_secs()).ok()?,
+			i64::from(rhs.subsec_nanos()),
+		)
+	}
+
+	/// Adds a [`chrono::TimeDelta`] to this Duration, returning `Some(Duration)` or `None` on overflow.
+	#[cfg(feature = "chrono")]
+	#[must_use]
+	#[inline]
+	pub fn checked_add_time_delta(&self, rhs: chrono::TimeDelta) -> Option<Self> {
+		self.checked_add_raw(rhs.num_seconds(), i64::from(rhs.subsec_nanos()))
+	}
+
+	/// Subtracts a [`chrono::TimeDelta`] from this Duration, returning `Some(Duration)` or `None` on overflow.
+	#[cfg(feature = "chrono")]
+	#[must_use]
+	#[inline]
+	pub fn checked_sub_time_delta(&self, rhs: chrono::TimeDelta) -> Option<Self> {
+		self.checked_sub_raw(rhs.num_seconds(), i64::from(rhs.subsec_nanos()))
+	}
+
 	/// Divides the Duration by an i64 scalar, returning `Some(Duration)` or `None` on overflow.
 	#[must_use]
 	#[inline]
@@ -300,11 +380,91 @@ impl Duration {
 	}
 }
 
+impl Duration {
+	/// Rounds this duration to a multiple of `step`, according to the given [`RoundingMode`].
+	///
+	/// The sign of `step` is ignored. Returns `None` if `step` is zero or if the result overflows.
+	#[must_use]
+	pub fn quantize(&self, step: Self, mode: RoundingMode) -> Option<Self> {
+		let step = step.total_nanos().abs();
+
+		if step == 0 {
+			return None;
+		}
+
+		let total = mode
+			.div(self.total_nanos(), step)
+			.checked_mul(step)?;
+		Self::from_total_nanos(total)
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
 	use core::cmp::Ordering;
 
+	#[test]
+	fn quantize() {
+		let step = Duration::new(900, 0);
+		let value = Duration::new(1_000, 0);
+
+		assert_eq!(value.quantize(step, RoundingMode::Floor), Some(step));
+		assert_eq!(
+			value.quantize(step, RoundingMode::Ceil),
+			Some(Duration::new(1_800, 0))
+		);
+		assert_eq!(
+			Duration::new(-1_000, 0).quantize(step, RoundingMode::Floor),
+			Some(Duration::new(-1_800, 0))
+		);
+		assert_eq!(
+			Duration::new(-1_000, 0).quantize(step, RoundingMode::TowardZero),
+			Some(Duration::new(-900, 0))
+		);
+		assert_eq!(
+			Duration::new(450, 0).quantize(step, RoundingMode::HalfEven),
+			Some(Duration::new(0, 0))
+		);
+		assert_eq!(
+			value.quantize(Duration::new(0, 0), RoundingMode::Floor),
+			None
+		);
+	}
+
+	#[test]
+	fn sign_helpers() {
+		let negative = Duration::new(-2, -500_000_000);
+		assert_eq!(negative.signum(), -1);
+		assert_eq!(negative.abs_std(), StdDuration::new(2, 500_000_000));
+		assert_eq!(
+			negative.split_sign(),
+			(true, StdDuration::new(2, 500_000_000))
+		);
+
+		// Non-normalized values are handled through their total amount
+		let mixed = Duration {
+			seconds: 1,
+			nanos: -1_500_000_000,
+		};
+		assert_eq!(mixed.split_sign(), (true, StdDuration::from_millis(500)));
+
+		assert_eq!(Duration::default().signum(), 0);
+		assert_eq!(
+			Duration::new(0, 1).split_sign(),
+			(false, StdDuration::from_nanos(1))
+		);
+
+		let min = Duration {
+			seconds: i64::MIN,
+			nanos: -999_999_999,
+		};
+		assert_eq!(
+			min.abs_std(),
+			StdDuration::new(i64::MIN.unsigned_abs(), 999_999_999)
+		);
+	}
+
 	macro_rules! get_duration {
 		(duration, $secs:literal, $nanos:literal) => {
 			Duration::new($secs, $nanos)
@@ -321,6 +481,7 @@ mod tests {
 
 	macro_rules! test_ops {
 		($duration:ident) => {
+			#[cfg(not(feature = "no-panic"))]
 			#[test]
 			fn test_add_sub() {
 				// 1. Add causing carry
@@ -391,6 +552,8 @@ mod tests {
 	fn test_mul_overflow_checks() {
 		// 1. Basic
 		let d = dur(10, 0);
+		assert_eq!(d.checked_mul(2), Some(dur(20, 0)));
+		#[cfg(not(feature = "no-panic"))]
 		assert_eq!(d * 2, dur(20, 0));
 
 		// 2. Overflow i64 seconds via huge multiplier
@@ -428,15 +591,46 @@ mod tests {
 
 		// Clean division
 		let d = dur(1, 0);
-		let res: Duration = d / 2;
+		let res = d.checked_div(2).unwrap();
 		assert_eq!(res.seconds, 0);
 		assert_eq!(res.nanos, 500_000_000);
+		#[cfg(not(feature = "no-panic"))]
+		assert_eq!(d / 2, res);
 
 		// Negative division
 		let d = dur(-1, 0);
-		let res: Duration = d / 2;
+		let res = d.checked_div(2).unwrap();
 		assert_eq!(res.seconds, 0);
 		assert_eq!(res.nanos, -500_000_000);
+		#[cfg(not(feature = "no-panic"))]
+		assert_eq!(d / 2, res);
+	}
+
+	#[test]
+	fn checked_foreign_durations() {
+		let d = dur(1, 900_000_000);
+
+		assert_eq!(
+			d.checked_add_std(StdDuration::new(0, 200_000_000)),
+			Some(dur(2, 100_000_000))
+		);
+		assert_eq!(
+			dur(1, 0).checked_sub_std(StdDuration::new(2, 0)),
+			Some(dur(-1, 0))
+		);
+		assert_eq!(d.checked_add_std(StdDuration::MAX), None);
+		assert_eq!(
+			dur(i64::MIN, 0).checked_sub_std(StdDuration::new(1, 0)),
+			None
+		);
+
+		#[cfg(feature = "chrono")]
+		{
+			let delta = chrono::TimeDelta::new(0, 200_000_000).unwrap();
+
+			assert_eq!(d.checked_add_time_delta(delta), Some(dur(2, 100_000_000)));
+			assert_eq!(d.checked_sub_time_delta(delta), Some(dur(1, 700_000_000)));
+		}
 	}
 
 	#[test]
@@ -3,10 +3,11 @@ use alloc::string::String;
 use core::fmt::Write;
 
 use super::data::DurationData;
-use crate::{Duration, Vec};
+use crate::{Duration, ToString, Vec, format};
 
-impl core::fmt::Display for Duration {
-	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+impl Duration {
+	/// Writes the canonical protobuf JSON representation of this duration (e.g. `1.5s`, the same as its [`Display`](core::fmt::Display) output) into the given writer, without allocating.
+	pub fn write_canonical<W: core::fmt::Write + ?Sized>(&self, w: &mut W) -> core::fmt::Result {
 		let normalized = self.normalized();
 
 		// 1. Handle Negative case
@@ -14,14 +15,14 @@ impl core::fmt::Display for Duration {
 		let is_negative = normalized.seconds < 0 || normalized.nanos < 0;
 
 		if is_negative {
-			write!(f, "-")?;
+			write!(w, "-")?;
 		}
 
 		// Work with absolute values for printing
-		let abs_seconds = normalized.seconds.abs();
-		let mut abs_nanos = normalized.nanos.abs();
+		let abs_seconds = normalized.seconds.unsigned_abs();
+		let mut abs_nanos = normalized.nanos.unsigned_abs();
 
-		write!(f, "{abs_seconds}")?;
+		write!(w, "{abs_seconds}")?;
 
 		if abs_nanos > 0 {
 			let mut width = 9;
@@ -29,19 +30,79 @@ impl core::fmt::Display for Duration {
 			// Strip trailing zeros mathematically
 			// e.g. 500_000_000 (width 9) -> 5 (width 1) -> prints ".5"
 			// e.g. 000_000_500 (width 9) -> 5 (width 7) -> prints ".0000005"
-			while abs_nanos % 10 == 0 {
+			while abs_nanos.is_multiple_of(10) {
 				abs_nanos /= 10;
 				width -= 1;
 			}
 
-			write!(f, ".{abs_nanos:0width$}")?;
+			write!(w, ".{abs_nanos:0width$}")?;
 		}
 
-		write!(f, "s")
+		write!(w, "s")
+	}
+}
+
+impl core::fmt::Display for Duration {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		self.write_canonical(f)
+	}
+}
+
+impl core::fmt::Debug for Duration {
+	/// The alternate form (`{:#?}`) shows both the canonical rendering and the raw fields.
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		if f.alternate() {
+			write!(
+				f,
+				"Duration({self}; seconds={}, nanos={})",
+				self.seconds, self.nanos
+			)
+		} else {
+			f.debug_struct("Duration")
+				.field("seconds", &self.seconds)
+				.field("nanos", &self.nanos)
+				.finish()
+		}
 	}
 }
 
 impl Duration {
+	/// Formats the duration in seconds with a fixed amount of decimal places (truncating the rest), right-aligned to `width` characters.
+	///
+	/// The digits of the seconds are grouped in threes with a thin space (e.g. `1 234.500s`), so that durations line up when printed in columns.
+	/// Values that are wider than `width` are not truncated.
+	#[must_use]
+	pub fn to_aligned_string(&self, decimal_places: u32, width: usize) -> String {
+		let decimal_places = decimal_places.min(9);
+		let normalized = self.normalized();
+
+		let sign = if normalized.seconds < 0 || normalized.nanos < 0 {
+			"-"
+		} else {
+			""
+		};
+
+		let mut formatted = String::new();
+		let _ = write!(
+			formatted,
+			"{sign}{}",
+			crate::tabulate::group_digits(&normalized.seconds.unsigned_abs().to_string())
+		);
+
+		if decimal_places > 0 {
+			let fraction = normalized.nanos.unsigned_abs() / 10_u32.pow(9 - decimal_places);
+			let _ = write!(
+				formatted,
+				".{fraction:0width$}",
+				width = decimal_places as usize
+			);
+		}
+
+		formatted.push('s');
+
+		format!("{formatted:>width$}")
+	}
+
 	/// Formats a duration in human readable form. (e.g. "2 days 15 hours 12 minutes and 15 seconds")
 	#[must_use]
 	pub fn to_human_readable_string(&self) -> String {
@@ -99,6 +160,72 @@ mod tests {
 		}
 	}
 
+	/// A fixed-capacity writer, to check that formatting does not need to allocate.
+	struct StackWriter {
+		buf: [u8; 64],
+		len: usize,
+	}
+
+	impl Write for StackWriter {
+		fn write_str(&mut self, s: &str) -> core::fmt::Result {
+			let end = self.len + s.len();
+			self.buf
+				.get_mut(self.len..end)
+				.ok_or(core::fmt::Error)?
+				.copy_from_slice(s.as_bytes());
+			self.len = end;
+			Ok(())
+		}
+	}
+
+	#[test]
+	fn write_without_allocating() {
+		let mut writer = StackWriter {
+			buf: [0; 64],
+			len: 0,
+		};
+
+		Duration::new(-90, -500_000_000)
+			.write_canonical(&mut writer)
+			.unwrap();
+		writer.write_str(" ").unwrap();
+		crate::Timestamp::new(1_700_000_000, 250_000_000)
+			.write_rfc3339(&mut writer)
+			.unwrap();
+
+		assert_eq!(
+			core::str::from_utf8(&writer.buf[..writer.len]).unwrap(),
+			"-90.5s 2023-11-14T22:13:20.25Z"
+		);
+	}
+
+	#[test]
+	fn aligned_string() {
+		assert_eq!(
+			Duration::new(1234, 500_000_000).to_aligned_string(3, 12),
+			"  1\u{2009}234.500s"
+		);
+		assert_eq!(
+			Duration::new(-1, -999_999_999).to_aligned_string(2, 0),
+			"-1.99s"
+		);
+		assert_eq!(Duration::default().to_aligned_string(0, 3), " 0s");
+	}
+
+	#[test]
+	fn test_debug_formatting() {
+		let d = dur(1, 500_000_000);
+
+		assert_eq!(
+			format!("{d:?}"),
+			"Duration { seconds: 1, nanos: 500000000 }"
+		);
+		assert_eq!(
+			format!("{d:#?}"),
+			"Duration(1.5s; seconds=1, nanos=500000000)"
+		);
+	}
+
 	#[test]
 	fn test_canonical_display() {
 		// Simple
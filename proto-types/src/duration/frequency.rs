@@ -0,0 +1,73 @@
+use crate::{Duration, constants::NANOS_PER_SECOND};
+
+const NANOS_PER_SECOND_F64: f64 = NANOS_PER_SECOND as f64;
+
+impl Duration {
+	/// Creates the period of a frequency in hertz, rounded to the nearest nanosecond.
+	///
+	/// Returns `None` if the frequency is not finite and positive, or if the period overflows.
+	/// With the `fraction` feature, `from_hz_fraction` gives exact periods for rational frequencies.
+	#[must_use]
+	pub fn from_hz(hz: f64) -> Option<Self> {
+		if !hz.is_finite() || hz <= 0.0 {
+			return None;
+		}
+
+		let nanos = NANOS_PER_SECOND_F64 / hz + 0.5;
+
+		// The cast saturates, so values that do not fit are rejected by the range check
+		if nanos >= i128::MAX as f64 {
+			return None;
+		}
+
+		// SAFETY: The value is positive and below i128::MAX
+		#[allow(clippy::cast_possible_truncation)]
+		Self::from_total_nanos(nanos as i128)
+	}
+
+	/// Creates the period of a rotation speed in revolutions per minute, rounded to the nearest nanosecond.
+	///
+	/// Returns `None` if the speed is not finite and positive, or if the period overflows.
+	#[must_use]
+	#[inline]
+	pub fn from_rpm(rpm: f64) -> Option<Self> {
+		Self::from_hz(rpm / 60.0)
+	}
+
+	/// Returns the frequency in hertz for which this duration is the period.
+	///
+	/// Returns `None` if the duration is not positive.
+	#[must_use]
+	pub fn to_hz(&self) -> Option<f64> {
+		let nanos = self.total_nanos();
+
+		if nanos <= 0 {
+			return None;
+		}
+
+		// SAFETY: The precision loss is acceptable for a floating point frequency
+		#[allow(clippy::cast_precision_loss)]
+		Some(NANOS_PER_SECOND_F64 / nanos as f64)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn frequency_conversions() {
+		assert_eq!(Duration::from_hz(48_000.0), Some(Duration::new(0, 20_833)));
+		assert_eq!(Duration::from_hz(0.5), Some(Duration::new(2, 0)));
+		assert_eq!(
+			Duration::from_rpm(600.0),
+			Some(Duration::new(0, 100_000_000))
+		);
+		assert_eq!(Duration::from_hz(0.0), None);
+		assert_eq!(Duration::from_hz(f64::NAN), None);
+		assert_eq!(Duration::from_hz(f64::MIN_POSITIVE), None);
+
+		assert_eq!(Duration::new(0, 1_000_000).to_hz(), Some(1_000.0));
+		assert_eq!(Duration::new(-1, 0).to_hz(), None);
+	}
+}
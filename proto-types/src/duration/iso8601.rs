@@ -0,0 +1,303 @@
+use core::{fmt, str::FromStr};
+
+use crate::{
+	Duration, String, Timestamp, ToString, constants::NANOS_PER_SECOND, duration::DurationError,
+};
+
+const NANOS_PER_SECOND_I128: i128 = NANOS_PER_SECOND as i128;
+
+impl Duration {
+	/// Parses an ISO 8601 duration, such as `PT1H30M`, `P1DT12H` or `PT0.5S`.
+	///
+	/// Years and months are rejected because their length depends on the calendar, while weeks and days are treated as 7 and 1 days of 24 hours.
+	/// As in the standard, weeks cannot be combined with other units (`P1W1D` is rejected).
+	/// Only the last component may have a fractional part, and a leading `-` is accepted for negative durations.
+	pub fn from_iso8601(value: &str) -> Result<Self, DurationError> {
+		parse_iso8601(value).ok_or(DurationError::ParseFailure)
+	}
+
+	/// Formats the duration in the ISO 8601 format, such as `P1DT2H3M4.5S`, using days as the largest unit.
+	#[must_use]
+	#[inline]
+	pub fn to_iso8601(&self) -> String {
+		Iso8601(self).to_string()
+	}
+}
+
+/// Displays a [`Duration`] in the ISO 8601 format.
+struct Iso8601<'a>(&'a Duration);
+
+impl fmt::Display for Iso8601<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let total_nanos = self.0.total_nanos();
+
+		if total_nanos < 0 {
+			write!(f, "-")?;
+		}
+
+		let abs_nanos = total_nanos.unsigned_abs();
+		let nanos_per_second = NANOS_PER_SECOND_I128.unsigned_abs();
+
+		let total_seconds = abs_nanos / nanos_per_second;
+		let nanos = abs_nanos % nanos_per_second;
+
+		let days = total_seconds / 86_400;
+		let hours = (total_seconds % 86_400) / 3_600;
+		let minutes = (total_seconds % 3_600) / 60;
+		let seconds = total_seconds % 60;
+
+		write!(f, "P")?;
+
+		if days > 0 {
+			write!(f, "{days}D")?;
+
+			if hours == 0 && minutes == 0 && seconds == 0 && nanos == 0 {
+				return Ok(());
+			}
+		}
+
+		write!(f, "T")?;
+
+		if hours > 0 {
+			write!(f, "{hours}H")?;
+		}
+
+		if minutes > 0 {
+			write!(f, "{minutes}M")?;
+		}
+
+		if nanos > 0 {
+			let mut nanos = nanos;
+			let mut width = 9;
+
+			while nanos.is_multiple_of(10) {
+				nanos /= 10;
+				width -= 1;
+			}
+
+			write!(f, "{seconds}.{nanos:0width$}S")
+		} else if seconds > 0 || total_seconds == 0 {
+			write!(f, "{seconds}S")
+		} else {
+			Ok(())
+		}
+	}
+}
+
+fn parse_iso8601(value: &str) -> Option<Duration> {
+	let (is_negative, value) = match value.strip_prefix('-') {
+		Some(rest) => (true, rest),
+		None => (false, value),
+	};
+
+	let value = value.strip_prefix('P')?;
+	let (date, time) = match value.split_once('T') {
+		Some((date, time)) => {
+			if time.is_empty() {
+				return None;
+			}
+			(date, Some(time))
+		}
+		None if value.is_empty() => return None,
+		None => (value, None),
+	};
+
+	let mut total: i128 = 0;
+	let mut has_fraction = false;
+	let mut components = 0;
+	let mut has_weeks = false;
+
+	for (part, units) in [
+		(date, &[('W', 604_800), ('D', 86_400)][..]),
+		(
+			time.unwrap_or_default(),
+			&[('H', 3_600), ('M', 60), ('S', 1)][..],
+		),
+	] {
+		let mut rest = part;
+		let mut units = units.iter();
+
+		while !rest.is_empty() {
+			// A fractional component must be the last one
+			if has_fraction {
+				return None;
+			}
+
+			let end = rest.find(|c: char| !c.is_ascii_digit() && c != '.' && c != ',')?;
+			let (number, designator) = (&rest[..end], rest[end..].chars().next()?);
+			rest = &rest[end + designator.len_utf8()..];
+
+			// Units must appear at most once and in order
+			let (_, unit_seconds) = units.find(|(unit, _)| *unit == designator)?;
+			let unit_nanos = i128::from(*unit_seconds) * NANOS_PER_SECOND_I128;
+			has_weeks |= designator == 'W';
+
+			let (integer, fraction) = number
+				.split_once(['.', ','])
+				.map_or((number, None), |(i, f)| (i, Some(f)));
+
+			if integer.is_empty() || !integer.bytes().all(|b| b.is_ascii_digit()) {
+				return None;
+			}
+
+			let integer: i128 = integer.parse().ok()?;
+			total = total.checked_add(integer.checked_mul(unit_nanos)?)?;
+
+			if let Some(fraction) = fraction {
+				if fraction.is_empty()
+					|| fraction.len() > 9
+					|| !fraction.bytes().all(|b| b.is_ascii_digit())
+				{
+					return None;
+				}
+
+				let numerator: i128 = fraction.parse().ok()?;
+				let denominator = 10_i128.pow(u32::try_from(fraction.len()).ok()?);
+
+				total = total.checked_add(numerator * unit_nanos / denominator)?;
+				has_fraction = true;
+			}
+
+			components += 1;
+		}
+	}
+
+	if components == 0 || (has_weeks && components > 1) {
+		return None;
+	}
+
+	Duration::from_total_nanos(if is_negative { -total } else { total })
+}
+
+/// An ISO 8601 repeating interval with a duration only, such as `R5/PT1H` or `R/P1D`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RepeatingInterval {
+	/// The number of repetitions, or `None` if the interval repeats indefinitely.
+	pub repeats: Option<u64>,
+	/// The duration of each repetition, which is always positive.
+	pub duration: Duration,
+}
+
+impl RepeatingInterval {
+	/// Creates a new instance. Returns `None` if the duration is not positive.
+	#[must_use]
+	pub fn new(repeats: Option<u64>, duration: Duration) -> Option<Self> {
+		(duration > Duration::default()).then_some(Self { repeats, duration })
+	}
+
+	/// Returns the start times of the repetitions, beginning at `start`.
+	///
+	/// The iterator is unbounded if the number of repetitions is not specified.
+	pub fn occurrences(&self, start: Timestamp) -> impl Iterator<Item = Timestamp> + use<> {
+		let duration = self.duration;
+
+		let occurrences =
+			core::iter::successors(Some(start), move |current| Some(current + duration));
+
+		occurrences.take(self.repeats.map_or(usize::MAX, |repeats| {
+			usize::try_from(repeats).unwrap_or(usize::MAX)
+		}))
+	}
+}
+
+impl FromStr for RepeatingInterval {
+	type Err = DurationError;
+
+	fn from_str(s: &str) -> Result<Self, DurationError> {
+		let (repeats, duration) = s
+			.strip_prefix('R')
+			.and_then(|rest| rest.split_once('/'))
+			.ok_or(DurationError::ParseFailure)?;
+
+		let repeats = if repeats.is_empty() {
+			None
+		} else if repeats.bytes().all(|b| b.is_ascii_digit()) {
+			Some(
+				repeats
+					.parse()
+					.map_err(|_| DurationError::ParseFailure)?,
+			)
+		} else {
+			return Err(DurationError::ParseFailure);
+		};
+
+		if duration.starts_with('-') {
+			return Err(DurationError::ParseFailure);
+		}
+
+		Self::new(repeats, Duration::from_iso8601(duration)?).ok_or(DurationError::ParseFailure)
+	}
+}
+
+impl fmt::Display for RepeatingInterval {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self.repeats {
+			Some(repeats) => write!(f, "R{repeats}/{}", Iso8601(&self.duration)),
+			None => write!(f, "R/{}", Iso8601(&self.duration)),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::Vec;
+
+	#[test]
+	fn iso8601_durations() {
+		let parse = |s: &str| Duration::from_iso8601(s);
+
+		assert_eq!(parse("PT1H30M"), Ok(Duration::new(5_400, 0)));
+		assert_eq!(parse("P1DT12H"), Ok(Duration::new(129_600, 0)));
+		assert_eq!(parse("P2W"), Ok(Duration::new(1_209_600, 0)));
+		assert_eq!(parse("PT0.5S"), Ok(Duration::new(0, 500_000_000)));
+		assert_eq!(parse("PT1,5M"), Ok(Duration::new(90, 0)));
+		assert_eq!(parse("-PT1S"), Ok(Duration::new(-1, 0)));
+
+		for invalid in [
+			"", "P", "PT", "P1Y", "P1M", "PT1S1M", "PT0.5M1S", "PT1H1H", "P1DT", "1S", "P1W1D",
+			"P1WT1H",
+		] {
+			assert_eq!(
+				parse(invalid),
+				Err(DurationError::ParseFailure),
+				"{invalid}"
+			);
+		}
+
+		assert_eq!(
+			Duration::new(93_784, 500_000_000).to_iso8601(),
+			"P1DT2H3M4.5S"
+		);
+		assert_eq!(Duration::new(86_400, 0).to_iso8601(), "P1D");
+		assert_eq!(Duration::new(3_600, 0).to_iso8601(), "PT1H");
+		assert_eq!(Duration::default().to_iso8601(), "PT0S");
+		assert_eq!(Duration::new(-90, 0).to_iso8601(), "-PT1M30S");
+		assert_eq!(Duration::new(1, 5_000_000).to_iso8601(), "PT1.005S");
+		assert_eq!(Duration::new(0, 1).to_iso8601(), "PT0.000000001S");
+	}
+
+	#[test]
+	fn repeating_intervals() {
+		let hourly: RepeatingInterval = "R5/PT1H".parse().unwrap();
+		assert_eq!(hourly.repeats, Some(5));
+		assert_eq!(hourly.duration, Duration::new(3_600, 0));
+		assert_eq!(hourly.to_string(), "R5/PT1H");
+
+		let daily: RepeatingInterval = "R/P1D".parse().unwrap();
+		assert_eq!(daily.repeats, None);
+		assert_eq!(daily.to_string(), "R/P1D");
+
+		for invalid in ["5/PT1H", "R5PT1H", "Rx/PT1H", "R5/-PT1H", "R5/PT0S"] {
+			assert!(invalid.parse::<RepeatingInterval>().is_err(), "{invalid}");
+		}
+
+		let starts: Vec<Timestamp> = hourly.occurrences(Timestamp::new(0, 0)).collect();
+		assert_eq!(starts.len(), 5);
+		assert_eq!(starts[4], Timestamp::new(14_400, 0));
+		assert_eq!(
+			daily.occurrences(Timestamp::new(0, 0)).nth(2),
+			Some(Timestamp::new(172_800, 0))
+		);
+	}
+}
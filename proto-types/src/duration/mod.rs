@@ -1,17 +1,30 @@
 mod base;
 pub use base::DurationError;
 
-use crate::Duration;
+use crate::{
+	Duration, ProtoValidate,
+	constants::NANOS_PER_SECOND,
+	invariants::{InvariantReport, InvariantViolation},
+};
 
 mod duration_impls;
 
+mod buckets;
+pub use buckets::DurationBuckets;
+
 mod formatting;
 
+mod frequency;
+
+mod iso8601;
+pub use iso8601::RepeatingInterval;
+
 /// Structs for duration units such as Seconds and Minutes.
 pub mod data {
 	pub use super::{duration_data::*, duration_units::*};
 }
 
+mod duration_bytes;
 mod duration_data;
 mod duration_operations;
 mod duration_units;
@@ -24,6 +37,13 @@ impl Duration {
 		self.seconds < 0 || (self.seconds == 0 && self.nanos < 0)
 	}
 
+	/// Creates a duration from a number of whole seconds, in a const context.
+	#[must_use]
+	#[inline]
+	pub const fn const_from_secs(seconds: i64) -> Self {
+		Self { seconds, nanos: 0 }
+	}
+
 	#[must_use]
 	#[inline]
 	pub fn new(seconds: i64, nanos: i32) -> Self {
@@ -33,6 +53,97 @@ impl Duration {
 	}
 }
 
+impl Duration {
+	/// Sets the seconds, checking that they are within the range allowed by the protobuf spec and that their sign is consistent with the nanos.
+	///
+	/// The duration is left unchanged if the check fails.
+	pub const fn set_seconds_checked(&mut self, seconds: i64) -> Result<(), InvariantViolation> {
+		if seconds < -MAX_SECONDS || seconds > MAX_SECONDS {
+			return Err(InvariantViolation::SecondsOutOfRange { seconds });
+		}
+		if let Err(e) = check_signs(seconds, self.nanos) {
+			return Err(e);
+		}
+
+		self.seconds = seconds;
+		Ok(())
+	}
+
+	/// Sets the nanos, checking that they are within `-999_999_999..=999_999_999` and that their sign is consistent with the seconds.
+	///
+	/// The duration is left unchanged if the check fails.
+	pub const fn set_nanos_checked(&mut self, nanos: i32) -> Result<(), InvariantViolation> {
+		if nanos.unsigned_abs() >= NANOS_PER_SECOND.unsigned_abs() {
+			return Err(InvariantViolation::NanosOutOfRange { nanos });
+		}
+		if let Err(e) = check_signs(self.seconds, nanos) {
+			return Err(e);
+		}
+
+		self.nanos = nanos;
+		Ok(())
+	}
+}
+
+const fn check_signs(seconds: i64, nanos: i32) -> Result<(), InvariantViolation> {
+	if seconds > 0 && nanos < 0 || seconds < 0 && nanos > 0 {
+		Err(InvariantViolation::SignMismatch { seconds, nanos })
+	} else {
+		Ok(())
+	}
+}
+
+impl ProtoValidate for Duration {
+	type Error = InvariantReport;
+
+	/// Checks the invariants described in [`check_invariants`].
+	#[inline]
+	fn validate(&self) -> Result<(), Self::Error> {
+		check_invariants(self).into_result()
+	}
+}
+
+/// The maximum absolute value for the seconds of a duration, according to the protobuf spec (roughly 10,000 years).
+const MAX_SECONDS: i64 = 315_576_000_000;
+
+/// Checks that the duration is within the range allowed by the protobuf spec, that seconds and nanos have consistent signs and that normalization is idempotent.
+///
+/// Intended for sanity checks on values decoded from untrusted sources, in `debug_assert!` calls or fuzzing harnesses.
+#[must_use]
+pub fn check_invariants(duration: &Duration) -> InvariantReport {
+	let mut report = InvariantReport::default();
+
+	report.check(
+		duration.nanos.unsigned_abs() < NANOS_PER_SECOND.unsigned_abs(),
+		InvariantViolation::NanosOutOfRange {
+			nanos: duration.nanos,
+		},
+	);
+	report.check(
+		(-MAX_SECONDS..=MAX_SECONDS).contains(&duration.seconds),
+		InvariantViolation::SecondsOutOfRange {
+			seconds: duration.seconds,
+		},
+	);
+	report.check(
+		!(duration.seconds > 0 && duration.nanos < 0 || duration.seconds < 0 && duration.nanos > 0),
+		InvariantViolation::SignMismatch {
+			seconds: duration.seconds,
+			nanos: duration.nanos,
+		},
+	);
+
+	let normalized = duration.normalized();
+
+	report.check(normalized == *duration, InvariantViolation::NotNormalized);
+	report.check(
+		normalized.normalized() == normalized,
+		InvariantViolation::NormalizationNotIdempotent,
+	);
+
+	report
+}
+
 #[cfg(test)]
 mod test {
 	use crate::Duration;
@@ -51,4 +162,75 @@ mod test {
 		let negative = Duration::new(-1, 0);
 		assert!(negative.is_negative());
 	}
+
+	#[test]
+	fn invariants() {
+		use super::check_invariants;
+		use crate::invariants::InvariantViolation;
+
+		assert!(check_invariants(&Duration::new(-5, -20)).is_ok());
+
+		let report = check_invariants(&Duration {
+			seconds: 1,
+			nanos: -1_500_000_000,
+		});
+
+		assert!(report.contains(&InvariantViolation::NanosOutOfRange {
+			nanos: -1_500_000_000
+		}));
+		assert!(report.contains(&InvariantViolation::SignMismatch {
+			seconds: 1,
+			nanos: -1_500_000_000
+		}));
+		assert!(report.contains(&InvariantViolation::NotNormalized));
+		assert!(!report.contains(&InvariantViolation::NormalizationNotIdempotent));
+
+		assert_eq!(
+			check_invariants(&Duration {
+				seconds: i64::MAX,
+				nanos: 0
+			})
+			.violations(),
+			&[InvariantViolation::SecondsOutOfRange { seconds: i64::MAX }]
+		);
+
+		assert!(
+			check_invariants(&Duration {
+				seconds: 0,
+				nanos: i32::MIN
+			})
+			.contains(&InvariantViolation::NanosOutOfRange { nanos: i32::MIN })
+		);
+	}
+
+	#[test]
+	fn checked_setters() {
+		use crate::invariants::InvariantViolation;
+
+		let mut duration = Duration::new(5, 0);
+
+		assert_eq!(duration.set_nanos_checked(500), Ok(()));
+		assert_eq!(
+			duration.set_nanos_checked(-500),
+			Err(InvariantViolation::SignMismatch {
+				seconds: 5,
+				nanos: -500
+			})
+		);
+		assert_eq!(
+			duration.set_nanos_checked(1_000_000_000),
+			Err(InvariantViolation::NanosOutOfRange {
+				nanos: 1_000_000_000
+			})
+		);
+		assert_eq!(
+			duration.set_nanos_checked(i32::MIN),
+			Err(InvariantViolation::NanosOutOfRange { nanos: i32::MIN })
+		);
+		assert_eq!(
+			duration.set_seconds_checked(i64::MAX),
+			Err(InvariantViolation::SecondsOutOfRange { seconds: i64::MAX })
+		);
+		assert_eq!(duration, Duration::new(5, 500));
+	}
 }
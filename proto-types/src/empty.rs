@@ -106,7 +106,21 @@ mod serde_impls {
 				}
 			}
 
-			deserializer.deserialize_unit_struct("Empty", EmptyVisitor) // Expect a struct with no fields
+			// `deserialize_unit_struct` would only accept `null` in self-describing formats such as JSON
+			deserializer.deserialize_any(EmptyVisitor)
 		}
 	}
 }
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn json_round_trip() {
+		assert_eq!(serde_json::to_string(&Empty).unwrap(), "{}");
+		assert_eq!(serde_json::from_str::<Empty>("{}").unwrap(), Empty);
+		assert_eq!(serde_json::from_str::<Empty>("null").unwrap(), Empty);
+		assert!(serde_json::from_str::<Empty>(r#"{"a":1}"#).is_err());
+	}
+}
@@ -0,0 +1,225 @@
+//! Helpers to enumerate and parse the variants of the generated enums.
+
+use crate::{Code, DayOfWeek, Month, protobuf::field_descriptor_proto::Type};
+
+macro_rules! impl_enum_helpers {
+	($(#[$meta:meta])* $ty:ty { $($variant:ident),* $(,)? }) => {
+		$(#[$meta])*
+		impl $ty {
+			/// All the variants of this enum, ordered by their numeric value.
+			pub const VARIANTS: &[Self] = &[$(Self::$variant),*];
+
+			/// The number of variants of this enum.
+			pub const COUNT: usize = Self::VARIANTS.len();
+
+			/// Returns an iterator over all the variants of this enum, ordered by their numeric value.
+			#[inline]
+			pub fn iter() -> impl Iterator<Item = Self> {
+				Self::VARIANTS.iter().copied()
+			}
+		}
+
+		// Fails to compile if a variant is missing from the list
+		$(#[$meta])*
+		#[allow(dead_code)]
+		const _: () = {
+			const fn exhaustive(value: $ty) {
+				match value {
+					$(<$ty>::$variant)|* => {}
+				}
+			}
+		};
+	};
+}
+
+impl_enum_helpers!(Code {
+	Ok,
+	Cancelled,
+	Unknown,
+	InvalidArgument,
+	DeadlineExceeded,
+	NotFound,
+	AlreadyExists,
+	PermissionDenied,
+	ResourceExhausted,
+	FailedPrecondition,
+	Aborted,
+	OutOfRange,
+	Unimplemented,
+	Internal,
+	Unavailable,
+	DataLoss,
+	Unauthenticated,
+});
+
+impl_enum_helpers!(
+	#[cfg(feature = "protovalidate")]
+	crate::protovalidate::Ignore {
+		Unspecified,
+		IfZeroValue,
+		Always,
+	}
+);
+
+impl_enum_helpers!(Type {
+	Double,
+	Float,
+	Int64,
+	Uint64,
+	Int32,
+	Fixed64,
+	Fixed32,
+	Bool,
+	String,
+	Group,
+	Message,
+	Bytes,
+	Uint32,
+	Enum,
+	Sfixed32,
+	Sfixed64,
+	Sint32,
+	Sint64,
+});
+
+impl_enum_helpers!(DayOfWeek {
+	Unspecified,
+	Monday,
+	Tuesday,
+	Wednesday,
+	Thursday,
+	Friday,
+	Saturday,
+	Sunday,
+});
+
+impl_enum_helpers!(Month {
+	Unspecified,
+	January,
+	February,
+	March,
+	April,
+	May,
+	June,
+	July,
+	August,
+	September,
+	October,
+	November,
+	December,
+});
+
+#[cfg(feature = "serde")]
+mod serde {
+	use serde::{
+		Deserialize, Deserializer, Serialize, Serializer,
+		de::{self, Visitor},
+	};
+
+	use crate::{format, protobuf::field_descriptor_proto::Type};
+
+	impl Serialize for Type {
+		fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+		where
+			S: Serializer,
+		{
+			serializer.serialize_str(self.as_str_name())
+		}
+	}
+
+	impl<'de> Deserialize<'de> for Type {
+		fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+		where
+			D: Deserializer<'de>,
+		{
+			struct TypeVisitor;
+
+			impl Visitor<'_> for TypeVisitor {
+				type Value = Type;
+
+				fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+					formatter.write_str(
+						"a string representing a field type variant (e.g., \"TYPE_STRING\")",
+					)
+				}
+
+				fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+				where
+					E: de::Error,
+				{
+					Type::from_str_name(v)
+						.ok_or_else(|| E::custom(format!("unknown field type variant: {v}")))
+				}
+			}
+
+			deserializer.deserialize_str(TypeVisitor)
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn assert_round_trips<T: Copy + PartialEq + core::fmt::Debug>(
+		variants: impl Iterator<Item = T>,
+		as_str_name: fn(&T) -> &'static str,
+		from_str_name: fn(&str) -> Option<T>,
+		as_i32: fn(T) -> i32,
+	) {
+		let mut last = None;
+
+		for variant in variants {
+			assert_eq!(from_str_name(as_str_name(&variant)), Some(variant));
+			assert!(last < Some(as_i32(variant)), "{variant:?} is out of order");
+			last = Some(as_i32(variant));
+		}
+	}
+
+	#[test]
+	fn variants() {
+		assert_eq!(Code::COUNT, 17);
+		assert_eq!(Type::COUNT, 18);
+		assert_eq!(DayOfWeek::COUNT, 8);
+		assert_eq!(Month::COUNT, 13);
+
+		assert_round_trips(Code::iter(), Code::as_str_name, Code::from_str_name, |v| {
+			v as i32
+		});
+		assert_round_trips(Type::iter(), Type::as_str_name, Type::from_str_name, |v| {
+			v as i32
+		});
+		assert_round_trips(
+			DayOfWeek::iter(),
+			DayOfWeek::as_str_name,
+			DayOfWeek::from_str_name,
+			|v| v as i32,
+		);
+		assert_round_trips(
+			Month::iter(),
+			Month::as_str_name,
+			Month::from_str_name,
+			|v| v as i32,
+		);
+	}
+
+	#[cfg(feature = "serde")]
+	#[test]
+	fn serde_as_names() {
+		use ::serde::Deserialize;
+		use serde_json::json;
+
+		assert_eq!(serde_json::to_value(Month::March).unwrap(), json!("MARCH"));
+		assert_eq!(Month::deserialize(json!("MARCH")).unwrap(), Month::March);
+		assert_eq!(
+			Type::deserialize(json!("TYPE_STRING")).unwrap(),
+			Type::String
+		);
+		assert!(Month::deserialize(json!("SMARCH")).is_err());
+		assert_eq!(
+			serde_json::to_value(Type::Bytes).unwrap(),
+			json!("TYPE_BYTES")
+		);
+		assert!(Type::deserialize(json!("TYPE_STRINGS")).is_err());
+	}
+}
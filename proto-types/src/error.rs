@@ -0,0 +1,111 @@
+use thiserror::Error;
+
+use crate::{duration::DurationError, invariants::InvariantReport, timestamp::TimestampError};
+
+/// A top-level error wrapping the errors of all the types of this crate.
+///
+/// Every per-type error converts into this one, so that composite operations can use `?` without mapping each error manually.
+/// The wrapped error is exposed as the [`source`](core::error::Error::source) of this one.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum ProtoTypesError {
+	#[error("Timestamp error: {0}")]
+	Timestamp(#[from] TimestampError),
+	#[cfg(feature = "std")]
+	#[error("SystemTime conversion error: {0}")]
+	SystemTime(#[from] crate::timestamp::SystemTimeRangeError),
+	#[error("Duration error: {0}")]
+	Duration(#[from] DurationError),
+	#[error("Invariant check failed: {0}")]
+	Invariants(#[from] InvariantReport),
+	#[cfg(feature = "date")]
+	#[error("Date error: {0}")]
+	Date(#[from] crate::date::DateError),
+	#[cfg(feature = "datetime")]
+	#[error("DateTime error: {0}")]
+	DateTime(#[from] crate::datetime::DateTimeError),
+	#[cfg(feature = "timeofday")]
+	#[error("TimeOfDay error: {0}")]
+	TimeOfDay(#[from] crate::time_of_day::TimeOfDayError),
+	#[cfg(feature = "interval")]
+	#[error("Interval error: {0}")]
+	Interval(#[from] crate::interval::IntervalError),
+	#[cfg(all(feature = "date", feature = "timeofday", feature = "interval"))]
+	#[error("Business calendar error: {0}")]
+	BusinessCalendar(#[from] crate::business_calendar::BusinessCalendarError),
+	#[cfg(feature = "money")]
+	#[error("Money error: {0}")]
+	Money(#[from] crate::money::MoneyError),
+	#[cfg(feature = "latlng")]
+	#[error("LatLng error: {0}")]
+	LatLng(#[from] crate::latlng::LatLngError),
+	#[cfg(feature = "color")]
+	#[error("Color error: {0}")]
+	Color(#[from] crate::color::ColorError),
+	#[cfg(feature = "decimal")]
+	#[error("Decimal error: {0}")]
+	Decimal(#[from] crate::decimal::DecimalError),
+	#[cfg(feature = "fraction")]
+	#[error("Fraction error: {0}")]
+	Fraction(#[from] crate::fraction::FractionError),
+	#[cfg(feature = "rpc")]
+	#[error("gRPC trailers error: {0}")]
+	GrpcTrailers(#[from] crate::rpc::GrpcTrailersError),
+	#[cfg(feature = "rand")]
+	#[error("Weighted selection error: {0}")]
+	Weighted(#[from] crate::weighted::WeightedError),
+	#[error("FieldMask error: {0}")]
+	FieldMask(#[from] crate::FieldMaskError),
+	#[error("ETag error: {0}")]
+	Etag(#[from] crate::EtagError),
+	#[error("Resource name error: {0}")]
+	ResourceName(#[from] crate::resource_name::ResourceNameError),
+	#[cfg(feature = "cel")]
+	#[error("CEL conversion error: {0}")]
+	Cel(#[from] crate::cel::CelConversionError),
+	#[cfg(feature = "prost-reflect")]
+	#[error("Reflection error: {0}")]
+	Reflect(#[from] crate::reflect::ReflectError),
+}
+
+#[cfg(test)]
+mod tests {
+	use core::error::Error;
+
+	use super::*;
+	use crate::{Timestamp, ToString};
+
+	fn parse_and_check(input: &str) -> Result<Timestamp, ProtoTypesError> {
+		let timestamp: Timestamp = input.parse()?;
+		crate::timestamp::check_invariants(&timestamp).into_result()?;
+		Ok(timestamp)
+	}
+
+	#[test]
+	fn source_chain() {
+		let err = parse_and_check("not a timestamp").unwrap_err();
+
+		assert!(matches!(
+			err,
+			ProtoTypesError::Timestamp(TimestampError::ParseFailure)
+		));
+		assert_eq!(
+			err.source().unwrap().to_string(),
+			TimestampError::ParseFailure.to_string()
+		);
+		assert!(parse_and_check("2024-01-01T00:00:00Z").is_ok());
+	}
+
+	#[cfg(all(feature = "date", feature = "datetime"))]
+	#[test]
+	fn date_error_into_datetime_error() {
+		use crate::{date::DateError, datetime::DateTimeError};
+
+		let err: DateTimeError = crate::Date::new(2024, 13, 1).unwrap_err().into();
+		assert_eq!(err, DateTimeError::InvalidMonth);
+		assert_eq!(
+			DateTimeError::from(DateError::ConversionError("x".to_string())),
+			DateTimeError::ConversionError("x".to_string())
+		);
+	}
+}
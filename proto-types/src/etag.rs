@@ -0,0 +1,218 @@
+use core::fmt::Write;
+
+use thiserror::Error;
+
+use crate::{Display, FromStr, String, Timestamp, ToString, fmt};
+
+/// Errors that can occur while creating or parsing an [`Etag`].
+#[derive(Debug, Error, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum EtagError {
+	#[error("Invalid ETag '{0}' (must be a quoted string, optionally prefixed by W/)")]
+	InvalidFormat(String),
+	#[error("Invalid ETag value '{0}' (only visible ASCII characters other than '\"' are allowed)")]
+	InvalidValue(String),
+}
+
+/// An entity tag, as defined by [RFC 9110](https://www.rfc-editor.org/rfc/rfc9110#name-etag), used for optimistic concurrency control.
+///
+/// The [`Display`] and [`FromStr`] impls use the header format (`"abc"` for strong tags, `W/"abc"` for weak ones).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Etag {
+	value: String,
+	weak: bool,
+}
+
+fn is_valid_value(value: &str) -> bool {
+	value
+		.bytes()
+		.all(|b| b.is_ascii_graphic() && b != b'"')
+}
+
+impl Etag {
+	/// Creates a new strong ETag with the given opaque value.
+	pub fn strong(value: impl Into<String>) -> Result<Self, EtagError> {
+		Self::new(value.into(), false)
+	}
+
+	/// Creates a new weak ETag with the given opaque value.
+	pub fn weak(value: impl Into<String>) -> Result<Self, EtagError> {
+		Self::new(value.into(), true)
+	}
+
+	fn new(value: String, weak: bool) -> Result<Self, EtagError> {
+		if is_valid_value(&value) {
+			Ok(Self { value, weak })
+		} else {
+			Err(EtagError::InvalidValue(value))
+		}
+	}
+
+	/// Creates a weak ETag from the last modification time of a resource.
+	///
+	/// The timestamp is normalized, so that equivalent timestamps produce the same tag, and can be recovered with [`timestamp`](Self::timestamp).
+	#[must_use]
+	pub fn from_timestamp(timestamp: &Timestamp) -> Self {
+		let Timestamp { seconds, nanos } = timestamp.normalized();
+
+		Self {
+			value: crate::format!("{seconds}.{nanos:09}"),
+			weak: true,
+		}
+	}
+
+	/// Returns the timestamp that this ETag was created from with [`from_timestamp`](Self::from_timestamp), if it has that format.
+	#[must_use]
+	pub fn timestamp(&self) -> Option<Timestamp> {
+		let (seconds, nanos) = self.value.split_once('.')?;
+
+		if nanos.len() != 9 {
+			return None;
+		}
+
+		Some(Timestamp {
+			seconds: seconds.parse().ok()?,
+			nanos: nanos.parse().ok()?,
+		})
+	}
+
+	/// Returns the opaque value of the ETag, without quotes or weakness indicator.
+	#[must_use]
+	#[inline]
+	pub fn value(&self) -> &str {
+		&self.value
+	}
+
+	/// Checks if the ETag is weak.
+	#[must_use]
+	#[inline]
+	pub const fn is_weak(&self) -> bool {
+		self.weak
+	}
+
+	/// Performs the strong comparison described in RFC 9110, where both tags must be strong and have the same value.
+	///
+	/// This is the comparison to use for `If-Match` preconditions.
+	#[must_use]
+	pub fn strong_eq(&self, other: &Self) -> bool {
+		!self.weak && !other.weak && self.value == other.value
+	}
+
+	/// Performs the weak comparison described in RFC 9110, where only the values must match.
+	///
+	/// This is the comparison to use for `If-None-Match` preconditions.
+	#[must_use]
+	#[inline]
+	pub fn weak_eq(&self, other: &Self) -> bool {
+		self.value == other.value
+	}
+}
+
+#[cfg(feature = "hash")]
+impl Etag {
+	/// Creates a strong ETag from the SHA-256 digest of the encoded message.
+	///
+	/// Messages containing map fields or [`Any`](crate::Any) details in different orders produce different tags,
+	/// so [`from_stable_hash`](Self::from_stable_hash) should be preferred for the well known types.
+	#[must_use]
+	pub fn from_canonical_bytes(message: &impl prost::Message) -> Self {
+		use sha2::{Digest, Sha256};
+
+		Self::from_digest(&Sha256::digest(message.encode_to_vec()).into())
+	}
+
+	/// Creates a strong ETag from the [`StableHash`](crate::StableHash) of the value.
+	#[must_use]
+	pub fn from_stable_hash(value: &impl crate::StableHash) -> Self {
+		Self::from_digest(&value.stable_hash_sha256())
+	}
+
+	/// Uses the first 128 bits of the digest, encoded as hex.
+	fn from_digest(digest: &[u8; 32]) -> Self {
+		let mut value = String::with_capacity(32);
+
+		for byte in &digest[..16] {
+			let _ = write!(value, "{byte:02x}");
+		}
+
+		Self { value, weak: false }
+	}
+}
+
+impl Display for Etag {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if self.weak {
+			f.write_str("W/")?;
+		}
+
+		f.write_char('"')?;
+		f.write_str(&self.value)?;
+		f.write_char('"')
+	}
+}
+
+impl FromStr for Etag {
+	type Err = EtagError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let (weak, quoted) = match s.strip_prefix("W/") {
+			Some(rest) => (true, rest),
+			None => (false, s),
+		};
+
+		let value = quoted
+			.strip_prefix('"')
+			.and_then(|v| v.strip_suffix('"'))
+			.ok_or_else(|| EtagError::InvalidFormat(s.to_string()))?;
+
+		Self::new(value.to_string(), weak)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn timestamp_etag_round_trip() {
+		let ts = Timestamp::new(1_700_000_000, 5_000);
+		let etag = Etag::from_timestamp(&ts);
+
+		assert_eq!(etag.to_string(), "W/\"1700000000.000005000\"");
+
+		let parsed: Etag = etag.to_string().parse().unwrap();
+		assert_eq!(parsed, etag);
+		assert_eq!(parsed.timestamp(), Some(ts));
+		assert_eq!(Etag::strong("abc").unwrap().timestamp(), None);
+	}
+
+	#[test]
+	fn comparisons_and_parsing() {
+		let strong: Etag = "\"v1\"".parse().unwrap();
+		let weak: Etag = "W/\"v1\"".parse().unwrap();
+
+		assert!(strong.strong_eq(&strong));
+		assert!(!strong.strong_eq(&weak));
+		assert!(strong.weak_eq(&weak));
+
+		assert!("v1".parse::<Etag>().is_err());
+		assert!("\"a\"b\"".parse::<Etag>().is_err());
+		assert!(Etag::weak("has space").is_err());
+	}
+
+	#[cfg(feature = "hash")]
+	#[test]
+	fn content_etags() {
+		let a = Etag::from_canonical_bytes(&Timestamp::new(10, 0));
+		let b = Etag::from_canonical_bytes(&Timestamp::new(10, 0));
+
+		assert_eq!(a, b);
+		assert!(!a.is_weak());
+		assert_eq!(a.value().len(), 32);
+		assert_ne!(a, Etag::from_canonical_bytes(&Timestamp::new(11, 0)));
+		assert_eq!(
+			Etag::from_stable_hash(&Timestamp::new(9, 1_000_000_000)),
+			Etag::from_stable_hash(&Timestamp::new(10, 0))
+		);
+	}
+}
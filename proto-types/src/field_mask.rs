@@ -73,27 +73,43 @@ mod serde_impls {
 
 	use core::fmt;
 
-	use serde::{Deserialize, Serialize};
+	use ::serde::{Deserialize, Serialize};
 
 	use crate::FieldMask;
+
+	/// Writes the paths separated by commas, without joining them into an intermediate String.
+	struct JoinedPaths<'a>(&'a [String]);
+
+	impl fmt::Display for JoinedPaths<'_> {
+		fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+			for (i, path) in self.0.iter().enumerate() {
+				if i > 0 {
+					f.write_str(",")?;
+				}
+				f.write_str(path)?;
+			}
+
+			Ok(())
+		}
+	}
+
 	impl Serialize for FieldMask {
 		fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
 		where
-			S: serde::Serializer,
+			S: ::serde::Serializer,
 		{
-			let joined_paths = self.paths.join(",");
-			serializer.serialize_str(&joined_paths)
+			serializer.collect_str(&JoinedPaths(&self.paths))
 		}
 	}
 
 	impl<'de> Deserialize<'de> for FieldMask {
 		fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
 		where
-			D: serde::Deserializer<'de>,
+			D: ::serde::Deserializer<'de>,
 		{
 			struct FieldMaskVisitor;
 
-			impl serde::de::Visitor<'_> for FieldMaskVisitor {
+			impl ::serde::de::Visitor<'_> for FieldMaskVisitor {
 				type Value = FieldMask;
 
 				fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -102,16 +118,14 @@ mod serde_impls {
 
 				fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
 				where
-					E: serde::de::Error,
+					E: ::serde::de::Error,
 				{
 					if value.is_empty() {
 						return Ok(FieldMask { paths: Vec::new() });
 					}
 
-					let paths: Vec<String> = value
-						.split(",")
-						.map(|s| s.trim().to_string())
-						.collect();
+					let mut paths = Vec::with_capacity(value.matches(',').count() + 1);
+					paths.extend(value.split(',').map(|s| s.trim().to_string()));
 
 					Ok(FieldMask { paths })
 				}
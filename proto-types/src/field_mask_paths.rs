@@ -0,0 +1,437 @@
+use core::fmt::Write;
+
+use thiserror::Error;
+
+use crate::{FieldMask, String, Vec};
+
+/// Errors that can occur when validating the paths of a [`FieldMask`].
+#[derive(Debug, Error, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum FieldMaskError {
+	#[error("Invalid field mask path '{0}'")]
+	InvalidPath(String),
+	#[error("Wildcard segments are not allowed in strict mode (path '{0}')")]
+	WildcardNotAllowed(String),
+	#[error("Map key segments are not allowed in strict mode (path '{0}')")]
+	MapKeyNotAllowed(String),
+}
+
+/// The set of path syntaxes accepted by [`FieldMask::validate_paths`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum MaskStrictness {
+	/// Only field names are allowed, as required by the canonical FieldMask spec.
+	#[default]
+	Strict,
+	/// Also allows the `*` wildcard segment and map key segments, as described in [AIP-161](https://google.aip.dev/161).
+	///
+	/// Map keys that are not valid identifiers or integers must be quoted with backticks (e.g. ``labels.`my.key` ``), with literal backticks escaped by doubling them.
+	Relaxed,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+	Name(String),
+	Wildcard,
+}
+
+fn is_identifier(s: &str) -> bool {
+	let mut chars = s.chars();
+
+	chars
+		.next()
+		.is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+		&& chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn is_integer(s: &str) -> bool {
+	let digits = s.strip_prefix('-').unwrap_or(s);
+
+	!digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Splits a path into its segments, returning the segments along with a flag indicating whether any of them was quoted.
+fn parse_segments(path: &str) -> Option<(Vec<Segment>, bool)> {
+	let mut segments = Vec::new();
+	let mut quoted_any = false;
+	let mut chars = path.chars().peekable();
+
+	loop {
+		if chars.peek() == Some(&'`') {
+			chars.next();
+			quoted_any = true;
+
+			let mut key = String::new();
+
+			loop {
+				match chars.next()? {
+					'`' if chars.peek() == Some(&'`') => {
+						chars.next();
+						key.push('`');
+					}
+					'`' => break,
+					c => key.push(c),
+				}
+			}
+
+			segments.push(Segment::Name(key));
+		} else {
+			let mut name = String::new();
+
+			while let Some(&c) = chars.peek() {
+				if c == '.' {
+					break;
+				}
+				name.push(c);
+				chars.next();
+			}
+
+			let segment = match name.as_str() {
+				"*" => Segment::Wildcard,
+				_ if is_identifier(&name) || is_integer(&name) => Segment::Name(name),
+				_ => return None,
+			};
+
+			segments.push(segment);
+		}
+
+		match chars.next() {
+			None => return Some((segments, quoted_any)),
+			Some('.') => {}
+			Some(_) => return None,
+		}
+	}
+}
+
+fn write_path(segments: &[Segment]) -> String {
+	let mut output = String::new();
+
+	for (i, segment) in segments.iter().enumerate() {
+		if i > 0 {
+			output.push('.');
+		}
+
+		match segment {
+			Segment::Wildcard => output.push('*'),
+			Segment::Name(name) if is_identifier(name) || is_integer(name) => {
+				output.push_str(name);
+			}
+			Segment::Name(name) => {
+				let _ = write!(output, "`{}`", name.replace('`', "``"));
+			}
+		}
+	}
+
+	output
+}
+
+/// Checks if the mask path selects the given path, which is the case when it is a prefix of it (with `*` matching any segment).
+fn prefix_covers(mask: &[Segment], path: &[Segment]) -> bool {
+	mask.len() <= path.len()
+		&& mask
+			.iter()
+			.zip(path)
+			.all(|(m, p)| *m == Segment::Wildcard || m == p)
+}
+
+/// Returns the path selecting the fields that are selected by both of the given paths, if any.
+fn intersect_paths(a: &[Segment], b: &[Segment]) -> Option<Vec<Segment>> {
+	let mut output = Vec::with_capacity(a.len().max(b.len()));
+
+	for (sa, sb) in a.iter().zip(b) {
+		let segment = match (sa, sb) {
+			(Segment::Wildcard, other) | (other, Segment::Wildcard) => other.clone(),
+			(sa, sb) if sa == sb => sa.clone(),
+			_ => return None,
+		};
+
+		output.push(segment);
+	}
+
+	let longer = if a.len() > b.len() { a } else { b };
+	output.extend_from_slice(&longer[output.len()..]);
+
+	Some(output)
+}
+
+impl FieldMask {
+	/// Validates the syntax of each path with the given strictness.
+	pub fn validate_paths(&self, strictness: MaskStrictness) -> Result<(), FieldMaskError> {
+		for path in &self.paths {
+			let (segments, quoted_any) =
+				parse_segments(path).ok_or_else(|| FieldMaskError::InvalidPath(path.clone()))?;
+
+			if strictness == MaskStrictness::Strict {
+				if segments.contains(&Segment::Wildcard) {
+					return Err(FieldMaskError::WildcardNotAllowed(path.clone()));
+				}
+
+				let has_map_key = quoted_any
+					|| segments
+						.iter()
+						.any(|s| matches!(s, Segment::Name(name) if !is_identifier(name)));
+
+				if has_map_key {
+					return Err(FieldMaskError::MapKeyNotAllowed(path.clone()));
+				}
+			}
+		}
+
+		Ok(())
+	}
+
+	/// Checks if the given path is selected by the mask, which is the case when one of the paths in the mask is equal to it or a prefix of it.
+	///
+	/// A `*` segment in the mask matches any field name or map key.
+	/// Returns false if the path (or the relevant mask path) cannot be parsed.
+	#[must_use]
+	pub fn covers(&self, path: &str) -> bool {
+		let Some((path, _)) = parse_segments(path) else {
+			return false;
+		};
+
+		self.paths
+			.iter()
+			.filter_map(|p| parse_segments(p))
+			.any(|(mask, _)| prefix_covers(&mask, &path))
+	}
+
+	/// Returns a mask selecting the fields that are selected by both masks.
+	///
+	/// Wildcard segments are resolved against the other mask where possible (e.g. `labels.*` and `labels.env` intersect into `labels.env`), and redundant paths are removed.
+	/// Paths that cannot be parsed are ignored.
+	#[must_use]
+	pub fn intersect(&self, other: &Self) -> Self {
+		let parse_all = |mask: &Self| -> Vec<Vec<Segment>> {
+			mask.paths
+				.iter()
+				.filter_map(|p| parse_segments(p).map(|(segments, _)| segments))
+				.collect()
+		};
+
+		let (left, right) = (parse_all(self), parse_all(other));
+
+		let mut paths: Vec<Vec<Segment>> = Vec::new();
+
+		for a in &left {
+			for b in &right {
+				if let Some(path) = intersect_paths(a, b)
+					&& !paths.contains(&path)
+				{
+					paths.push(path);
+				}
+			}
+		}
+
+		let covered_by_other = |i: usize, path: &[Segment]| {
+			// Paths are deduplicated, so two distinct paths cannot cover each other
+			paths
+				.iter()
+				.enumerate()
+				.any(|(j, other)| i != j && prefix_covers(other, path))
+		};
+
+		paths
+			.iter()
+			.enumerate()
+			.filter(|(i, path)| !covered_by_other(*i, path))
+			.map(|(_, path)| write_path(path))
+			.collect()
+	}
+}
+
+#[cfg(feature = "serde")]
+mod json {
+	use serde_json::{Map, Value};
+
+	use super::*;
+
+	/// Projects the value on the given path, descending into every element of the arrays that are found along the way.
+	fn project(value: &Value, path: &[Segment]) -> Option<Value> {
+		let Some((first, rest)) = path.split_first() else {
+			return Some(value.clone());
+		};
+
+		match value {
+			Value::Array(items) => {
+				// A wildcard on a repeated field selects every element
+				let path = if *first == Segment::Wildcard {
+					rest
+				} else {
+					path
+				};
+
+				Some(Value::Array(
+					items
+						.iter()
+						.map(|item| project(item, path).unwrap_or(Value::Null))
+						.collect(),
+				))
+			}
+			Value::Object(fields) => {
+				let mut output = Map::new();
+
+				match first {
+					Segment::Wildcard => {
+						for (key, child) in fields {
+							if let Some(projected) = project(child, rest) {
+								output.insert(key.clone(), projected);
+							}
+						}
+					}
+					Segment::Name(name) => {
+						if let Some(projected) = fields.get(name).and_then(|c| project(c, rest)) {
+							output.insert(name.clone(), projected);
+						}
+					}
+				}
+
+				Some(Value::Object(output))
+			}
+			_ => None,
+		}
+	}
+
+	fn merge(into: &mut Value, from: Value) {
+		match (into, from) {
+			(Value::Object(into), Value::Object(from)) => {
+				for (key, value) in from {
+					match into.get_mut(&key) {
+						Some(existing) => merge(existing, value),
+						None => {
+							into.insert(key, value);
+						}
+					}
+				}
+			}
+			(Value::Array(into), Value::Array(from)) => {
+				for (existing, value) in into.iter_mut().zip(from) {
+					merge(existing, value);
+				}
+			}
+			(into @ Value::Null, from) => *into = from,
+			_ => {}
+		}
+	}
+
+	impl FieldMask {
+		/// Returns a copy of the JSON value containing only the fields selected by the mask.
+		///
+		/// Map keys and `*` segments are matched against the keys of the JSON objects.
+		/// Paths that go through a repeated field are applied to each of its elements, so that `items.name` keeps the `name` of every item.
+		/// Paths that cannot be parsed are ignored.
+		#[must_use]
+		pub fn apply_to_json(&self, value: &Value) -> Value {
+			let mut output = match value {
+				Value::Object(_) => Value::Object(Map::new()),
+				other => return other.clone(),
+			};
+
+			for path in &self.paths {
+				if let Some((segments, _)) = parse_segments(path)
+					&& let Some(projected) = project(value, &segments)
+				{
+					merge(&mut output, projected);
+				}
+			}
+
+			output
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::ToString;
+
+	fn mask(paths: &[&str]) -> FieldMask {
+		paths.iter().map(ToString::to_string).collect()
+	}
+
+	#[test]
+	fn strictness() {
+		assert!(
+			mask(&["a.b", "c"])
+				.validate_paths(MaskStrictness::Strict)
+				.is_ok()
+		);
+		assert_eq!(
+			mask(&["labels.*"]).validate_paths(MaskStrictness::Strict),
+			Err(FieldMaskError::WildcardNotAllowed("labels.*".into()))
+		);
+		assert_eq!(
+			mask(&["labels.`a.b`"]).validate_paths(MaskStrictness::Strict),
+			Err(FieldMaskError::MapKeyNotAllowed("labels.`a.b`".into()))
+		);
+		assert!(
+			mask(&["labels.*", "labels.`a.b`", "counts.42"])
+				.validate_paths(MaskStrictness::Relaxed)
+				.is_ok()
+		);
+		assert!(
+			mask(&["a..b"])
+				.validate_paths(MaskStrictness::Relaxed)
+				.is_err()
+		);
+		assert!(
+			mask(&["labels.`open"])
+				.validate_paths(MaskStrictness::Relaxed)
+				.is_err()
+		);
+	}
+
+	#[test]
+	fn wildcard_covers() {
+		let mask = mask(&["labels.*", "items.*.name", "title"]);
+
+		assert!(mask.covers("labels.env"));
+		assert!(mask.covers("labels.`a.b`.value"));
+		assert!(mask.covers("items.0.name"));
+		assert!(mask.covers("title.text"));
+		assert!(!mask.covers("labels"));
+		assert!(!mask.covers("items.0.id"));
+	}
+
+	#[test]
+	fn intersection() {
+		let a = mask(&["labels.*", "a.*.c", "title"]);
+		let b = mask(&["labels.env", "a.b", "other"]);
+
+		assert_eq!(a.intersect(&b), mask(&["labels.env", "a.b.c"]));
+		assert_eq!(
+			mask(&["a"]).intersect(&mask(&["a.b", "a.c"])),
+			mask(&["a.b", "a.c"])
+		);
+		assert_eq!(mask(&["a", "a.b"]).intersect(&mask(&["a"])), mask(&["a"]));
+	}
+
+	#[cfg(feature = "serde")]
+	#[test]
+	fn apply_to_json() {
+		use serde_json::json;
+
+		let value = json!({
+			"title": "t",
+			"labels": { "env": "prod", "a.b": "x", "team": "core" },
+			"items": [{ "name": "n1", "id": 1 }, { "name": "n2", "id": 2 }],
+			"hidden": true
+		});
+
+		let applied =
+			mask(&["labels.env", "labels.`a.b`", "items.name", "title"]).apply_to_json(&value);
+
+		assert_eq!(
+			applied,
+			json!({
+				"title": "t",
+				"labels": { "env": "prod", "a.b": "x" },
+				"items": [{ "name": "n1" }, { "name": "n2" }]
+			})
+		);
+
+		assert_eq!(
+			mask(&["labels.*"]).apply_to_json(&value),
+			json!({ "labels": { "env": "prod", "a.b": "x", "team": "core" } })
+		);
+	}
+}
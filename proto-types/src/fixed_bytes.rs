@@ -0,0 +1,45 @@
+//! The fixed-size binary layout shared by [`Timestamp`](crate::Timestamp) and [`Duration`](crate::Duration), made of the seconds as an `i64` followed by the nanos as an `i32`.
+
+/// The size of the encoded seconds and nanos.
+pub(crate) const BYTES_LEN: usize = 12;
+
+fn join(seconds: [u8; 8], nanos: [u8; 4]) -> [u8; BYTES_LEN] {
+	let mut bytes = [0; BYTES_LEN];
+
+	bytes[..8].copy_from_slice(&seconds);
+	bytes[8..].copy_from_slice(&nanos);
+
+	bytes
+}
+
+fn split(bytes: [u8; BYTES_LEN]) -> ([u8; 8], [u8; 4]) {
+	let mut seconds = [0; 8];
+	let mut nanos = [0; 4];
+
+	seconds.copy_from_slice(&bytes[..8]);
+	nanos.copy_from_slice(&bytes[8..]);
+
+	(seconds, nanos)
+}
+
+pub(crate) fn to_be_bytes(seconds: i64, nanos: i32) -> [u8; BYTES_LEN] {
+	join(seconds.to_be_bytes(), nanos.to_be_bytes())
+}
+
+pub(crate) fn to_le_bytes(seconds: i64, nanos: i32) -> [u8; BYTES_LEN] {
+	join(seconds.to_le_bytes(), nanos.to_le_bytes())
+}
+
+/// Returns the seconds and the nanos, without checking them.
+pub(crate) fn from_be_bytes(bytes: [u8; BYTES_LEN]) -> (i64, i32) {
+	let (seconds, nanos) = split(bytes);
+
+	(i64::from_be_bytes(seconds), i32::from_be_bytes(nanos))
+}
+
+/// Returns the seconds and the nanos, without checking them.
+pub(crate) fn from_le_bytes(bytes: [u8; BYTES_LEN]) -> (i64, i32) {
+	let (seconds, nanos) = split(bytes);
+
+	(i64::from_le_bytes(seconds), i32::from_le_bytes(nanos))
+}
@@ -0,0 +1,34 @@
+//! Integer helpers shared by the exact arithmetic of the fractional and monetary types.
+
+/// Returns the greatest common divisor of two integers, which is 0 only if both are 0.
+pub(crate) const fn gcd_u128(mut a: u128, mut b: u128) -> u128 {
+	while b != 0 {
+		let temp = b;
+		b = a % b;
+		a = temp;
+	}
+
+	a
+}
+
+/// Returns the greatest common divisor of the magnitudes of two integers.
+///
+/// The result is unsigned, since the divisor of `i128::MIN` and 0 does not fit in an `i128`.
+pub(crate) const fn gcd_i128(a: i128, b: i128) -> u128 {
+	gcd_u128(a.unsigned_abs(), b.unsigned_abs())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn greatest_common_divisor() {
+		assert_eq!(gcd_u128(12, 18), 6);
+		assert_eq!(gcd_u128(0, 7), 7);
+		assert_eq!(gcd_u128(0, 0), 0);
+
+		assert_eq!(gcd_i128(-12, 18), 6);
+		assert_eq!(gcd_i128(i128::MIN, 0), 1 << 127);
+	}
+}
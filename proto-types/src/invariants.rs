@@ -0,0 +1,117 @@
+use core::fmt::{self, Display};
+
+use thiserror::Error;
+
+use crate::Vec;
+
+/// A single invariant that was found to be violated by a value.
+#[derive(Debug, Error, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum InvariantViolation {
+	#[error("Nanos value {nanos} is outside of the allowed range")]
+	NanosOutOfRange { nanos: i32 },
+	#[error("Seconds value {seconds} is outside of the allowed range")]
+	SecondsOutOfRange { seconds: i64 },
+	#[error("Seconds ({seconds}) and nanos ({nanos}) have different signs")]
+	SignMismatch { seconds: i64, nanos: i32 },
+	#[error("The value is not in its normalized form")]
+	NotNormalized,
+	#[error("Normalizing the value twice produced a different result than normalizing it once")]
+	NormalizationNotIdempotent,
+}
+
+/// The result of an invariant check, listing all the violations that were found.
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct InvariantReport {
+	violations: Vec<InvariantViolation>,
+}
+
+impl InvariantReport {
+	pub(crate) fn check(&mut self, condition: bool, violation: InvariantViolation) {
+		if !condition {
+			self.violations.push(violation);
+		}
+	}
+
+	/// Returns `true` if no violations were found.
+	#[must_use]
+	#[inline]
+	pub const fn is_ok(&self) -> bool {
+		self.violations.is_empty()
+	}
+
+	/// Returns the violations that were found.
+	#[must_use]
+	#[inline]
+	pub fn violations(&self) -> &[InvariantViolation] {
+		&self.violations
+	}
+
+	/// Returns `true` if the given violation was found.
+	#[must_use]
+	pub fn contains(&self, violation: &InvariantViolation) -> bool {
+		self.violations.contains(violation)
+	}
+
+	/// Converts the report into a [`Result`], which is `Err` if any violation was found.
+	pub fn into_result(self) -> Result<(), Self> {
+		if self.is_ok() { Ok(()) } else { Err(self) }
+	}
+}
+
+impl Display for InvariantReport {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if self.violations.is_empty() {
+			return write!(f, "All invariants hold");
+		}
+
+		for (i, violation) in self.violations.iter().enumerate() {
+			if i > 0 {
+				write!(f, "; ")?;
+			}
+
+			write!(f, "{violation}")?;
+		}
+
+		Ok(())
+	}
+}
+
+impl core::error::Error for InvariantReport {}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{Timestamp, ToString, timestamp::check_invariants};
+
+	#[test]
+	fn timestamp_invariants() {
+		assert!(check_invariants(&Timestamp::new(1_700_000_000, 5)).is_ok());
+
+		let report = check_invariants(&Timestamp {
+			seconds: 10,
+			nanos: -1,
+		});
+
+		assert_eq!(
+			report.violations(),
+			&[
+				InvariantViolation::NanosOutOfRange { nanos: -1 },
+				InvariantViolation::NotNormalized
+			]
+		);
+		assert_eq!(
+			report.to_string(),
+			"Nanos value -1 is outside of the allowed range; The value is not in its normalized form"
+		);
+		assert!(report.into_result().is_err());
+
+		assert!(
+			check_invariants(&Timestamp::new(-62_135_596_801, 0)).contains(
+				&InvariantViolation::SecondsOutOfRange {
+					seconds: -62_135_596_801
+				}
+			)
+		);
+	}
+}
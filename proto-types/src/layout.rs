@@ -0,0 +1,97 @@
+//! The sizes and alignments of the plain value types of this crate, which are checked at compile time.
+//!
+//! Adding a field to any of these types causes a compilation failure, so that code relying on these
+//! values (for example when sizing buffers for FFI or zero-copy archives) cannot silently break.
+//!
+//! Keep in mind that these types are not `#[repr(C)]`, so only their size and alignment are guaranteed,
+//! not the order of their fields in memory.
+
+use core::mem::{align_of, size_of};
+
+use crate::{Duration, Empty, Timestamp};
+
+/// The size of a [`Timestamp`] in bytes (16 on most targets).
+pub const TIMESTAMP_SIZE: usize = padded(8 + 4, TIMESTAMP_ALIGN);
+/// The alignment of a [`Timestamp`] in bytes, which is the alignment of `i64` (8 on most targets).
+pub const TIMESTAMP_ALIGN: usize = align_of::<i64>();
+
+/// The size of a [`Duration`] in bytes (16 on most targets).
+pub const DURATION_SIZE: usize = padded(8 + 4, DURATION_ALIGN);
+/// The alignment of a [`Duration`] in bytes, which is the alignment of `i64` (8 on most targets).
+pub const DURATION_ALIGN: usize = align_of::<i64>();
+
+/// The size of an [`Empty`] in bytes.
+pub const EMPTY_SIZE: usize = 0;
+
+/// The size of a [`Date`](crate::Date) in bytes.
+#[cfg(feature = "date")]
+pub const DATE_SIZE: usize = 12;
+/// The alignment of a [`Date`](crate::Date) in bytes.
+#[cfg(feature = "date")]
+pub const DATE_ALIGN: usize = 4;
+
+/// The size of a [`TimeOfDay`](crate::TimeOfDay) in bytes.
+#[cfg(feature = "timeofday")]
+pub const TIME_OF_DAY_SIZE: usize = 16;
+/// The alignment of a [`TimeOfDay`](crate::TimeOfDay) in bytes.
+#[cfg(feature = "timeofday")]
+pub const TIME_OF_DAY_ALIGN: usize = 4;
+
+/// The size of a [`LatLng`](crate::LatLng) in bytes.
+#[cfg(feature = "latlng")]
+pub const LATLNG_SIZE: usize = 16;
+/// The alignment of a [`LatLng`](crate::LatLng) in bytes, which is the alignment of `f64` (8 on most targets).
+#[cfg(feature = "latlng")]
+pub const LATLNG_ALIGN: usize = align_of::<f64>();
+
+/// The size of a [`Fraction`](crate::Fraction) in bytes.
+#[cfg(feature = "fraction")]
+pub const FRACTION_SIZE: usize = 16;
+/// The alignment of a [`Fraction`](crate::Fraction) in bytes, which is the alignment of `i64` (8 on most targets).
+#[cfg(feature = "fraction")]
+pub const FRACTION_ALIGN: usize = align_of::<i64>();
+
+/// Rounds `size` up to the next multiple of `align`, like the compiler does for struct sizes.
+const fn padded(size: usize, align: usize) -> usize {
+	size.next_multiple_of(align)
+}
+
+macro_rules! assert_layout {
+	($ty:ty, $size:expr, $align:expr) => {
+		const _: () = assert!(size_of::<$ty>() == $size && align_of::<$ty>() == $align);
+	};
+}
+
+assert_layout!(Timestamp, TIMESTAMP_SIZE, TIMESTAMP_ALIGN);
+assert_layout!(Duration, DURATION_SIZE, DURATION_ALIGN);
+assert_layout!(Empty, EMPTY_SIZE, 1);
+
+#[cfg(feature = "date")]
+assert_layout!(crate::Date, DATE_SIZE, DATE_ALIGN);
+#[cfg(feature = "timeofday")]
+assert_layout!(crate::TimeOfDay, TIME_OF_DAY_SIZE, TIME_OF_DAY_ALIGN);
+#[cfg(feature = "latlng")]
+assert_layout!(crate::LatLng, LATLNG_SIZE, LATLNG_ALIGN);
+#[cfg(feature = "fraction")]
+assert_layout!(crate::Fraction, FRACTION_SIZE, FRACTION_ALIGN);
+
+macro_rules! assert_wrapper_layout {
+	($($wrapper:ident => $target:ty),* $(,)?) => {
+		$(
+			const _: () = assert!(
+				size_of::<crate::num_wrappers::$wrapper>() == size_of::<$target>()
+					&& align_of::<crate::num_wrappers::$wrapper>() == align_of::<$target>()
+			);
+		)*
+	};
+}
+
+// The wrappers for the protobuf numeric types have the same layout as the integers they wrap
+assert_wrapper_layout!(
+	Sint64 => i64,
+	Sint32 => i32,
+	Sfixed64 => i64,
+	Sfixed32 => i32,
+	Fixed64 => u64,
+	Fixed32 => u32,
+);
@@ -24,6 +24,22 @@ pub mod protovalidate;
 #[cfg(feature = "cel")]
 pub mod cel;
 
+/// Helpers for mapping the types of this crate in `prost-build` configurations.
+#[cfg(feature = "build")]
+pub mod build;
+
+/// Utilities for loading descriptor sets and inspecting [`Any`] messages of unknown types.
+#[cfg(feature = "descriptor")]
+pub mod descriptor;
+
+/// Const helpers for the compile-time checked `timestamp!`, `duration!` and `date!` macros.
+#[doc(hidden)]
+pub mod macros;
+
+/// Interoperability with the dynamic messages from `prost-reflect`.
+#[cfg(feature = "prost-reflect")]
+pub mod reflect;
+
 pub mod rpc;
 pub use rpc::*;
 
@@ -41,8 +57,32 @@ pub mod duration;
 
 pub mod timestamp;
 
+/// Re-exports of the most commonly used types and traits, for glob imports.
+pub mod prelude;
+
+pub mod layout;
+
+pub mod resource_name;
+
+mod etag;
+pub use etag::{Etag, EtagError};
+
+/// Canonical encodings of the well known types, for conformance tests.
+#[cfg(feature = "test-vectors")]
+pub mod test_vectors;
+
+pub mod clock;
+
+#[cfg(any(test, feature = "testkit"))]
+pub mod testkit;
+
+/// Reports for the sanity checks performed by [`duration::check_invariants`] and [`timestamp::check_invariants`].
+pub mod invariants;
+
 mod any;
 mod any_impls;
+mod any_list;
+pub use any_list::{AnyListExt, AnyRegistry};
 #[cfg(any(
 	feature = "diesel-postgres",
 	feature = "diesel-sqlite",
@@ -51,6 +91,30 @@ mod any_impls;
 mod diesel_impls;
 
 mod field_mask;
+mod field_mask_paths;
+pub use field_mask_paths::{FieldMaskError, MaskStrictness};
+
+#[cfg(feature = "serde")]
+mod serde_strict;
+
+/// Lenient serde adapters for configuration files.
+#[cfg(feature = "serde")]
+pub mod serde;
+
+#[cfg(all(feature = "serde", feature = "chrono"))]
+pub mod chrono_serde;
+
+#[cfg(feature = "prost-types-compat")]
+mod prost_types_compat;
+
+#[cfg(feature = "rand")]
+pub mod weighted;
+
+#[cfg(feature = "metrics")]
+mod metrics;
+
+#[cfg(feature = "opentelemetry")]
+mod opentelemetry;
 
 mod field_type;
 #[doc(inline)]
@@ -59,9 +123,44 @@ pub use field_type::FieldType;
 mod empty;
 pub use empty::Empty;
 
+mod enum_helpers;
+
+mod validate;
+pub use validate::{ProtoValidate, Validated};
+
+mod decode_validated;
+pub use decode_validated::{DecodeValidatedError, DecodeValidatedExt, decode_validated};
+
+mod error;
+pub use error::ProtoTypesError;
+
+mod canonical;
+pub use canonical::{CanonicalHash, CanonicalOrd};
+
+mod tabulate;
+pub use tabulate::{Align, Tabulate};
+
+mod string_conversions;
+
+mod rounding;
+pub use rounding::RoundingMode;
+
+#[cfg(feature = "hash")]
+mod stable_hash;
+#[cfg(feature = "hash")]
+pub use stable_hash::StableHash;
+
+#[cfg(feature = "zeroize")]
+mod zeroize_impls;
+
+pub mod calendar;
 mod constants;
 mod conversions;
 mod datetime_internal;
+mod fixed_bytes;
+#[cfg(any(feature = "fraction", feature = "money"))]
+mod integer;
+mod text_format;
 mod type_url;
 
 use alloc::{format, string::String, string::ToString, vec::Vec};
@@ -73,4 +172,5 @@ use core::{
 };
 
 use prost::{DecodeError, EncodeError, Message, Name};
-pub(crate) use type_url::{TypeUrl, type_url_for};
+pub use type_url::TypeUrl;
+pub(crate) use type_url::type_url_for;
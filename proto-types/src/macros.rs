@@ -0,0 +1,395 @@
+//! Const parsers used by [`Timestamp::const_from_rfc3339`] and by the `timestamp!`, `duration!` and `date!` macros.
+
+use crate::{
+	Duration, Timestamp,
+	calendar::{days_from_civil, days_in_month},
+	constants::NANOS_PER_SECOND,
+};
+
+/// Creates a [`Timestamp`](crate::Timestamp) from an RFC 3339 string literal, which is validated at compile time.
+///
+/// Accepts a `Z` or `±HH:MM` offset and up to 9 fractional digits, such as `timestamp!("2024-05-01T10:00:00.5+02:00")`.
+#[cfg(feature = "macros")]
+#[macro_export]
+macro_rules! timestamp {
+	($value:literal) => {
+		const {
+			match $crate::macros::parse_timestamp($value) {
+				::core::option::Option::Some(timestamp) => timestamp,
+				::core::option::Option::None => {
+					::core::panic!(::core::concat!("Invalid timestamp: ", $value))
+				}
+			}
+		}
+	};
+}
+
+/// Creates a [`Duration`](crate::Duration) from a string literal such as `"1h30m"` or `"-250ms"`, which is validated at compile time.
+///
+/// The supported units are `d`, `h`, `m`, `s`, `ms`, `us` and `ns`.
+#[cfg(feature = "macros")]
+#[macro_export]
+macro_rules! duration {
+	($value:literal) => {
+		const {
+			match $crate::macros::parse_duration($value) {
+				::core::option::Option::Some(duration) => duration,
+				::core::option::Option::None => {
+					::core::panic!(::core::concat!("Invalid duration: ", $value))
+				}
+			}
+		}
+	};
+}
+
+/// Creates a [`Date`](crate::Date) from a literal such as `date!(2024-05-01)`, which is validated at compile time.
+///
+/// The year or the month and day can be set to 0, following the rules of [`Date::new`](crate::Date::new).
+#[cfg(all(feature = "macros", feature = "date"))]
+#[macro_export]
+macro_rules! date {
+	($($date:tt)+) => {
+		const {
+			match $crate::macros::parse_date(::core::stringify!($($date)+)) {
+				::core::option::Option::Some(date) => date,
+				::core::option::Option::None => ::core::panic!(::core::concat!(
+					"Invalid date: ",
+					::core::stringify!($($date)+)
+				)),
+			}
+		}
+	};
+}
+
+/// Parses a run of ASCII digits starting at `pos`, returning the value, the number of digits and the new position.
+const fn parse_number(bytes: &[u8], mut pos: usize) -> Option<(i64, usize, usize)> {
+	let start = pos;
+	let mut value: i64 = 0;
+
+	while pos < bytes.len() && bytes[pos].is_ascii_digit() {
+		let digit = (bytes[pos] - b'0') as i64;
+
+		value = match value.checked_mul(10) {
+			Some(v) => match v.checked_add(digit) {
+				Some(v) => v,
+				None => return None,
+			},
+			None => return None,
+		};
+
+		pos += 1;
+	}
+
+	if pos == start {
+		None
+	} else {
+		Some((value, pos - start, pos))
+	}
+}
+
+/// Parses a number with exactly `digits` digits starting at `pos`.
+const fn parse_fixed(bytes: &[u8], pos: usize, digits: usize) -> Option<(i64, usize)> {
+	match parse_number(bytes, pos) {
+		Some((value, len, end)) if len == digits => Some((value, end)),
+		_ => None,
+	}
+}
+
+const fn expect(bytes: &[u8], pos: usize, c: u8) -> Option<usize> {
+	if pos < bytes.len() && bytes[pos] == c {
+		Some(pos + 1)
+	} else {
+		None
+	}
+}
+
+// Shorthand for bailing out of the const parsers, since `?` is not available in const contexts.
+macro_rules! try_opt {
+	($expr:expr) => {
+		match $expr {
+			Some(v) => v,
+			None => return None,
+		}
+	};
+}
+
+/// Parses an RFC 3339 timestamp in a const context.
+#[doc(hidden)]
+#[must_use]
+pub const fn parse_timestamp(value: &str) -> Option<Timestamp> {
+	let bytes = value.as_bytes();
+
+	let (year, pos) = try_opt!(parse_fixed(bytes, 0, 4));
+	let pos = try_opt!(expect(bytes, pos, b'-'));
+	let (month, pos) = try_opt!(parse_fixed(bytes, pos, 2));
+	let pos = try_opt!(expect(bytes, pos, b'-'));
+	let (day, pos) = try_opt!(parse_fixed(bytes, pos, 2));
+
+	// Timestamps start from year 1, as required by the protobuf spec
+	if year < 1 || month < 1 || month > 12 || day < 1 {
+		return None;
+	}
+
+	// SAFETY: The month is within 1..=12, and the day has at most 2 digits
+	#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+	let (month, day) = (month as u8, day as u8);
+
+	if day > days_in_month(year, month) {
+		return None;
+	}
+
+	let pos = match expect(bytes, pos, b'T') {
+		Some(pos) => pos,
+		None => try_opt!(expect(bytes, pos, b't')),
+	};
+
+	let (hours, pos) = try_opt!(parse_fixed(bytes, pos, 2));
+	let pos = try_opt!(expect(bytes, pos, b':'));
+	let (minutes, pos) = try_opt!(parse_fixed(bytes, pos, 2));
+	let pos = try_opt!(expect(bytes, pos, b':'));
+	let (seconds, mut pos) = try_opt!(parse_fixed(bytes, pos, 2));
+
+	if hours > 23 || minutes > 59 || seconds > 59 {
+		return None;
+	}
+
+	let mut nanos = 0;
+
+	if let Some(next) = expect(bytes, pos, b'.') {
+		let (fraction, digits, end) = try_opt!(parse_number(bytes, next));
+
+		if digits > 9 {
+			return None;
+		}
+
+		// SAFETY: digits is at most 9
+		#[allow(clippy::cast_possible_truncation)]
+		let exponent = 9 - digits as u32;
+		nanos = fraction * 10_i64.pow(exponent);
+		pos = end;
+	}
+
+	let offset = if pos + 1 == bytes.len() && (bytes[pos] == b'Z' || bytes[pos] == b'z') {
+		0
+	} else if pos + 6 == bytes.len() && (bytes[pos] == b'+' || bytes[pos] == b'-') {
+		let (offset_hours, next) = try_opt!(parse_fixed(bytes, pos + 1, 2));
+		let next = try_opt!(expect(bytes, next, b':'));
+		let (offset_minutes, _) = try_opt!(parse_fixed(bytes, next, 2));
+
+		if offset_hours > 23 || offset_minutes > 59 {
+			return None;
+		}
+
+		let offset = offset_hours * 3600 + offset_minutes * 60;
+
+		if bytes[pos] == b'-' { -offset } else { offset }
+	} else {
+		return None;
+	};
+
+	let seconds =
+		days_from_civil(year, month, day) * 86_400 + hours * 3600 + minutes * 60 + seconds - offset;
+
+	// SAFETY: nanos has at most 9 digits
+	#[allow(clippy::cast_possible_truncation)]
+	let nanos = nanos as i32;
+
+	Some(Timestamp { seconds, nanos })
+}
+
+/// Parses a duration such as `1h30m` in a const context.
+#[doc(hidden)]
+#[must_use]
+pub const fn parse_duration(value: &str) -> Option<Duration> {
+	let bytes = value.as_bytes();
+
+	let (is_negative, mut pos) = match expect(bytes, 0, b'-') {
+		Some(pos) => (true, pos),
+		None => (false, 0),
+	};
+
+	if pos == bytes.len() {
+		return None;
+	}
+
+	let mut total_nanos: i128 = 0;
+
+	while pos < bytes.len() {
+		let (amount, _, next) = try_opt!(parse_number(bytes, pos));
+
+		let remaining = bytes.len() - next;
+		let (unit_nanos, unit_len): (i128, usize) = if remaining >= 2 && bytes[next + 1] == b's' {
+			match bytes[next] {
+				b'm' => (1_000_000, 2),
+				b'u' => (1_000, 2),
+				b'n' => (1, 2),
+				_ => return None,
+			}
+		} else if remaining >= 1 {
+			match bytes[next] {
+				b'd' => (86_400 * NANOS_PER_SECOND as i128, 1),
+				b'h' => (3600 * NANOS_PER_SECOND as i128, 1),
+				b'm' => (60 * NANOS_PER_SECOND as i128, 1),
+				b's' => (NANOS_PER_SECOND as i128, 1),
+				_ => return None,
+			}
+		} else {
+			return None;
+		};
+
+		total_nanos = try_opt!(total_nanos.checked_add(amount as i128 * unit_nanos));
+		pos = next + unit_len;
+	}
+
+	if is_negative {
+		total_nanos = -total_nanos;
+	}
+
+	let seconds = total_nanos / NANOS_PER_SECOND as i128;
+
+	if seconds > i64::MAX as i128 || seconds < i64::MIN as i128 {
+		return None;
+	}
+
+	// SAFETY: seconds was checked above, and the remainder is always within ±999_999_999
+	#[allow(clippy::cast_possible_truncation)]
+	let (seconds, nanos) = (
+		seconds as i64,
+		(total_nanos % NANOS_PER_SECOND as i128) as i32,
+	);
+
+	Some(Duration { seconds, nanos })
+}
+
+/// Skips the whitespace that [`stringify!`] may insert between tokens.
+#[cfg(feature = "date")]
+const fn skip_whitespace(bytes: &[u8], mut pos: usize) -> usize {
+	while pos < bytes.len() && bytes[pos].is_ascii_whitespace() {
+		pos += 1;
+	}
+
+	pos
+}
+
+/// Parses and validates a `YYYY-MM-DD` date in a const context.
+#[cfg(feature = "date")]
+#[doc(hidden)]
+#[must_use]
+pub const fn parse_date(value: &str) -> Option<crate::Date> {
+	let bytes = value.as_bytes();
+
+	let (year, _, pos) = try_opt!(parse_number(bytes, skip_whitespace(bytes, 0)));
+	let pos = try_opt!(expect(bytes, skip_whitespace(bytes, pos), b'-'));
+	let (month, _, pos) = try_opt!(parse_number(bytes, skip_whitespace(bytes, pos)));
+	let pos = try_opt!(expect(bytes, skip_whitespace(bytes, pos), b'-'));
+	let (day, _, pos) = try_opt!(parse_number(bytes, skip_whitespace(bytes, pos)));
+
+	if skip_whitespace(bytes, pos) != bytes.len() {
+		return None;
+	}
+
+	// SAFETY: The month is within 1..=12 when it is used to look up the days in the month
+	#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+	let valid = match (year, month, day) {
+		(1..=9999, 0..=12, 0) => month != 0 || day == 0,
+		// A year of 0 means a recurring date, and it is a leap year, so February 29th is allowed
+		(0..=9999, 1..=12, 1..=31) => day <= days_in_month(year, month as u8) as i64,
+		_ => false,
+	};
+
+	if !valid {
+		return None;
+	}
+
+	// SAFETY: all the components were checked to be within range
+	#[allow(clippy::cast_possible_truncation)]
+	let (year, month, day) = (year as i32, month as i32, day as i32);
+
+	Some(crate::Date { year, month, day })
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn const_constructors() {
+		const DEADLINE: Timestamp = match Timestamp::const_from_rfc3339("2024-05-01T10:00:00Z") {
+			Some(timestamp) => timestamp,
+			None => panic!("Invalid deadline"),
+		};
+		const TIMEOUT: Duration = Duration::const_from_secs(30);
+
+		assert_eq!(DEADLINE, "2024-05-01T10:00:00Z".parse().unwrap());
+		assert_eq!(TIMEOUT, Duration::new(30, 0));
+		assert_eq!(Timestamp::const_from_rfc3339("2024-05-01"), None);
+	}
+
+	#[cfg(feature = "macros")]
+	#[test]
+	fn timestamp_literals() {
+		assert_eq!(timestamp!("1970-01-01T00:00:00Z"), Timestamp::default());
+		assert_eq!(
+			timestamp!("2024-05-01T10:00:00Z"),
+			"2024-05-01T10:00:00Z".parse().unwrap()
+		);
+		assert_eq!(
+			timestamp!("2024-02-29T23:59:59.123+02:30"),
+			"2024-02-29T23:59:59.123+02:30".parse().unwrap()
+		);
+		assert_eq!(
+			timestamp!("1969-12-31T23:59:59.5Z"),
+			Timestamp::new(-1, 500_000_000)
+		);
+
+		assert_eq!(parse_timestamp("2023-02-29T00:00:00Z"), None);
+		assert_eq!(parse_timestamp("0000-01-01T00:00:00Z"), None);
+		assert_eq!(
+			parse_timestamp("0001-01-01T00:00:00Z"),
+			Some(Timestamp {
+				seconds: -62_135_596_800,
+				nanos: 0
+			})
+		);
+		assert_eq!(parse_timestamp("2024-05-01T24:00:00Z"), None);
+		assert_eq!(parse_timestamp("2024-05-01T10:00:00"), None);
+		assert_eq!(parse_timestamp("2024-05-01T10:00:00.1234567890Z"), None);
+	}
+
+	#[cfg(feature = "macros")]
+	#[test]
+	fn duration_literals() {
+		assert_eq!(duration!("1h30m"), Duration::new(5400, 0));
+		assert_eq!(duration!("1s500ms"), Duration::new(1, 500_000_000));
+		assert_eq!(duration!("-250ms"), Duration::new(0, -250_000_000));
+		assert_eq!(duration!("2d"), Duration::new(172_800, 0));
+		assert_eq!(duration!("10us5ns"), Duration::new(0, 10_005));
+
+		assert_eq!(parse_duration(""), None);
+		assert_eq!(parse_duration("-"), None);
+		assert_eq!(parse_duration("10"), None);
+		assert_eq!(parse_duration("1w"), None);
+	}
+
+	#[cfg(all(feature = "macros", feature = "date"))]
+	#[test]
+	fn date_literals() {
+		use crate::Date;
+
+		assert_eq!(
+			date!(2024 - 05 - 01),
+			Date {
+				year: 2024,
+				month: 5,
+				day: 1
+			}
+		);
+		assert!(date!(0000 - 02 - 29).is_month_and_day());
+		assert!(date!(2024 - 00 - 00).is_year_only());
+
+		assert_eq!(parse_date("2023-02-29"), None);
+		assert_eq!(parse_date("0000-00-05"), None);
+		assert_eq!(parse_date("2024-00-05"), None);
+		assert_eq!(parse_date("2024-05"), None);
+	}
+}
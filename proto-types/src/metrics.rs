@@ -0,0 +1,77 @@
+use ::metrics::IntoF64;
+
+use crate::{Duration, Timestamp};
+
+#[allow(clippy::cast_precision_loss)]
+fn seconds_f64(seconds: i64, nanos: i32) -> f64 {
+	seconds as f64 + f64::from(nanos) / 1e9
+}
+
+impl Duration {
+	/// Returns the duration as a fractional number of seconds, which is the base unit for histograms of durations in Prometheus.
+	#[must_use]
+	#[inline]
+	pub fn as_secs_f64(&self) -> f64 {
+		let normalized = self.normalized();
+
+		seconds_f64(normalized.seconds, normalized.nanos)
+	}
+}
+
+impl Timestamp {
+	/// Returns the timestamp as a fractional number of seconds since the unix epoch, as used by timestamp gauges (e.g. `process_start_time_seconds`).
+	#[must_use]
+	#[inline]
+	pub fn as_secs_f64(&self) -> f64 {
+		let normalized = self.normalized();
+
+		seconds_f64(normalized.seconds, normalized.nanos)
+	}
+
+	/// Returns the timestamp in the format used by the Prometheus exposition format, which is the number of milliseconds since the unix epoch.
+	///
+	/// Saturates at the bounds of `i64`.
+	#[must_use]
+	pub fn to_metrics_timestamp(&self) -> i64 {
+		let normalized = self.normalized();
+
+		normalized
+			.seconds
+			.saturating_mul(1000)
+			.saturating_add(i64::from(normalized.nanos / 1_000_000))
+	}
+}
+
+impl IntoF64 for Duration {
+	#[inline]
+	fn into_f64(self) -> f64 {
+		self.as_secs_f64()
+	}
+}
+
+impl IntoF64 for Timestamp {
+	#[inline]
+	fn into_f64(self) -> f64 {
+		self.as_secs_f64()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn durations_as_seconds() {
+		assert!((Duration::new(1, 500_000_000).into_f64() - 1.5).abs() < f64::EPSILON);
+		assert!((Duration::new(-2, -250_000_000).into_f64() + 2.25).abs() < f64::EPSILON);
+	}
+
+	#[test]
+	fn timestamps() {
+		let timestamp = Timestamp::new(1_700_000_000, 123_456_789);
+
+		assert_eq!(timestamp.to_metrics_timestamp(), 1_700_000_000_123);
+		assert_eq!(Timestamp::new(-1, 500_000_000).to_metrics_timestamp(), -500);
+		assert!((timestamp.into_f64() - 1_700_000_000.123_456).abs() < 1e-6);
+	}
+}
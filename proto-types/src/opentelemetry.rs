@@ -0,0 +1,98 @@
+use std::time::SystemTime;
+
+use ::opentelemetry::trace::SpanBuilder;
+
+use crate::{Interval, Timestamp, ToString, common::interval::IntervalError};
+
+fn to_system_time(timestamp: Option<Timestamp>) -> Result<Option<SystemTime>, IntervalError> {
+	timestamp
+		.map(SystemTime::try_from)
+		.transpose()
+		.map_err(|e| IntervalError::ConversionError(e.to_string()))
+}
+
+impl Interval {
+	/// Converts the interval into the start and end times used by OpenTelemetry spans.
+	///
+	/// Missing bounds are returned as `None`, like the corresponding fields of [`SpanBuilder`].
+	pub fn to_span_bounds(
+		&self,
+	) -> Result<(Option<SystemTime>, Option<SystemTime>), IntervalError> {
+		Ok((
+			to_system_time(self.start_time)?,
+			to_system_time(self.end_time)?,
+		))
+	}
+
+	/// Creates an interval from the start and end times of an OpenTelemetry span, checking that `end` is not before `start`.
+	pub fn from_span_bounds(
+		start: Option<SystemTime>,
+		end: Option<SystemTime>,
+	) -> Result<Self, IntervalError> {
+		Self::new(start.map(Timestamp::from), end.map(Timestamp::from))
+	}
+
+	/// Sets the start and end times of the given [`SpanBuilder`] to the bounds of this interval.
+	///
+	/// Bounds that are missing from the interval are left untouched in the builder.
+	pub fn apply_to_span_builder(
+		&self,
+		mut builder: SpanBuilder,
+	) -> Result<SpanBuilder, IntervalError> {
+		let (start, end) = self.to_span_bounds()?;
+
+		if start.is_some() {
+			builder.start_time = start;
+		}
+
+		if end.is_some() {
+			builder.end_time = end;
+		}
+
+		Ok(builder)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use core::time::Duration;
+	use std::time::UNIX_EPOCH;
+
+	use super::*;
+
+	#[test]
+	fn span_bounds_round_trip() {
+		let interval = Interval::new(
+			Some(Timestamp::new(1_700_000_000, 0)),
+			Some(Timestamp::new(1_700_000_001, 500_000_000)),
+		)
+		.unwrap();
+
+		let (start, end) = interval.to_span_bounds().unwrap();
+
+		assert_eq!(start, Some(UNIX_EPOCH + Duration::from_secs(1_700_000_000)));
+		assert_eq!(
+			end.unwrap()
+				.duration_since(start.unwrap())
+				.unwrap(),
+			Duration::from_millis(1500)
+		);
+		assert_eq!(Interval::from_span_bounds(start, end), Ok(interval));
+		assert!(Interval::from_span_bounds(end, start).is_err());
+	}
+
+	#[test]
+	fn span_builder() {
+		let interval = Interval::new(Some(Timestamp::new(10, 0)), None).unwrap();
+
+		let builder = interval
+			.apply_to_span_builder(SpanBuilder::from_name("event").with_end_time(UNIX_EPOCH))
+			.unwrap();
+
+		assert_eq!(
+			builder.start_time,
+			Some(UNIX_EPOCH + Duration::from_secs(10))
+		);
+		assert_eq!(builder.end_time, Some(UNIX_EPOCH));
+	}
+}
@@ -0,0 +1,55 @@
+//! A glob import for the most commonly used types and traits of this crate.
+//!
+//! Items that depend on a feature flag are only re-exported when that feature is enabled.
+//!
+//! # Examples
+//! ```rust
+//! use proto_types::prelude::*;
+//!
+//! let start = Timestamp::new(1_000, 0);
+//! let end = start + Duration::new(30, 0);
+//!
+//! assert!(end > start);
+//! assert!(end.validate().is_ok());
+//! ```
+
+pub use prost::{Message, Name};
+
+pub use crate::{
+	Any, AnyListExt, AnyRegistry, CanonicalHash, CanonicalOrd, DecodeValidatedExt, Duration, Empty,
+	FieldMask, MaskStrictness, ProtoTypesError, ProtoValidate, Tabulate, Timestamp, TypeUrl,
+	Validated,
+};
+
+#[cfg(feature = "hash")]
+pub use crate::StableHash;
+
+#[cfg(feature = "prost-reflect")]
+pub use crate::reflect::DynamicMessageExt;
+
+#[cfg(feature = "protovalidate")]
+pub use crate::protovalidate::{RuleValue, Violation, ViolationWithValue, Violations};
+
+#[cfg(feature = "rpc")]
+pub use crate::{Code, Status, StatusBuilder};
+
+#[cfg(feature = "color")]
+pub use crate::Color;
+#[cfg(feature = "date")]
+pub use crate::Date;
+#[cfg(feature = "datetime")]
+pub use crate::DateTime;
+#[cfg(feature = "decimal")]
+pub use crate::Decimal;
+#[cfg(feature = "fraction")]
+pub use crate::Fraction;
+#[cfg(feature = "interval")]
+pub use crate::Interval;
+#[cfg(feature = "latlng")]
+pub use crate::LatLng;
+#[cfg(feature = "timeofday")]
+pub use crate::TimeOfDay;
+#[cfg(feature = "timeofday")]
+pub use crate::time_range::TimeRange;
+#[cfg(feature = "money")]
+pub use crate::{MinorUnits, Money};
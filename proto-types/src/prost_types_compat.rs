@@ -0,0 +1,77 @@
+//! Conversions between the well known types of this crate and the ones from `prost-types`.
+//!
+//! Both crates use the same field layout and the same protobuf encoding, so the conversions only move the fields across.
+
+use crate::{Any, Duration, Empty, FieldMask, Timestamp};
+
+macro_rules! impl_prost_types_conversions {
+	($name:ident { $($field:ident),* }) => {
+		impl From<prost_types::$name> for $name {
+			#[inline]
+			fn from(value: prost_types::$name) -> Self {
+				Self {
+					$($field: value.$field),*
+				}
+			}
+		}
+
+		impl From<$name> for prost_types::$name {
+			#[inline]
+			fn from(value: $name) -> Self {
+				Self {
+					$($field: value.$field),*
+				}
+			}
+		}
+	};
+}
+
+impl_prost_types_conversions!(Timestamp { seconds, nanos });
+impl_prost_types_conversions!(Duration { seconds, nanos });
+impl_prost_types_conversions!(Any { type_url, value });
+impl_prost_types_conversions!(FieldMask { paths });
+
+/// `prost-types` represents `google.protobuf.Empty` as the unit type.
+impl From<Empty> for () {
+	#[inline]
+	fn from(_: Empty) -> Self {}
+}
+
+#[cfg(test)]
+mod tests {
+	use alloc::vec;
+
+	use prost::Message;
+
+	use super::*;
+
+	/// Checks that both types produce the same bytes, and that each one decodes the encoding of the other.
+	fn assert_wire_compatible<A, B>(ours: &A)
+	where
+		A: Message + Default + PartialEq + Clone + core::fmt::Debug + From<B>,
+		B: Message + Default + PartialEq + core::fmt::Debug + From<A>,
+	{
+		let theirs = B::from(ours.clone());
+		let (our_bytes, their_bytes) = (ours.encode_to_vec(), theirs.encode_to_vec());
+
+		assert_eq!(our_bytes, their_bytes);
+		assert_eq!(B::decode(our_bytes.as_slice()).unwrap(), theirs);
+		assert_eq!(&A::decode(their_bytes.as_slice()).unwrap(), ours);
+		assert_eq!(&A::from(theirs), ours);
+	}
+
+	#[test]
+	fn wire_compatibility() {
+		assert_wire_compatible::<_, prost_types::Timestamp>(&Timestamp::new(1_714_557_600, 5));
+		assert_wire_compatible::<_, prost_types::Duration>(&Duration::new(-3, -500));
+		assert_wire_compatible::<_, prost_types::FieldMask>(&FieldMask {
+			paths: vec!["name".into(), "address.city".into()],
+		});
+		assert_wire_compatible::<_, prost_types::Any>(
+			&Any::from_msg(&Duration::new(5, 0)).unwrap(),
+		);
+
+		assert_eq!(Empty::from(()), Empty);
+		assert_eq!(<()>::from(Empty), ());
+	}
+}
@@ -1809,6 +1809,7 @@ pub struct Mixin {
 /// microsecond should be expressed in JSON format as "3.000001s".
 
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+#[prost(skip_debug)]
 #[cfg_attr(
   feature = "diesel-postgres",
   derive(diesel::QueryId, diesel::AsExpression, diesel::FromSqlRow),
@@ -2260,8 +2261,13 @@ impl NullValue {
 /// the time format spec '%Y-%m-%dT%H:%M:%S.%fZ'. Likewise, in Java, one can use
 /// the Joda Time's [`ISODateTimeFormat.dateTime()`](<http://www.joda.org/joda-time/apidocs/org/joda/time/format/ISODateTimeFormat.html#dateTime%2D%2D>) to obtain a formatter capable of generating timestamps in this format.
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+#[prost(skip_debug)]
 #[cfg_attr(
-  any(feature = "diesel-postgres", feature = "diesel-sqlite"),
+  any(
+    feature = "diesel-postgres",
+    feature = "diesel-sqlite",
+    feature = "diesel-mysql"
+  ),
   derive(diesel::QueryId, diesel::AsExpression, diesel::FromSqlRow),
   diesel(sql_type = diesel::sql_types::Timestamp)
 )]
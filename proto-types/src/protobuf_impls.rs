@@ -2,14 +2,14 @@
 mod serde {
 	use crate::*;
 
-	use alloc::borrow::ToOwned;
-	use base64::{Engine, prelude::BASE64_STANDARD};
-	use prost::bytes::Bytes;
-	use serde::{
+	use ::serde::{
 		Deserialize, Deserializer, Serialize,
 		de::{self, MapAccess, SeqAccess, Visitor},
 		ser::Serializer,
 	};
+	use alloc::borrow::ToOwned;
+	use base64::{Engine, prelude::BASE64_STANDARD};
+	use prost::bytes::Bytes;
 
 	use crate::{BytesValue, ListValue, NullValue, Struct, Value, value::Kind};
 
@@ -44,7 +44,7 @@ mod serde {
 				Some(Kind::BoolValue(v)) => serializer.serialize_bool(v),
 				Some(Kind::StructValue(ref v)) => v.serialize(serializer),
 				Some(Kind::ListValue(ref v)) => v.serialize(serializer),
-				None => Err(serde::ser::Error::custom("Value must have a variant set")),
+				None => Err(::serde::ser::Error::custom("Value must have a variant set")),
 			}
 		}
 	}
@@ -162,3 +162,31 @@ mod serde {
 		}
 	}
 }
+
+macro_rules! impl_wrapper_name {
+	($($name:ident),*) => {
+		$(
+			impl prost::Name for crate::$name {
+				const PACKAGE: &'static str = crate::constants::PACKAGE_PREFIX;
+
+				const NAME: &'static str = stringify!($name);
+
+				fn type_url() -> crate::String {
+					crate::type_url_for::<Self>()
+				}
+			}
+		)*
+	};
+}
+
+impl_wrapper_name!(
+	DoubleValue,
+	FloatValue,
+	Int64Value,
+	UInt64Value,
+	Int32Value,
+	UInt32Value,
+	BoolValue,
+	StringValue,
+	BytesValue
+);
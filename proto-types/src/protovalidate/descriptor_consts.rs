@@ -0,0 +1,116 @@
+//! Field numbers of the `buf.validate` extensions and of the fields of its rule messages, for code that walks descriptors and options.
+//!
+//! The field numbers of the rules for specific types (such as `string.min_len`) are available in the [`ViolationData`](super::ViolationData) tables.
+
+/// The number of the `buf.validate.message` extension of `google.protobuf.MessageOptions`.
+pub const MESSAGE_EXTENSION: i32 = 1159;
+/// The number of the `buf.validate.oneof` extension of `google.protobuf.OneofOptions`.
+pub const ONEOF_EXTENSION: i32 = 1159;
+/// The number of the `buf.validate.field` extension of `google.protobuf.FieldOptions`.
+pub const FIELD_EXTENSION: i32 = 1159;
+/// The number of the `buf.validate.predefined` extension of `google.protobuf.FieldOptions`.
+pub const PREDEFINED_EXTENSION: i32 = 1160;
+
+/// Field numbers of `buf.validate.Rule`.
+pub mod rule {
+	pub const ID: i32 = 1;
+	pub const MESSAGE: i32 = 2;
+	pub const EXPRESSION: i32 = 3;
+}
+
+/// Field numbers of `buf.validate.MessageRules`.
+pub mod message_rules {
+	pub const CEL: i32 = 3;
+	pub const ONEOF: i32 = 4;
+	pub const CEL_EXPRESSION: i32 = 5;
+}
+
+/// Field numbers of `buf.validate.MessageOneofRule`.
+pub mod message_oneof_rule {
+	pub const FIELDS: i32 = 1;
+	pub const REQUIRED: i32 = 2;
+}
+
+/// Field numbers of `buf.validate.OneofRules`.
+pub mod oneof_rules {
+	pub const REQUIRED: i32 = 1;
+}
+
+/// Field numbers of `buf.validate.PredefinedRules`.
+pub mod predefined_rules {
+	pub const CEL: i32 = 1;
+}
+
+/// Field numbers of `buf.validate.FieldRules`, including the members of its `type` oneof.
+pub mod field_rules {
+	pub const CEL: i32 = 23;
+	pub const REQUIRED: i32 = 25;
+	pub const IGNORE: i32 = 27;
+	pub const CEL_EXPRESSION: i32 = 29;
+
+	pub const FLOAT: i32 = 1;
+	pub const DOUBLE: i32 = 2;
+	pub const INT32: i32 = 3;
+	pub const INT64: i32 = 4;
+	pub const UINT32: i32 = 5;
+	pub const UINT64: i32 = 6;
+	pub const SINT32: i32 = 7;
+	pub const SINT64: i32 = 8;
+	pub const FIXED32: i32 = 9;
+	pub const FIXED64: i32 = 10;
+	pub const SFIXED32: i32 = 11;
+	pub const SFIXED64: i32 = 12;
+	pub const BOOL: i32 = 13;
+	pub const STRING: i32 = 14;
+	pub const BYTES: i32 = 15;
+	pub const ENUM: i32 = 16;
+	pub const REPEATED: i32 = 18;
+	pub const MAP: i32 = 19;
+	pub const ANY: i32 = 20;
+	pub const DURATION: i32 = 21;
+	pub const TIMESTAMP: i32 = 22;
+	pub const FIELD_MASK: i32 = 28;
+
+	/// The numbers of all the members of the `type` oneof.
+	pub const TYPE_ONEOF: [i32; 22] = [
+		FLOAT, DOUBLE, INT32, INT64, UINT32, UINT64, SINT32, SINT64, FIXED32, FIXED64, SFIXED32,
+		SFIXED64, BOOL, STRING, BYTES, ENUM, REPEATED, MAP, ANY, DURATION, TIMESTAMP, FIELD_MASK,
+	];
+}
+
+#[cfg(test)]
+mod tests {
+	use prost::Message;
+
+	use super::*;
+	use crate::protovalidate::{
+		CEL_VIOLATION, FieldRules, ONEOF_REQUIRED_VIOLATION, REQUIRED_VIOLATION,
+		STRING_MIN_LEN_VIOLATION, StringRules, field_rules::Type,
+	};
+
+	#[test]
+	fn matches_violation_data() {
+		assert_eq!(CEL_VIOLATION.elements[0].number, field_rules::CEL);
+		assert_eq!(REQUIRED_VIOLATION.elements[0].number, field_rules::REQUIRED);
+		assert_eq!(
+			ONEOF_REQUIRED_VIOLATION.elements[0].number,
+			oneof_rules::REQUIRED
+		);
+		assert_eq!(
+			STRING_MIN_LEN_VIOLATION.elements[0].number,
+			field_rules::STRING
+		);
+	}
+
+	#[test]
+	fn matches_wire_encoding() {
+		let rules = FieldRules {
+			r#type: Some(Type::String(StringRules::default())),
+			..Default::default()
+		};
+
+		// The key of a length-delimited field is `(number << 3) | 2`
+		let key = u8::try_from((field_rules::STRING << 3) | 2).unwrap();
+		assert_eq!(rules.encode_to_vec(), [key, 0]);
+	}
+}
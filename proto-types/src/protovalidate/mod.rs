@@ -5,7 +5,9 @@ use crate::protovalidate::field_path_element::Subscript;
 
 mod violations;
 
-use crate::{Display, String, ToString, fmt};
+pub mod descriptor_consts;
+
+use crate::{String, ToString};
 
 pub use violations::*;
 
@@ -56,14 +58,3 @@ impl From<&str> for Subscript {
 		Self::StringKey(value.to_string())
 	}
 }
-
-impl Display for Subscript {
-	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		match self {
-			Self::BoolKey(val) => write!(f, "{val}"),
-			Self::IntKey(val) => write!(f, "{val}"),
-			Self::Index(val) | Self::UintKey(val) => write!(f, "{val}"),
-			Self::StringKey(val) => write!(f, "{val}"),
-		}
-	}
-}
@@ -0,0 +1,194 @@
+use core::fmt::Write;
+
+use crate::{
+	String,
+	protovalidate::{FieldPath, field_path_element::Subscript},
+};
+
+/// The casing used for field names when rendering a [`FieldPath`] with [`FieldPath::to_json_pointer`] or [`FieldPath::to_jsonpath`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum FieldNameCase {
+	/// The field names as they are defined in the proto file (e.g. `street_name`).
+	#[default]
+	Proto,
+	/// The lowerCamelCase names used by the protobuf JSON mapping (e.g. `streetName`).
+	CamelCase,
+}
+
+fn push_field_name(output: &mut String, name: &str, case: FieldNameCase) {
+	match case {
+		FieldNameCase::Proto => output.push_str(name),
+		FieldNameCase::CamelCase => {
+			// Same algorithm used by protoc to generate the json_name of a field
+			let mut capitalize_next = false;
+
+			for c in name.chars() {
+				if c == '_' {
+					capitalize_next = true;
+				} else if capitalize_next {
+					output.push(c.to_ascii_uppercase());
+					capitalize_next = false;
+				} else {
+					output.push(c);
+				}
+			}
+		}
+	}
+}
+
+fn push_json_pointer_token(output: &mut String, token: &str) {
+	output.push('/');
+
+	for c in token.chars() {
+		match c {
+			'~' => output.push_str("~0"),
+			'/' => output.push_str("~1"),
+			c => output.push(c),
+		}
+	}
+}
+
+fn push_jsonpath_key(output: &mut String, key: &str) {
+	output.push_str("['");
+
+	for c in key.chars() {
+		if c == '\'' || c == '\\' {
+			output.push('\\');
+		}
+		output.push(c);
+	}
+
+	output.push_str("']");
+}
+
+fn is_identifier(name: &str) -> bool {
+	let mut chars = name.chars();
+
+	chars
+		.next()
+		.is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+		&& chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+impl FieldPath {
+	/// Renders the path as a JSON Pointer (RFC 6901), such as `/person/friends/0/name`.
+	///
+	/// Map keys are never affected by the chosen casing.
+	#[must_use]
+	pub fn to_json_pointer(&self, case: FieldNameCase) -> String {
+		let mut output = String::new();
+		let mut name = String::new();
+
+		for element in &self.elements {
+			if let Some(field_name) = &element.field_name {
+				name.clear();
+				push_field_name(&mut name, field_name, case);
+				push_json_pointer_token(&mut output, &name);
+			}
+
+			if let Some(subscript) = &element.subscript {
+				push_json_pointer_token(&mut output, &subscript.bare_key());
+			}
+		}
+
+		output
+	}
+
+	/// Renders the path as a JSONPath expression (RFC 9535), such as `$.person.friends[0].name`.
+	///
+	/// Map keys, and names that are not valid identifiers, use the bracket notation (`$.labels['app']`).
+	/// Map keys are never affected by the chosen casing.
+	#[must_use]
+	pub fn to_jsonpath(&self, case: FieldNameCase) -> String {
+		let mut output = String::from("$");
+		let mut name = String::new();
+
+		for element in &self.elements {
+			if let Some(field_name) = &element.field_name {
+				name.clear();
+				push_field_name(&mut name, field_name, case);
+
+				if is_identifier(&name) {
+					output.push('.');
+					output.push_str(&name);
+				} else {
+					push_jsonpath_key(&mut output, &name);
+				}
+			}
+
+			match &element.subscript {
+				Some(Subscript::Index(index)) => {
+					let _ = write!(output, "[{index}]");
+				}
+				Some(subscript) => push_jsonpath_key(&mut output, &subscript.bare_key()),
+				None => {}
+			}
+		}
+
+		output
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::protovalidate::FieldPathElement;
+
+	fn path(elements: impl IntoIterator<Item = FieldPathElement>) -> FieldPath {
+		elements.into_iter().collect()
+	}
+
+	fn element(name: &str, subscript: Option<Subscript>) -> FieldPathElement {
+		FieldPathElement {
+			field_name: Some(name.into()),
+			subscript,
+			..Default::default()
+		}
+	}
+
+	#[test]
+	fn json_notations() {
+		let friends = path([
+			element("person", None),
+			element("best_friends", Some(Subscript::Index(0))),
+			element("first_name", None),
+		]);
+
+		assert_eq!(
+			friends.to_json_pointer(FieldNameCase::Proto),
+			"/person/best_friends/0/first_name"
+		);
+		assert_eq!(
+			friends.to_json_pointer(FieldNameCase::CamelCase),
+			"/person/bestFriends/0/firstName"
+		);
+		assert_eq!(
+			friends.to_jsonpath(FieldNameCase::Proto),
+			"$.person.best_friends[0].first_name"
+		);
+		assert_eq!(
+			friends.to_jsonpath(FieldNameCase::CamelCase),
+			"$.person.bestFriends[0].firstName"
+		);
+
+		let labels = path([
+			element("meta_labels", Some(Subscript::StringKey("a/b~c'd".into()))),
+			element("counts", Some(Subscript::IntKey(-1))),
+		]);
+
+		assert_eq!(
+			labels.to_json_pointer(FieldNameCase::CamelCase),
+			"/metaLabels/a~1b~0c'd/counts/-1"
+		);
+		assert_eq!(
+			labels.to_jsonpath(FieldNameCase::CamelCase),
+			"$.metaLabels['a/b~c\\'d'].counts['-1']"
+		);
+
+		assert_eq!(
+			FieldPath::default().to_json_pointer(FieldNameCase::Proto),
+			""
+		);
+		assert_eq!(FieldPath::default().to_jsonpath(FieldNameCase::Proto), "$");
+	}
+}
@@ -1,6 +1,25 @@
 use crate::protobuf::field_descriptor_proto::Type;
 
+mod field_path_notation;
+pub use field_path_notation::FieldNameCase;
+mod openapi_errors;
+pub use openapi_errors::ErrorObject;
+mod subscript_notation;
+pub use subscript_notation::FieldPathParseError;
+#[cfg(feature = "serde")]
+pub use subscript_notation::compact_serde;
 mod violation_impls;
+mod violation_value;
+pub use violation_value::{RuleValue, ViolationWithValue};
+mod violations_bounded;
+mod violations_diff;
+pub use violations_diff::ViolationsDiff;
+mod violations_merge;
+mod violations_order;
+mod violations_ref;
+pub use violations_ref::{
+	FieldPathElementRef, FieldPathRef, SubscriptRef, ViolationRef, ViolationsRef,
+};
 
 pub mod violations_data;
 
@@ -0,0 +1,90 @@
+use crate::{
+	String, ToString, Vec,
+	protovalidate::{Violation, Violations},
+};
+
+/// A single entry of the `errors` array used by many REST style guides, in the shape `{ "field": ..., "code": ..., "message": ... }`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ErrorObject {
+	/// The path of the invalid field, joined by a dot (e.g. `person.friends.0.name`), or an empty string for message-level violations.
+	pub field: String,
+	/// The error code, derived from the rule id.
+	pub code: String,
+	/// The human-readable error message.
+	pub message: String,
+}
+
+impl Violation {
+	/// Converts the violation into an [`ErrorObject`], using the given function to map the rule id into the error code.
+	#[must_use]
+	pub fn to_openapi_error_object_with(
+		&self,
+		map_code: impl FnOnce(&str) -> String,
+	) -> ErrorObject {
+		ErrorObject {
+			field: self.field_path_str().unwrap_or_default(),
+			code: map_code(self.rule_id()),
+			message: self.message().to_string(),
+		}
+	}
+}
+
+impl Violations {
+	/// Converts the violations into a list of [`ErrorObject`]s, using the rule ids as the error codes.
+	#[must_use]
+	pub fn to_openapi_error_objects(&self) -> Vec<ErrorObject> {
+		self.to_openapi_error_objects_with(ToString::to_string)
+	}
+
+	/// Converts the violations into a list of [`ErrorObject`]s, using the given function to map each rule id into the error code (e.g. `string.min_len` into `TOO_SHORT`).
+	#[must_use]
+	pub fn to_openapi_error_objects_with(
+		&self,
+		mut map_code: impl FnMut(&str) -> String,
+	) -> Vec<ErrorObject> {
+		self.violations
+			.iter()
+			.map(|v| v.to_openapi_error_object_with(&mut map_code))
+			.collect()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use alloc::vec;
+
+	use super::*;
+	use crate::testkit::violation;
+
+	#[test]
+	fn error_objects() {
+		let violations = Violations {
+			violations: vec![
+				violation("name", "string.min_len", "too short"),
+				violation("", "message.cel", "invalid"),
+			],
+		};
+
+		assert_eq!(
+			violations.to_openapi_error_objects(),
+			vec![
+				ErrorObject {
+					field: "name".into(),
+					code: "string.min_len".into(),
+					message: "too short".into(),
+				},
+				ErrorObject {
+					field: String::new(),
+					code: "message.cel".into(),
+					message: "invalid".into(),
+				},
+			]
+		);
+
+		let mapped = violations
+			.to_openapi_error_objects_with(|rule_id| rule_id.replace('.', "_").to_uppercase());
+
+		assert_eq!(mapped[0].code, "STRING_MIN_LEN");
+	}
+}
@@ -0,0 +1,390 @@
+use thiserror::Error;
+
+use crate::{
+	Display, FromStr, String, ToString, fmt, format,
+	protovalidate::{FieldPathElement, field_path_element::Subscript},
+};
+
+/// Errors that can occur while parsing the compact string form of a [`Subscript`] or a [`FieldPathElement`].
+#[derive(Debug, Error, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum FieldPathParseError {
+	#[error("Invalid subscript: {0}")]
+	InvalidSubscript(String),
+	#[error("Invalid field path token: {0}")]
+	InvalidToken(String),
+}
+
+impl Subscript {
+	/// Returns the value of the subscript without brackets, quotes or type suffixes (e.g. `0` or `key`).
+	pub(crate) fn bare_key(&self) -> String {
+		match self {
+			Self::BoolKey(val) => val.to_string(),
+			Self::IntKey(val) => val.to_string(),
+			Self::Index(val) | Self::UintKey(val) => val.to_string(),
+			Self::StringKey(val) => val.clone(),
+		}
+	}
+}
+
+/// Formats the subscript as a bracketed token, which can be parsed back with [`FromStr`].
+///
+/// Indexes are printed as they are (`[3]`), bool keys as `[true]`, and string keys are quoted, with `"` and `\` escaped by a backslash (`["key"]`).
+/// Integer map keys carry a suffix (`[-3i]` for signed keys, `[3u]` for unsigned keys), so that they can be told apart from indexes.
+impl Display for Subscript {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Index(val) => write!(f, "[{val}]"),
+			Self::BoolKey(val) => write!(f, "[{val}]"),
+			Self::IntKey(val) => write!(f, "[{val}i]"),
+			Self::UintKey(val) => write!(f, "[{val}u]"),
+			Self::StringKey(val) => {
+				f.write_str("[\"")?;
+
+				for c in val.chars() {
+					if matches!(c, '"' | '\\') {
+						f.write_str("\\")?;
+					}
+					write!(f, "{c}")?;
+				}
+
+				f.write_str("\"]")
+			}
+		}
+	}
+}
+
+fn unescape_string_key(quoted: &str) -> Option<String> {
+	let inner = quoted.strip_prefix('"')?.strip_suffix('"')?;
+	let mut output = String::with_capacity(inner.len());
+	let mut chars = inner.chars();
+
+	while let Some(c) = chars.next() {
+		match c {
+			'\\' => match chars.next()? {
+				escaped @ ('"' | '\\') => output.push(escaped),
+				_ => return None,
+			},
+			'"' => return None,
+			c => output.push(c),
+		}
+	}
+
+	Some(output)
+}
+
+impl FromStr for Subscript {
+	type Err = FieldPathParseError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let invalid = || FieldPathParseError::InvalidSubscript(s.to_string());
+
+		let inner = s
+			.strip_prefix('[')
+			.and_then(|s| s.strip_suffix(']'))
+			.ok_or_else(invalid)?;
+
+		let subscript = if inner.starts_with('"') {
+			unescape_string_key(inner).map(Self::StringKey)
+		} else if let Ok(val) = inner.parse::<bool>() {
+			Some(Self::BoolKey(val))
+		} else if let Some(val) = inner.strip_suffix('i') {
+			val.parse().ok().map(Self::IntKey)
+		} else if let Some(val) = inner.strip_suffix('u') {
+			val.parse().ok().map(Self::UintKey)
+		} else {
+			inner.parse().ok().map(Self::Index)
+		};
+
+		subscript.ok_or_else(invalid)
+	}
+}
+
+/// Formats the element as a compact path token, made of the field name followed by the eventual subscript (e.g. `friends[3]` or `labels["env"]`).
+///
+/// The field number and the field, key and value types are not part of the token.
+impl Display for FieldPathElement {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if let Some(name) = &self.field_name {
+			f.write_str(name)?;
+		}
+
+		if let Some(subscript) = &self.subscript {
+			write!(f, "{subscript}")?;
+		}
+
+		Ok(())
+	}
+}
+
+/// Parses a compact path token, as produced by the [`Display`] impl.
+///
+/// Only the field name and the subscript are restored, since the other fields are not part of the token.
+impl FromStr for FieldPathElement {
+	type Err = FieldPathParseError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let (name, subscript) = match s.find('[') {
+			Some(start) => (&s[..start], Some(s[start..].parse::<Subscript>()?)),
+			None => (s, None),
+		};
+
+		if name.is_empty() && subscript.is_none() {
+			return Err(FieldPathParseError::InvalidToken(format!("{s:?}")));
+		}
+
+		Ok(Self {
+			field_name: (!name.is_empty()).then(|| name.to_string()),
+			subscript,
+			..Default::default()
+		})
+	}
+}
+
+#[cfg(feature = "serde")]
+mod serde_impls {
+	use core::marker::PhantomData;
+
+	use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+
+	use super::*;
+
+	pub(super) struct TokenVisitor<T>(pub(super) PhantomData<T>);
+
+	impl<T> de::Visitor<'_> for TokenVisitor<T>
+	where
+		T: FromStr<Err = FieldPathParseError>,
+	{
+		type Value = T;
+
+		fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+			formatter.write_str("a compact field path token")
+		}
+
+		fn visit_str<E>(self, value: &str) -> Result<T, E>
+		where
+			E: de::Error,
+		{
+			value.parse().map_err(de::Error::custom)
+		}
+	}
+
+	impl Serialize for Subscript {
+		fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+			serializer.collect_str(self)
+		}
+	}
+
+	impl<'de> Deserialize<'de> for Subscript {
+		fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+			deserializer.deserialize_str(TokenVisitor(PhantomData))
+		}
+	}
+}
+
+/// Serde adapters that (de)serialize field paths as compact path tokens rather than nested objects.
+///
+/// They are meant to be used with `#[serde(with = "...")]` on payloads that are sent to clients such as browsers,
+/// where a path like `["friends[3]", "labels[\"env\"]"]` is several times smaller than the full [`FieldPath`](crate::protovalidate::FieldPath) message.
+///
+/// The conversion is lossy: only the field names and the subscripts are preserved, while the field numbers and the field, key and value types are dropped.
+///
+/// # Examples
+/// ```rust
+/// use proto_types::protovalidate::{FieldPath, FieldPathElement, compact_serde};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct ViolationPayload {
+///   #[serde(with = "compact_serde::field_path")]
+///   field: FieldPath,
+///   message: String,
+/// }
+///
+/// let mut element = FieldPathElement {
+///   field_name: Some("friends".into()),
+///   ..Default::default()
+/// };
+/// element.set_subscript(3usize);
+///
+/// let payload = ViolationPayload {
+///   field: FieldPath { elements: vec![element] },
+///   message: "must be unique".into(),
+/// };
+///
+/// assert_eq!(
+///   serde_json::to_string(&payload).unwrap(),
+///   r#"{"field":["friends[3]"],"message":"must be unique"}"#
+/// );
+/// ```
+#[cfg(feature = "serde")]
+pub mod compact_serde {
+	use core::marker::PhantomData;
+
+	use serde::{Deserializer, Serializer};
+
+	use super::serde_impls::TokenVisitor;
+	use crate::protovalidate::{FieldPath, FieldPathElement};
+
+	/// (De)serializes a [`FieldPathElement`] as a single compact path token (e.g. `"friends[3]"`).
+	pub mod field_path_element {
+		use super::*;
+
+		pub fn serialize<S: Serializer>(
+			element: &FieldPathElement,
+			serializer: S,
+		) -> Result<S::Ok, S::Error> {
+			serializer.collect_str(element)
+		}
+
+		pub fn deserialize<'de, D: Deserializer<'de>>(
+			deserializer: D,
+		) -> Result<FieldPathElement, D::Error> {
+			deserializer.deserialize_str(TokenVisitor(PhantomData))
+		}
+	}
+
+	/// (De)serializes a [`FieldPath`] as a sequence of compact path tokens (e.g. `["friends[3]", "name"]`).
+	pub mod field_path {
+		use serde::{
+			de::{SeqAccess, Visitor},
+			ser::SerializeSeq,
+		};
+
+		use super::*;
+		use crate::{Vec, fmt};
+
+		struct ElementToken<'a>(&'a FieldPathElement);
+
+		impl serde::Serialize for ElementToken<'_> {
+			fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+				serializer.collect_str(self.0)
+			}
+		}
+
+		struct ElementFromToken(FieldPathElement);
+
+		impl<'de> serde::Deserialize<'de> for ElementFromToken {
+			fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+				deserializer
+					.deserialize_str(TokenVisitor(PhantomData))
+					.map(Self)
+			}
+		}
+
+		pub fn serialize<S: Serializer>(
+			path: &FieldPath,
+			serializer: S,
+		) -> Result<S::Ok, S::Error> {
+			let mut seq = serializer.serialize_seq(Some(path.elements.len()))?;
+
+			for element in &path.elements {
+				seq.serialize_element(&ElementToken(element))?;
+			}
+
+			seq.end()
+		}
+
+		pub fn deserialize<'de, D: Deserializer<'de>>(
+			deserializer: D,
+		) -> Result<FieldPath, D::Error> {
+			struct PathVisitor;
+
+			impl<'de> Visitor<'de> for PathVisitor {
+				type Value = FieldPath;
+
+				fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+					formatter.write_str("a sequence of compact field path tokens")
+				}
+
+				fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<FieldPath, A::Error> {
+					let mut elements = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+
+					while let Some(ElementFromToken(element)) = seq.next_element()? {
+						elements.push(element);
+					}
+
+					Ok(FieldPath { elements })
+				}
+			}
+
+			deserializer.deserialize_seq(PathVisitor)
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn subscript_round_trip() {
+		let cases = [
+			(Subscript::Index(3), "[3]"),
+			(Subscript::BoolKey(true), "[true]"),
+			(Subscript::IntKey(-3), "[-3i]"),
+			(Subscript::UintKey(3), "[3u]"),
+			(Subscript::StringKey("a\"b\\c]".into()), r#"["a\"b\\c]"]"#),
+		];
+
+		for (subscript, expected) in cases {
+			assert_eq!(subscript.to_string(), expected);
+			assert_eq!(expected.parse::<Subscript>().unwrap(), subscript);
+		}
+
+		assert!("3".parse::<Subscript>().is_err());
+		assert!("[\"a\"b\"]".parse::<Subscript>().is_err());
+		assert!("[abc]".parse::<Subscript>().is_err());
+	}
+
+	#[test]
+	fn element_token_round_trip() {
+		let element: FieldPathElement = "labels[\"env\"]".parse().unwrap();
+
+		assert_eq!(element.field_name(), "labels");
+		assert_eq!(element.subscript, Some(Subscript::StringKey("env".into())));
+		assert_eq!(element.to_string(), "labels[\"env\"]");
+
+		assert_eq!(
+			"name"
+				.parse::<FieldPathElement>()
+				.unwrap()
+				.subscript,
+			None
+		);
+		assert!("".parse::<FieldPathElement>().is_err());
+	}
+
+	#[cfg(feature = "serde")]
+	#[test]
+	fn compact_serde_field_path() {
+		use serde::{Deserialize, Serialize};
+
+		use crate::{Vec, protovalidate::FieldPath};
+
+		#[derive(Serialize, Deserialize)]
+		struct Payload {
+			#[serde(with = "compact_serde::field_path")]
+			field: FieldPath,
+		}
+
+		let payload = Payload {
+			field: FieldPath {
+				elements: ["friends[3]", "name"]
+					.into_iter()
+					.map(|token| token.parse().unwrap())
+					.collect::<Vec<_>>(),
+			},
+		};
+
+		let json = serde_json::to_string(&payload).unwrap();
+		assert_eq!(json, r#"{"field":["friends[3]","name"]}"#);
+
+		let decoded: Payload = serde_json::from_str(&json).unwrap();
+		assert_eq!(decoded.field, payload.field);
+		assert_eq!(
+			serde_json::to_string(&Subscript::IntKey(-1)).unwrap(),
+			r#""[-1i]""#
+		);
+	}
+}
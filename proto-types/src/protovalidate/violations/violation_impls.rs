@@ -4,7 +4,9 @@ use prost::Message;
 
 use crate::{
 	Any, Code, Status, String, ToString, Vec,
-	protovalidate::{FieldPath, FieldPathElement, Violation, Violations},
+	protovalidate::{
+		FieldPath, FieldPathElement, Violation, Violations, field_path_element::Subscript,
+	},
 };
 
 impl FromIterator<Violation> for Violations {
@@ -146,7 +148,7 @@ impl FieldPath {
 			}
 
 			if let Some(key) = &field.subscript {
-				path.push(key.to_string());
+				path.push(key.bare_key());
 			}
 		}
 
@@ -160,6 +162,81 @@ impl FieldPath {
 	}
 }
 
+impl FieldPathElement {
+	/// Returns the index of this element in a repeated field, if the subscript is an index.
+	#[must_use]
+	#[inline]
+	pub const fn index(&self) -> Option<u64> {
+		match &self.subscript {
+			Some(Subscript::Index(index)) => Some(*index),
+			_ => None,
+		}
+	}
+
+	/// Returns the map key of this element, if the subscript is a string key.
+	#[must_use]
+	#[inline]
+	pub fn map_key_str(&self) -> Option<&str> {
+		match &self.subscript {
+			Some(Subscript::StringKey(key)) => Some(key),
+			_ => None,
+		}
+	}
+
+	/// Returns the map key of this element, if the subscript is a signed integer key.
+	#[must_use]
+	#[inline]
+	pub const fn map_key_int(&self) -> Option<i64> {
+		match &self.subscript {
+			Some(Subscript::IntKey(key)) => Some(*key),
+			_ => None,
+		}
+	}
+
+	/// Returns the map key of this element, if the subscript is an unsigned integer key.
+	#[must_use]
+	#[inline]
+	pub const fn map_key_uint(&self) -> Option<u64> {
+		match &self.subscript {
+			Some(Subscript::UintKey(key)) => Some(*key),
+			_ => None,
+		}
+	}
+
+	/// Returns the map key of this element, if the subscript is a bool key.
+	#[must_use]
+	#[inline]
+	pub const fn map_key_bool(&self) -> Option<bool> {
+		match &self.subscript {
+			Some(Subscript::BoolKey(key)) => Some(*key),
+			_ => None,
+		}
+	}
+
+	/// Checks whether this element refers to an entry of a map field, which is the case when the subscript is a map key.
+	#[must_use]
+	#[inline]
+	pub const fn is_map_entry(&self) -> bool {
+		matches!(
+			self.subscript,
+			Some(
+				Subscript::BoolKey(_)
+					| Subscript::IntKey(_)
+					| Subscript::UintKey(_)
+					| Subscript::StringKey(_)
+			)
+		)
+	}
+
+	/// Sets the subscript of this element.
+	///
+	/// Note that `usize` values are converted to indexes, while `u64` and `u32` values are converted to unsigned map keys.
+	#[inline]
+	pub fn set_subscript(&mut self, subscript: impl Into<Subscript>) {
+		self.subscript = Some(subscript.into());
+	}
+}
+
 impl Violations {
 	/// Creates a new collection of Violations with the specified initial capacity.
 	#[must_use]
@@ -318,21 +395,28 @@ impl Violation {
 	}
 }
 
+pub(super) const VIOLATIONS_TYPE_URL: &str = "type.googleapis.com/buf.validate.Violations";
+
+/// Returns the message used for a [`Status`] created from the given violations.
+pub(super) fn status_message(violations: &[Violation]) -> &str {
+	if violations.len() == 1 && !violations[0].message().is_empty() {
+		violations[0].message()
+	} else {
+		"Validation failure"
+	}
+}
+
 impl From<Violations> for Status {
 	#[inline(never)]
 	#[cold]
 	fn from(value: Violations) -> Self {
-		let message = if value.violations.len() == 1 && !value.violations[0].message().is_empty() {
-			value.violations[0].message()
-		} else {
-			"Validation failure"
-		};
+		let message = status_message(&value.violations);
 
 		Self {
 			code: Code::InvalidArgument.into(),
 			message: message.to_string(),
 			details: vec![Any {
-				type_url: "type.googleapis.com/buf.validate.Violations".to_string(),
+				type_url: VIOLATIONS_TYPE_URL.to_string(),
 				value: value.encode_to_vec(),
 			}],
 		}
@@ -0,0 +1,256 @@
+use alloc::vec;
+
+use prost::{Message, Name};
+
+use super::violation_impls::{VIOLATIONS_TYPE_URL, status_message};
+use crate::{
+	Any, BoolValue, BytesValue, Code, DoubleValue, Int64Value, Status, String, StringValue,
+	ToString, TypeUrl, UInt64Value, Vec,
+	protovalidate::{Violation, Violations},
+};
+
+/// A snapshot of the value that caused a [`Violation`], attached with [`Violation::with_actual_value`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum RuleValue {
+	Bool(bool),
+	Int(i64),
+	Uint(u64),
+	Double(f64),
+	String(String),
+	Bytes(Vec<u8>),
+	/// Any other value, such as a message, packed into an [`Any`].
+	Any(Any),
+}
+
+impl RuleValue {
+	/// Packs the value into an [`Any`], using the well known wrapper types for the scalar values (such as `google.protobuf.Int64Value`).
+	#[must_use]
+	pub fn to_any(&self) -> Any {
+		fn pack<M: Name>(message: &M) -> Any {
+			Any {
+				type_url: M::type_url(),
+				value: message.encode_to_vec(),
+			}
+		}
+
+		match self {
+			Self::Bool(value) => pack(&BoolValue { value: *value }),
+			Self::Int(value) => pack(&Int64Value { value: *value }),
+			Self::Uint(value) => pack(&UInt64Value { value: *value }),
+			Self::Double(value) => pack(&DoubleValue { value: *value }),
+			Self::String(value) => pack(&StringValue {
+				value: value.clone(),
+			}),
+			Self::Bytes(value) => pack(&BytesValue {
+				value: value.clone().into(),
+			}),
+			Self::Any(any) => any.clone(),
+		}
+	}
+
+	/// Unpacks a value packed with [`to_any`](Self::to_any). [`Any`] messages of other types (or that cannot be decoded) are kept as they are.
+	#[must_use]
+	pub fn from_any(any: Any) -> Self {
+		let Some(type_url) = TypeUrl::parse(&any.type_url) else {
+			return Self::Any(any);
+		};
+
+		let value = any.value.as_slice();
+
+		let unpacked = if type_url.matches::<BoolValue>() {
+			BoolValue::decode(value).map(|v| Self::Bool(v.value))
+		} else if type_url.matches::<Int64Value>() {
+			Int64Value::decode(value).map(|v| Self::Int(v.value))
+		} else if type_url.matches::<UInt64Value>() {
+			UInt64Value::decode(value).map(|v| Self::Uint(v.value))
+		} else if type_url.matches::<DoubleValue>() {
+			DoubleValue::decode(value).map(|v| Self::Double(v.value))
+		} else if type_url.matches::<StringValue>() {
+			StringValue::decode(value).map(|v| Self::String(v.value))
+		} else if type_url.matches::<BytesValue>() {
+			BytesValue::decode(value).map(|v| Self::Bytes(v.value.into()))
+		} else {
+			return Self::Any(any);
+		};
+
+		unpacked.unwrap_or(Self::Any(any))
+	}
+}
+
+macro_rules! impl_from_for_rule_value {
+	($($typ:ty => $variant:ident),*) => {
+		$(
+			impl From<$typ> for RuleValue {
+				#[inline]
+				fn from(value: $typ) -> Self {
+					Self::$variant(value.into())
+				}
+			}
+		)*
+	};
+}
+
+impl_from_for_rule_value!(
+	bool => Bool,
+	i32 => Int,
+	i64 => Int,
+	u32 => Uint,
+	u64 => Uint,
+	f32 => Double,
+	f64 => Double,
+	&str => String,
+	String => String,
+	&[u8] => Bytes,
+	Vec<u8> => Bytes,
+	Any => Any
+);
+
+/// A [`Violation`] with a snapshot of the value that caused it, created with [`Violation::with_actual_value`].
+///
+/// When converted into a [`Status`], the value is packed into an additional detail (see [`Status::from_violations_with_values`]).
+#[derive(Debug, Clone, PartialEq)]
+pub struct ViolationWithValue {
+	pub violation: Violation,
+	pub actual_value: RuleValue,
+}
+
+impl Violation {
+	/// Attaches the value that caused the violation, to help debugging remote validation failures.
+	///
+	/// Values can contain sensitive data, so they should only be attached when the receiver is trusted to see them.
+	#[must_use]
+	#[inline]
+	pub fn with_actual_value(self, value: impl Into<RuleValue>) -> ViolationWithValue {
+		ViolationWithValue {
+			violation: self,
+			actual_value: value.into(),
+		}
+	}
+}
+
+impl Status {
+	/// Creates a new [`Status`] with the `InvalidArgument` code from violations with their actual values.
+	///
+	/// The first detail is the `buf.validate.Violations` message, as in the conversion from [`Violations`].
+	/// It is followed by one detail for each violation, in the same order, with its value packed by [`RuleValue::to_any`].
+	#[must_use]
+	pub fn from_violations_with_values(
+		violations: impl IntoIterator<Item = ViolationWithValue>,
+	) -> Self {
+		let (violations, values): (Vec<_>, Vec<_>) = violations
+			.into_iter()
+			.map(|v| (v.violation, v.actual_value.to_any()))
+			.unzip();
+
+		let violations = Violations { violations };
+		let mut details = vec![Any {
+			type_url: VIOLATIONS_TYPE_URL.to_string(),
+			value: violations.encode_to_vec(),
+		}];
+		details.extend(values);
+
+		Self {
+			code: Code::InvalidArgument.into(),
+			message: status_message(&violations.violations).to_string(),
+			details,
+		}
+	}
+
+	/// Extracts the violations and their actual values from a [`Status`] created with [`from_violations_with_values`](Self::from_violations_with_values).
+	///
+	/// Returns `None` if the first detail is not a `buf.validate.Violations` message, or if it is not followed by a value for each violation.
+	#[must_use]
+	pub fn violations_with_values(&self) -> Option<Vec<ViolationWithValue>> {
+		let (first, values) = self.details.split_first()?;
+
+		if TypeUrl::parse(&first.type_url)? != TypeUrl::parse(VIOLATIONS_TYPE_URL)? {
+			return None;
+		}
+
+		let violations = Violations::decode(first.value.as_slice()).ok()?;
+
+		if violations.violations.len() != values.len() {
+			return None;
+		}
+
+		Some(
+			violations
+				.violations
+				.into_iter()
+				.zip(values.iter().cloned())
+				.map(|(violation, value)| ViolationWithValue {
+					violation,
+					actual_value: RuleValue::from_any(value),
+				})
+				.collect(),
+		)
+	}
+}
+
+impl From<ViolationWithValue> for Status {
+	#[inline]
+	fn from(value: ViolationWithValue) -> Self {
+		Self::from_violations_with_values([value])
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::testkit::violation;
+
+	#[test]
+	fn rule_value_round_trip() {
+		for value in [
+			RuleValue::Bool(true),
+			RuleValue::Int(-3),
+			RuleValue::Uint(3),
+			RuleValue::Double(1.5),
+			RuleValue::String("abc".into()),
+			RuleValue::Bytes(vec![1, 2]),
+			RuleValue::Any(Any {
+				type_url: "type.googleapis.com/my.Message".into(),
+				value: vec![8, 1],
+			}),
+		] {
+			assert_eq!(RuleValue::from_any(value.to_any()), value);
+		}
+
+		assert_eq!(
+			RuleValue::Int(5).to_any().type_url,
+			"type.googleapis.com/google.protobuf.Int64Value"
+		);
+	}
+
+	#[test]
+	fn status_with_values() {
+		let status = Status::from_violations_with_values([
+			violation("", "string.min_len", "invalid").with_actual_value("ab"),
+			violation("", "int32.gt", "invalid").with_actual_value(-1),
+		]);
+
+		assert_eq!(status.code, i32::from(Code::InvalidArgument));
+		assert_eq!(status.details.len(), 3);
+
+		let extracted = status.violations_with_values().unwrap();
+		assert_eq!(extracted.len(), 2);
+		assert_eq!(extracted[0].violation.rule_id(), "string.min_len");
+		assert_eq!(extracted[0].actual_value, RuleValue::String("ab".into()));
+		assert_eq!(extracted[1].actual_value, RuleValue::Int(-1));
+
+		let single: Status = violation("", "bool.const", "invalid")
+			.with_actual_value(false)
+			.into();
+		assert_eq!(single.message, "invalid");
+		assert_eq!(
+			single.violations_with_values().unwrap()[0].actual_value,
+			RuleValue::Bool(false)
+		);
+
+		let plain: Status = Violations {
+			violations: vec![violation("", "bool.const", "invalid")],
+		}
+		.into();
+		assert_eq!(plain.violations_with_values(), None);
+	}
+}
@@ -0,0 +1,146 @@
+use alloc::vec;
+
+use prost::{
+	Message,
+	encoding::{encoded_len_varint, message},
+};
+
+use super::violation_impls::{VIOLATIONS_TYPE_URL, status_message};
+use crate::{
+	Any, Code, Status, ToString, Vec, format,
+	protovalidate::{Violation, Violations},
+};
+
+impl Violations {
+	/// The rule id of the summary violation appended by [`Status::from_violations_bounded`] when some violations are omitted.
+	pub const TRUNCATED_RULE_ID: &'static str = "violations.truncated";
+}
+
+fn truncation_summary(omitted: usize) -> Violation {
+	Violation {
+		rule_id: Some(Violations::TRUNCATED_RULE_ID.to_string()),
+		message: Some(format!(
+			"{omitted} more violation(s) omitted to fit the size limit"
+		)),
+		..Default::default()
+	}
+}
+
+/// Returns the encoded length of a [`Status`] with the given base fields and a single [`Any`] detail with a payload of `payload_len` bytes.
+fn status_len(base: &Status, payload_len: usize) -> usize {
+	let mut any_len =
+		1 + encoded_len_varint(VIOLATIONS_TYPE_URL.len() as u64) + VIOLATIONS_TYPE_URL.len();
+
+	if payload_len > 0 {
+		any_len += 1 + encoded_len_varint(payload_len as u64) + payload_len;
+	}
+
+	base.encoded_len() + 1 + encoded_len_varint(any_len as u64) + any_len
+}
+
+impl Status {
+	/// Converts the violations into a [`Status`] like the [`From`] impl does, while keeping the encoded size of the status within `max_encoded_bytes`.
+	///
+	/// If the full list of violations does not fit, only the first ones that fit are kept, followed by a summary violation
+	/// with the [`TRUNCATED_RULE_ID`](Violations::TRUNCATED_RULE_ID) rule id and a message with the number of omitted violations.
+	/// If not even the summary fits, the details are dropped entirely, so that only the code and the message remain
+	/// (the message is never truncated, so the result can still exceed the budget if the message alone is larger than it).
+	#[must_use]
+	pub fn from_violations_bounded(violations: Violations, max_encoded_bytes: usize) -> Self {
+		let mut base = Self {
+			code: Code::InvalidArgument.into(),
+			message: status_message(&violations.violations).to_string(),
+			details: vec![],
+		};
+
+		let sizes: Vec<usize> = violations
+			.violations
+			.iter()
+			.map(|v| message::encoded_len(1, v))
+			.collect();
+		let total: usize = sizes.iter().sum();
+
+		if status_len(&base, total) <= max_encoded_bytes {
+			base.details.push(Any {
+				type_url: VIOLATIONS_TYPE_URL.to_string(),
+				value: violations.encode_to_vec(),
+			});
+			return base;
+		}
+
+		let mut kept_len = total;
+
+		for kept in (0..sizes.len()).rev() {
+			kept_len -= sizes[kept];
+
+			let summary = truncation_summary(sizes.len() - kept);
+			let payload_len = kept_len + message::encoded_len(1, &summary);
+
+			if status_len(&base, payload_len) <= max_encoded_bytes {
+				let mut truncated = violations;
+				truncated.violations.truncate(kept);
+				truncated.violations.push(summary);
+
+				base.details.push(Any {
+					type_url: VIOLATIONS_TYPE_URL.to_string(),
+					value: truncated.encode_to_vec(),
+				});
+				return base;
+			}
+		}
+
+		base
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn violations(count: usize) -> Violations {
+		(0..count)
+			.map(|i| Violation {
+				rule_id: Some(format!("rule_{i}")),
+				message: Some("x".repeat(50)),
+				..Default::default()
+			})
+			.collect()
+	}
+
+	#[test]
+	fn fits_without_truncation() {
+		let status = Status::from_violations_bounded(violations(3), 10_000);
+
+		assert_eq!(status, Status::from(violations(3)));
+	}
+
+	#[test]
+	fn truncates_to_budget() {
+		let full_len = Status::from(violations(100)).encoded_len();
+		let budget = full_len / 4;
+
+		let status = Status::from_violations_bounded(violations(100), budget);
+		assert!(status.encoded_len() <= budget);
+
+		let decoded = Violations::decode(status.details[0].value.as_slice()).unwrap();
+		let summary = decoded.violations.last().unwrap();
+
+		assert_eq!(summary.rule_id(), Violations::TRUNCATED_RULE_ID);
+		assert_eq!(
+			summary.message(),
+			format!(
+				"{} more violation(s) omitted to fit the size limit",
+				101 - decoded.violations.len()
+			)
+		);
+		assert_eq!(decoded.violations[0].rule_id(), "rule_0");
+	}
+
+	#[test]
+	fn drops_details_when_nothing_fits() {
+		let status = Status::from_violations_bounded(violations(2), 25);
+
+		assert!(status.details.is_empty());
+		assert_eq!(status.message, "Validation failure");
+	}
+}
@@ -1,4 +1,5 @@
 use super::*;
+use crate::protovalidate::descriptor_consts::field_rules;
 
 /// Data for the `cel` violation.
 pub const CEL_VIOLATION: ViolationData = ViolationData {
@@ -6,7 +7,7 @@ pub const CEL_VIOLATION: ViolationData = ViolationData {
 	elements: &[ConstPathElement {
 		name: "cel",
 		field_type: Type::Message,
-		number: 23,
+		number: field_rules::CEL,
 	}],
 };
 
@@ -16,6 +17,6 @@ pub const REQUIRED_VIOLATION: ViolationData = ViolationData {
 	elements: &[ConstPathElement {
 		name: "required",
 		field_type: Type::Bool,
-		number: 25,
+		number: field_rules::REQUIRED,
 	}],
 };
@@ -1,4 +1,5 @@
 use super::*;
+use crate::protovalidate::descriptor_consts::oneof_rules;
 
 /// Data for the oneof `required` violation.
 pub const ONEOF_REQUIRED_VIOLATION: ViolationData = ViolationData {
@@ -6,6 +7,6 @@ pub const ONEOF_REQUIRED_VIOLATION: ViolationData = ViolationData {
 	elements: &[ConstPathElement {
 		name: "required",
 		field_type: Type::Bool,
-		number: 1,
+		number: oneof_rules::REQUIRED,
 	}],
 };
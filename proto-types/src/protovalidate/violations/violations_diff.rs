@@ -0,0 +1,166 @@
+use core::cmp::Ordering;
+
+use super::violations_order::cmp_paths;
+use crate::protovalidate::{Violation, Violations};
+
+/// The differences between two sets of [`Violations`], as returned by [`Violations::diff`].
+///
+/// Each collection is sorted by the [identity](Violation::cmp_identity) of the violations, and then by their [canonical order](Violation::cmp_canonical).
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ViolationsDiff {
+	/// The violations that are only present in the newer set.
+	pub added: Violations,
+	/// The violations that are only present in the older set.
+	pub removed: Violations,
+	/// The violations that are present in both sets, as they appear in the newer one.
+	pub unchanged: Violations,
+}
+
+impl ViolationsDiff {
+	/// Returns `true` if no violation was added or removed.
+	#[must_use]
+	#[inline]
+	pub fn is_unchanged(&self) -> bool {
+		self.added.is_empty() && self.removed.is_empty()
+	}
+}
+
+impl Violation {
+	/// Compares the identities of two violations, which are made of the field path and the rule id (along with `for_key`, so that the violations of a map key and of its value are kept apart).
+	///
+	/// Two violations with the same identity are considered to be the same one by [`Violations::diff`], even if their messages are different.
+	#[must_use]
+	pub fn cmp_identity(&self, other: &Self) -> Ordering {
+		cmp_paths(self.field.as_ref(), other.field.as_ref())
+			.then_with(|| self.rule_id().cmp(other.rule_id()))
+			.then_with(|| self.for_key().cmp(&other.for_key()))
+	}
+}
+
+impl Violations {
+	/// Compares this set of violations with a newer one, matching them by their [identity](Violation::cmp_identity).
+	///
+	/// If several violations share the same identity, they are matched one by one, so that a violation that appears one more time in the newer set is reported as added.
+	///
+	/// # Examples
+	/// ```rust
+	/// use proto_types::protovalidate::{Violation, Violations};
+	///
+	/// let violation = |rule_id: &str, message: &str| Violation {
+	///   rule_id: Some(rule_id.to_string()),
+	///   message: Some(message.to_string()),
+	///   ..Default::default()
+	/// };
+	///
+	/// let before: Violations = [violation("a", "old message"), violation("b", "")].into_iter().collect();
+	/// let after: Violations = [violation("c", ""), violation("a", "new message")].into_iter().collect();
+	///
+	/// let diff = before.diff(&after);
+	///
+	/// assert_eq!(diff.added[0].rule_id(), "c");
+	/// assert_eq!(diff.removed[0].rule_id(), "b");
+	/// assert_eq!(diff.unchanged[0].message(), "new message");
+	/// ```
+	#[must_use]
+	pub fn diff(&self, newer: &Self) -> ViolationsDiff {
+		let sorted = |violations: &Self| {
+			let mut violations = violations.clone();
+			violations.sort_by(|a, b| a.cmp_identity(b).then_with(|| a.cmp_canonical(b)));
+			violations
+		};
+
+		let (old, new) = (sorted(self), sorted(newer));
+		let mut diff = ViolationsDiff::default();
+		let (mut old_iter, mut new_iter) = (old.iter().peekable(), new.iter().peekable());
+
+		loop {
+			let ordering = match (old_iter.peek(), new_iter.peek()) {
+				(Some(old), Some(new)) => old.cmp_identity(new),
+				(Some(_), None) => Ordering::Less,
+				(None, Some(_)) => Ordering::Greater,
+				(None, None) => break,
+			};
+
+			match ordering {
+				Ordering::Less => diff
+					.removed
+					.push(old_iter.next().unwrap().clone()),
+				Ordering::Greater => diff.added.push(new_iter.next().unwrap().clone()),
+				Ordering::Equal => {
+					old_iter.next();
+					diff.unchanged
+						.push(new_iter.next().unwrap().clone());
+				}
+			}
+		}
+
+		diff
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{ToString, testkit::violation};
+
+	#[test]
+	fn diff_by_identity() {
+		let before: Violations = [
+			violation("name", "required", "name is required"),
+			violation("email", "string.email", "invalid email"),
+			violation("tags", "repeated.unique", ""),
+		]
+		.into_iter()
+		.collect();
+
+		let after: Violations = [
+			violation("tags", "repeated.unique", ""),
+			violation("tags", "repeated.unique", ""),
+			violation("email", "string.email", "email is not valid"),
+			violation("age", "int32.gte", ""),
+		]
+		.into_iter()
+		.collect();
+
+		let diff = before.diff(&after);
+
+		let rules = |violations: &Violations| {
+			violations
+				.iter()
+				.map(|v| (v.field_path_str().unwrap(), v.rule_id().to_string()))
+				.collect::<crate::Vec<_>>()
+		};
+
+		assert_eq!(
+			rules(&diff.added),
+			[
+				("age".to_string(), "int32.gte".to_string()),
+				("tags".to_string(), "repeated.unique".to_string())
+			]
+		);
+		assert_eq!(
+			rules(&diff.removed),
+			[("name".to_string(), "required".to_string())]
+		);
+		assert_eq!(
+			rules(&diff.unchanged),
+			[
+				("email".to_string(), "string.email".to_string()),
+				("tags".to_string(), "repeated.unique".to_string())
+			]
+		);
+		assert_eq!(diff.unchanged[0].message(), "email is not valid");
+		assert!(!diff.is_unchanged());
+
+		let mut key_violation = violation("labels", "string.min_len", "");
+		key_violation.for_key = Some(true);
+		let value_violation = violation("labels", "string.min_len", "");
+		let diff =
+			Violations::from_iter([key_violation]).diff(&Violations::from_iter([value_violation]));
+		assert_eq!(diff.added.len(), 1);
+		assert_eq!(diff.removed.len(), 1);
+
+		assert!(after.diff(&after).is_unchanged());
+		assert_eq!(Violations::default().diff(&after).added.len(), 4);
+	}
+}
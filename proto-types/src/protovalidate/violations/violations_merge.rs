@@ -0,0 +1,81 @@
+use crate::protovalidate::Violations;
+
+impl Violations {
+	/// Converts the collection into a [`Result`], which is `Err` if there is at least one violation.
+	#[inline]
+	pub fn into_result(self) -> Result<(), Self> {
+		if self.violations.is_empty() {
+			Ok(())
+		} else {
+			Err(self)
+		}
+	}
+
+	/// Aggregates the violations from several validation results, preserving their order.
+	///
+	/// Returns `Ok(())` only if no result contained any violation.
+	pub fn from_results(results: impl IntoIterator<Item = Result<(), Self>>) -> Result<(), Self> {
+		results
+			.into_iter()
+			.filter_map(Result::err)
+			.flatten()
+			.collect::<Self>()
+			.into_result()
+	}
+
+	/// Runs all of the given validators, without stopping at the first failure, and aggregates their violations.
+	///
+	/// Validators of different types can be combined by passing them as trait objects, such as `&dyn Fn() -> Result<(), Violations>`.
+	pub fn try_all<F>(validators: impl IntoIterator<Item = F>) -> Result<(), Self>
+	where
+		F: FnOnce() -> Result<(), Self>,
+	{
+		Self::from_results(
+			validators
+				.into_iter()
+				.map(|validator| validator()),
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{ToString, protovalidate::Violation};
+
+	fn failure(rule_id: &str) -> Result<(), Violations> {
+		Err(core::iter::once(Violation {
+			rule_id: Some(rule_id.to_string()),
+			..Default::default()
+		})
+		.collect())
+	}
+
+	#[test]
+	fn aggregation() {
+		assert_eq!(Violations::from_results([Ok(()), Ok(())]), Ok(()));
+
+		let violations =
+			Violations::from_results([failure("a"), Ok(()), failure("b")]).unwrap_err();
+		assert_eq!(violations.len(), 2);
+		assert_eq!(violations[1].rule_id(), "b");
+
+		let generated = || failure("generated");
+		let manual = || failure("manual");
+		let valid = || Ok(());
+
+		let validators: [&dyn Fn() -> Result<(), Violations>; 3] = [&generated, &valid, &manual];
+		let violations = Violations::try_all(validators).unwrap_err();
+
+		assert!(
+			violations
+				.violation_by_rule_id("generated")
+				.is_some()
+		);
+		assert!(
+			violations
+				.violation_by_rule_id("manual")
+				.is_some()
+		);
+	}
+}
@@ -0,0 +1,146 @@
+use core::cmp::Ordering;
+
+use prost::Message;
+
+use crate::{
+	Vec,
+	protovalidate::{
+		FieldPath, FieldPathElement, Violation, Violations, field_path_element::Subscript,
+	},
+};
+
+const fn subscript_rank(subscript: &Subscript) -> u8 {
+	match subscript {
+		Subscript::Index(_) => 0,
+		Subscript::BoolKey(_) => 1,
+		Subscript::IntKey(_) => 2,
+		Subscript::UintKey(_) => 3,
+		Subscript::StringKey(_) => 4,
+	}
+}
+
+fn cmp_subscripts(a: Option<&Subscript>, b: Option<&Subscript>) -> Ordering {
+	match (a, b) {
+		(Some(Subscript::Index(a)), Some(Subscript::Index(b)))
+		| (Some(Subscript::UintKey(a)), Some(Subscript::UintKey(b))) => a.cmp(b),
+		(Some(Subscript::BoolKey(a)), Some(Subscript::BoolKey(b))) => a.cmp(b),
+		(Some(Subscript::IntKey(a)), Some(Subscript::IntKey(b))) => a.cmp(b),
+		(Some(Subscript::StringKey(a)), Some(Subscript::StringKey(b))) => a.cmp(b),
+		(Some(a), Some(b)) => subscript_rank(a).cmp(&subscript_rank(b)),
+		(a, b) => a.is_some().cmp(&b.is_some()),
+	}
+}
+
+fn cmp_elements(a: &FieldPathElement, b: &FieldPathElement) -> Ordering {
+	a.field_name()
+		.cmp(b.field_name())
+		.then_with(|| a.field_number().cmp(&b.field_number()))
+		.then_with(|| cmp_subscripts(a.subscript.as_ref(), b.subscript.as_ref()))
+}
+
+pub(super) fn cmp_paths(a: Option<&FieldPath>, b: Option<&FieldPath>) -> Ordering {
+	let a = a.map_or(&[][..], |path| &path.elements);
+	let b = b.map_or(&[][..], |path| &path.elements);
+
+	a.iter()
+		.zip(b)
+		.map(|(a, b)| cmp_elements(a, b))
+		.find(|ordering| ordering.is_ne())
+		.unwrap_or_else(|| a.len().cmp(&b.len()))
+}
+
+impl Violation {
+	/// Compares two violations according to the canonical order used by [`Violations::sort_canonical`].
+	#[must_use]
+	pub fn cmp_canonical(&self, other: &Self) -> Ordering {
+		cmp_paths(self.field.as_ref(), other.field.as_ref())
+			.then_with(|| self.rule_id().cmp(other.rule_id()))
+			.then_with(|| cmp_paths(self.rule.as_ref(), other.rule.as_ref()))
+			.then_with(|| self.for_key().cmp(&other.for_key()))
+			.then_with(|| self.message().cmp(other.message()))
+	}
+}
+
+impl Violations {
+	/// Sorts the violations in their canonical order, so that the same set of violations always produces the same sequence.
+	///
+	/// Violations are ordered by their field path first, and then by their rule id.
+	/// Field paths are compared element by element, using the field name, the field number and then the subscript, with
+	/// indexes and map keys compared by value (so that `items[2]` comes before `items[10]`). A path comes before the paths that extend it,
+	/// and violations without a field path come first.
+	/// Remaining ties are broken by the rule path, `for_key` and the message.
+	#[inline]
+	pub fn sort_canonical(&mut self) {
+		self.violations.sort_by(Violation::cmp_canonical);
+	}
+
+	/// Encodes the message into a byte-stable form, with the violations in their [canonical order](Self::sort_canonical).
+	#[must_use]
+	pub fn encode_canonical(&self) -> Vec<u8> {
+		let mut canonical = self.clone();
+		canonical.sort_canonical();
+		canonical.encode_to_vec()
+	}
+
+	/// Returns a page of at most `limit` violations, starting at `offset`, along with the total number of violations.
+	///
+	/// The current order is preserved, so [`sort_canonical`](Self::sort_canonical) should be called first to get stable pages across calls.
+	#[must_use]
+	pub fn paginate(&self, offset: usize, limit: usize) -> (Self, usize) {
+		let page = self
+			.violations
+			.iter()
+			.skip(offset)
+			.take(limit)
+			.cloned()
+			.collect();
+
+		(page, self.violations.len())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{ToString, Vec, testkit::violation};
+
+	#[test]
+	fn canonical_order_and_pages() {
+		let mut violations: Violations = [
+			violation("items[10]", "a", ""),
+			violation("name", "string.min_len", ""),
+			violation("items[2]", "a", ""),
+			violation("name", "required", ""),
+			violation("items", "repeated.min_items", ""),
+		]
+		.into_iter()
+		.collect();
+
+		violations.sort_canonical();
+
+		let order: Vec<_> = violations
+			.iter()
+			.map(|v| (v.field_path_str().unwrap(), v.rule_id().to_string()))
+			.collect();
+
+		assert_eq!(
+			order,
+			[
+				("items".to_string(), "repeated.min_items".to_string()),
+				("items.2".to_string(), "a".to_string()),
+				("items.10".to_string(), "a".to_string()),
+				("name".to_string(), "required".to_string()),
+				("name".to_string(), "string.min_len".to_string()),
+			]
+		);
+
+		let (page, total) = violations.paginate(3, 10);
+		assert_eq!(total, 5);
+		assert_eq!(page.len(), 2);
+		assert_eq!(page[0].rule_id(), "required");
+
+		let (page, total) = violations.paginate(10, 10);
+		assert_eq!(total, 5);
+		assert!(page.is_empty());
+	}
+}
@@ -0,0 +1,414 @@
+use prost::encoding::{DecodeContext, WireType, decode_key, decode_varint, skip_field};
+
+use crate::{
+	DecodeError, Vec,
+	protovalidate::{
+		FieldPath, FieldPathElement, Violation, Violations, field_path_element::Subscript,
+	},
+};
+
+/// Reads a length-delimited value, returning the slice that contains it.
+fn read_bytes<'a>(buf: &mut &'a [u8]) -> Result<&'a [u8], DecodeError> {
+	let len = decode_varint(buf)?;
+
+	#[allow(deprecated)]
+	let len = usize::try_from(len)
+		.ok()
+		.filter(|len| *len <= buf.len())
+		.ok_or_else(|| DecodeError::new("buffer underflow"))?;
+
+	let (value, rest) = buf.split_at(len);
+	*buf = rest;
+
+	Ok(value)
+}
+
+fn read_str<'a>(buf: &mut &'a [u8]) -> Result<&'a str, DecodeError> {
+	#[allow(deprecated)]
+	core::str::from_utf8(read_bytes(buf)?)
+		.map_err(|_| DecodeError::new("invalid string value: data is not UTF-8 encoded"))
+}
+
+fn check_wire_type(actual: WireType, expected: WireType) -> Result<(), DecodeError> {
+	if actual == expected {
+		Ok(())
+	} else {
+		#[allow(deprecated)]
+		Err(DecodeError::new(crate::format!(
+			"invalid wire type: {actual:?} (expected {expected:?})"
+		)))
+	}
+}
+
+/// Reads an `int32` (or enum) varint, which is sign-extended to 64 bits on the wire.
+fn read_int32(buf: &mut &[u8]) -> Result<i32, DecodeError> {
+	// SAFETY: Truncating is how protobuf decodes int32 values
+	#[allow(clippy::cast_possible_truncation)]
+	decode_varint(buf).map(|value| value as i32)
+}
+
+/// A borrowed view of [`Violations`], decoded directly from the protobuf encoding without allocating the strings.
+///
+/// This is meant for scanning large amounts of stored violations, where only a few fields are inspected for each violation.
+/// The view can be converted into the owned [`Violations`] with [`From`].
+///
+/// # Examples
+/// ```rust
+/// use prost::Message;
+/// use proto_types::protovalidate::{Violation, Violations, ViolationsRef};
+///
+/// let encoded = Violations {
+///   violations: vec![Violation {
+///     rule_id: Some("string.min_len".into()),
+///     ..Default::default()
+///   }],
+/// }
+/// .encode_to_vec();
+///
+/// let view = ViolationsRef::decode(&encoded).unwrap();
+///
+/// assert_eq!(view.violations[0].rule_id(), "string.min_len");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct ViolationsRef<'a> {
+	pub violations: Vec<ViolationRef<'a>>,
+}
+
+/// A borrowed view of a [`Violation`]. See [`ViolationsRef`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct ViolationRef<'a> {
+	pub field: Option<FieldPathRef<'a>>,
+	pub rule: Option<FieldPathRef<'a>>,
+	pub rule_id: Option<&'a str>,
+	pub message: Option<&'a str>,
+	pub for_key: Option<bool>,
+}
+
+/// A borrowed view of a [`FieldPath`]. See [`ViolationsRef`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct FieldPathRef<'a> {
+	pub elements: Vec<FieldPathElementRef<'a>>,
+}
+
+/// A borrowed view of a [`FieldPathElement`]. See [`ViolationsRef`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct FieldPathElementRef<'a> {
+	pub field_number: Option<i32>,
+	pub field_name: Option<&'a str>,
+	pub field_type: Option<i32>,
+	pub key_type: Option<i32>,
+	pub value_type: Option<i32>,
+	pub subscript: Option<SubscriptRef<'a>>,
+}
+
+/// A borrowed view of a [`Subscript`]. See [`ViolationsRef`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SubscriptRef<'a> {
+	Index(u64),
+	BoolKey(bool),
+	IntKey(i64),
+	UintKey(u64),
+	StringKey(&'a str),
+}
+
+impl<'a> ViolationsRef<'a> {
+	/// Decodes the view from an encoded [`Violations`] message.
+	pub fn decode(mut buf: &'a [u8]) -> Result<Self, DecodeError> {
+		let mut view = Self::default();
+
+		while !buf.is_empty() {
+			let (tag, wire_type) = decode_key(&mut buf)?;
+
+			if tag == 1 {
+				check_wire_type(wire_type, WireType::LengthDelimited)?;
+				view.violations
+					.push(ViolationRef::decode(read_bytes(&mut buf)?)?);
+			} else {
+				skip_field(wire_type, tag, &mut buf, DecodeContext::default())?;
+			}
+		}
+
+		Ok(view)
+	}
+}
+
+impl<'a> ViolationRef<'a> {
+	/// Decodes the view from an encoded [`Violation`] message.
+	pub fn decode(mut buf: &'a [u8]) -> Result<Self, DecodeError> {
+		let mut view = Self::default();
+
+		while !buf.is_empty() {
+			let (tag, wire_type) = decode_key(&mut buf)?;
+
+			match tag {
+				2 => {
+					check_wire_type(wire_type, WireType::LengthDelimited)?;
+					view.rule_id = Some(read_str(&mut buf)?);
+				}
+				3 => {
+					check_wire_type(wire_type, WireType::LengthDelimited)?;
+					view.message = Some(read_str(&mut buf)?);
+				}
+				4 => {
+					check_wire_type(wire_type, WireType::Varint)?;
+					view.for_key = Some(decode_varint(&mut buf)? != 0);
+				}
+				5 | 6 => {
+					check_wire_type(wire_type, WireType::LengthDelimited)?;
+					let path = if tag == 5 {
+						&mut view.field
+					} else {
+						&mut view.rule
+					};
+					// Repeated occurrences of an embedded message are merged, which appends the elements
+					path.get_or_insert_with(Default::default)
+						.merge(read_bytes(&mut buf)?)?;
+				}
+				_ => skip_field(wire_type, tag, &mut buf, DecodeContext::default())?,
+			}
+		}
+
+		Ok(view)
+	}
+
+	/// Returns the value of `rule_id`, or the default value if the field is not set.
+	#[must_use]
+	#[inline]
+	pub fn rule_id(&self) -> &'a str {
+		self.rule_id.unwrap_or_default()
+	}
+
+	/// Returns the value of `message`, or the default value if the field is not set.
+	#[must_use]
+	#[inline]
+	pub fn message(&self) -> &'a str {
+		self.message.unwrap_or_default()
+	}
+
+	/// Returns the value of `for_key`, or the default value if the field is not set.
+	#[must_use]
+	#[inline]
+	pub fn for_key(&self) -> bool {
+		self.for_key.unwrap_or_default()
+	}
+}
+
+impl<'a> FieldPathRef<'a> {
+	/// Decodes the view from an encoded [`FieldPath`] message.
+	pub fn decode(buf: &'a [u8]) -> Result<Self, DecodeError> {
+		let mut view = Self::default();
+		view.merge(buf)?;
+		Ok(view)
+	}
+
+	fn merge(&mut self, mut buf: &'a [u8]) -> Result<(), DecodeError> {
+		while !buf.is_empty() {
+			let (tag, wire_type) = decode_key(&mut buf)?;
+
+			if tag == 1 {
+				check_wire_type(wire_type, WireType::LengthDelimited)?;
+				self.elements
+					.push(FieldPathElementRef::decode(read_bytes(&mut buf)?)?);
+			} else {
+				skip_field(wire_type, tag, &mut buf, DecodeContext::default())?;
+			}
+		}
+
+		Ok(())
+	}
+}
+
+impl<'a> FieldPathElementRef<'a> {
+	/// Decodes the view from an encoded [`FieldPathElement`] message.
+	pub fn decode(mut buf: &'a [u8]) -> Result<Self, DecodeError> {
+		let mut view = Self::default();
+
+		while !buf.is_empty() {
+			let (tag, wire_type) = decode_key(&mut buf)?;
+
+			let expected = if matches!(tag, 2 | 10) {
+				WireType::LengthDelimited
+			} else {
+				WireType::Varint
+			};
+
+			if !(1..=10).contains(&tag) {
+				skip_field(wire_type, tag, &mut buf, DecodeContext::default())?;
+				continue;
+			}
+
+			check_wire_type(wire_type, expected)?;
+
+			match tag {
+				1 => view.field_number = Some(read_int32(&mut buf)?),
+				2 => view.field_name = Some(read_str(&mut buf)?),
+				3 => view.field_type = Some(read_int32(&mut buf)?),
+				4 => view.key_type = Some(read_int32(&mut buf)?),
+				5 => view.value_type = Some(read_int32(&mut buf)?),
+				6 => view.subscript = Some(SubscriptRef::Index(decode_varint(&mut buf)?)),
+				7 => view.subscript = Some(SubscriptRef::BoolKey(decode_varint(&mut buf)? != 0)),
+				8 => {
+					view.subscript =
+						Some(SubscriptRef::IntKey(decode_varint(&mut buf)?.cast_signed()));
+				}
+				9 => view.subscript = Some(SubscriptRef::UintKey(decode_varint(&mut buf)?)),
+				_ => view.subscript = Some(SubscriptRef::StringKey(read_str(&mut buf)?)),
+			}
+		}
+
+		Ok(view)
+	}
+}
+
+impl From<ViolationsRef<'_>> for Violations {
+	fn from(value: ViolationsRef<'_>) -> Self {
+		Self {
+			violations: value
+				.violations
+				.into_iter()
+				.map(Into::into)
+				.collect(),
+		}
+	}
+}
+
+impl From<ViolationRef<'_>> for Violation {
+	fn from(value: ViolationRef<'_>) -> Self {
+		Self {
+			field: value.field.map(Into::into),
+			rule: value.rule.map(Into::into),
+			rule_id: value.rule_id.map(Into::into),
+			message: value.message.map(Into::into),
+			for_key: value.for_key,
+		}
+	}
+}
+
+impl From<FieldPathRef<'_>> for FieldPath {
+	fn from(value: FieldPathRef<'_>) -> Self {
+		Self {
+			elements: value
+				.elements
+				.into_iter()
+				.map(Into::into)
+				.collect(),
+		}
+	}
+}
+
+impl From<FieldPathElementRef<'_>> for FieldPathElement {
+	fn from(value: FieldPathElementRef<'_>) -> Self {
+		Self {
+			field_number: value.field_number,
+			field_name: value.field_name.map(Into::into),
+			field_type: value.field_type,
+			key_type: value.key_type,
+			value_type: value.value_type,
+			subscript: value.subscript.map(Into::into),
+		}
+	}
+}
+
+impl From<SubscriptRef<'_>> for Subscript {
+	fn from(value: SubscriptRef<'_>) -> Self {
+		match value {
+			SubscriptRef::Index(index) => Self::Index(index),
+			SubscriptRef::BoolKey(key) => Self::BoolKey(key),
+			SubscriptRef::IntKey(key) => Self::IntKey(key),
+			SubscriptRef::UintKey(key) => Self::UintKey(key),
+			SubscriptRef::StringKey(key) => Self::StringKey(key.into()),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use alloc::vec;
+
+	use prost::Message;
+
+	use super::*;
+
+	fn element(name: &str, subscript: Option<Subscript>) -> FieldPathElement {
+		FieldPathElement {
+			field_number: Some(-1),
+			field_name: Some(name.into()),
+			field_type: Some(9),
+			key_type: None,
+			value_type: Some(11),
+			subscript,
+		}
+	}
+
+	fn sample() -> Violations {
+		Violations {
+			violations: vec![
+				Violation {
+					field: Some(FieldPath {
+						elements: vec![
+							element("items", Some(Subscript::Index(3))),
+							element("labels", Some(Subscript::StringKey("env".into()))),
+							element("scores", Some(Subscript::IntKey(-7))),
+						],
+					}),
+					rule: Some(FieldPath {
+						elements: vec![element("string", None)],
+					}),
+					rule_id: Some("string.min_len".into()),
+					message: Some("value is too short".into()),
+					for_key: Some(true),
+				},
+				Violation::default(),
+			],
+		}
+	}
+
+	#[test]
+	fn round_trip() {
+		let violations = sample();
+		let encoded = violations.encode_to_vec();
+
+		let view = ViolationsRef::decode(&encoded).unwrap();
+
+		assert_eq!(view.violations[0].rule_id(), "string.min_len");
+		assert_eq!(
+			view.violations[0]
+				.field
+				.as_ref()
+				.unwrap()
+				.elements[1]
+				.subscript,
+			Some(SubscriptRef::StringKey("env"))
+		);
+		assert_eq!(Violations::from(view), violations);
+	}
+
+	#[test]
+	fn unknown_fields_and_invalid_input() {
+		let mut encoded = sample().encode_to_vec();
+		// Field 15, varint 1
+		encoded.extend_from_slice(&[0x78, 0x01]);
+
+		assert_eq!(
+			Violations::from(ViolationsRef::decode(&encoded).unwrap()),
+			sample()
+		);
+
+		// Cuts into the first violation, past the unknown field and the empty second violation
+		let truncated = &encoded[..encoded.len() - 5];
+		assert!(ViolationsRef::decode(truncated).is_err());
+
+		let invalid_utf8 = Violation {
+			rule_id: Some("ab".into()),
+			..Default::default()
+		}
+		.encode_to_vec()
+		.into_iter()
+		.map(|byte| if byte == b'a' { 0xff } else { byte })
+		.collect::<Vec<u8>>();
+		assert!(ViolationRef::decode(&invalid_utf8).is_err());
+
+		// Field 2 (rule_id) with the varint wire type
+		assert!(ViolationRef::decode(&[0x10, 0x01]).is_err());
+	}
+}
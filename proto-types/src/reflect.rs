@@ -0,0 +1,257 @@
+use prost_reflect::{
+	DescriptorError, DescriptorPool, DynamicMessage, FieldDescriptor, Kind, MessageDescriptor,
+	ReflectMessage, Value,
+};
+use thiserror::Error;
+
+use crate::{DecodeError, FileDescriptorSet, Message, Name, String, ToString, format};
+
+/// Errors that can occur when converting between the types of this crate and [`DynamicMessage`]s.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum ReflectError {
+	#[error("Message `{0}` was not found in the descriptor pool")]
+	MessageNotFound(String),
+
+	#[error("Field `{0}` was not found in the message descriptor")]
+	FieldNotFound(String),
+
+	#[error("Expected a message of type `{expected}`, found `{found}`")]
+	TypeMismatch { expected: String, found: String },
+
+	#[error("{0}")]
+	Decode(#[from] DecodeError),
+
+	#[error("{0}")]
+	Descriptor(#[from] DescriptorError),
+}
+
+/// The encoded [`FileDescriptorSet`] with the `google.type`, `google.rpc` and `buf.validate` files of this crate.
+///
+/// It does not include the `google.protobuf` files that it imports, which are registered from the global pool by [`register_well_known_types`].
+pub const FILE_DESCRIPTOR_SET_BYTES: &[u8] = include_bytes!("file_descriptor_set.bin");
+
+/// Adds the descriptors for all the types of this crate to the given pool, if they are not already present.
+///
+/// This includes the `google.protobuf` well known types, along with the `google.type`, `google.rpc` and `buf.validate` packages.
+pub fn register_well_known_types(pool: &mut DescriptorPool) -> Result<(), ReflectError> {
+	pool.add_file_descriptor_protos(
+		DescriptorPool::global()
+			.file_descriptor_protos()
+			.cloned(),
+	)?;
+	pool.decode_file_descriptor_set(FILE_DESCRIPTOR_SET_BYTES)?;
+
+	Ok(())
+}
+
+/// Adds the files from a [`FileDescriptorSet`] of this crate to the given pool.
+pub fn register_file_descriptor_set(
+	pool: &mut DescriptorPool,
+	set: &FileDescriptorSet,
+) -> Result<(), ReflectError> {
+	pool.decode_file_descriptor_set(set.encode_to_vec().as_slice())?;
+
+	Ok(())
+}
+
+/// Converts the given message into a [`DynamicMessage`], using the descriptor found in the pool.
+pub fn to_dynamic<T: Name>(
+	value: &T,
+	pool: &DescriptorPool,
+) -> Result<DynamicMessage, ReflectError> {
+	let full_name = T::full_name();
+
+	let descriptor = pool
+		.get_message_by_name(&full_name)
+		.ok_or(ReflectError::MessageNotFound(full_name))?;
+
+	let mut message = DynamicMessage::new(descriptor);
+	message.transcode_from(value)?;
+
+	Ok(message)
+}
+
+/// Converts a [`DynamicMessage`] into the given message type, checking that the message names match.
+pub fn from_dynamic<T: Name + Default>(message: &DynamicMessage) -> Result<T, ReflectError> {
+	let expected = T::full_name();
+	let descriptor = message.descriptor();
+
+	if descriptor.full_name() != expected {
+		return Err(ReflectError::TypeMismatch {
+			expected,
+			found: descriptor.full_name().to_string(),
+		});
+	}
+
+	Ok(message.transcode_to()?)
+}
+
+/// Extension methods to set and extract the types of this crate on the fields of a [`DynamicMessage`].
+pub trait DynamicMessageExt {
+	/// Sets the value of a message field, such as a `google.protobuf.Timestamp` field.
+	fn set_message_field<T: Name>(&mut self, field: &str, value: &T) -> Result<(), ReflectError>;
+
+	/// Extracts the value of a message field, such as a `google.protobuf.Duration` field.
+	///
+	/// Returns `Ok(None)` if the field is not set.
+	fn get_message_field<T: Name + Default>(&self, field: &str) -> Result<Option<T>, ReflectError>;
+
+	/// Sets the value of a `google.protobuf.Timestamp` field.
+	#[inline]
+	fn set_timestamp(&mut self, field: &str, value: crate::Timestamp) -> Result<(), ReflectError> {
+		self.set_message_field(field, &value)
+	}
+
+	/// Extracts the value of a `google.protobuf.Timestamp` field.
+	#[inline]
+	fn get_timestamp(&self, field: &str) -> Result<Option<crate::Timestamp>, ReflectError> {
+		self.get_message_field(field)
+	}
+
+	/// Sets the value of a `google.protobuf.Duration` field.
+	#[inline]
+	fn set_duration(&mut self, field: &str, value: crate::Duration) -> Result<(), ReflectError> {
+		self.set_message_field(field, &value)
+	}
+
+	/// Extracts the value of a `google.protobuf.Duration` field.
+	#[inline]
+	fn get_duration(&self, field: &str) -> Result<Option<crate::Duration>, ReflectError> {
+		self.get_message_field(field)
+	}
+}
+
+impl DynamicMessageExt for DynamicMessage {
+	fn set_message_field<T: Name>(&mut self, field: &str, value: &T) -> Result<(), ReflectError> {
+		let (field_desc, message_desc) = message_field::<T>(self, field)?;
+
+		let mut message = Self::new(message_desc);
+		message.transcode_from(value)?;
+
+		self.set_field(&field_desc, Value::Message(message));
+
+		Ok(())
+	}
+
+	fn get_message_field<T: Name + Default>(&self, field: &str) -> Result<Option<T>, ReflectError> {
+		let (field_desc, _) = message_field::<T>(self, field)?;
+
+		if !self.has_field(&field_desc) {
+			return Ok(None);
+		}
+
+		self.get_field(&field_desc)
+			.as_message()
+			.map(from_dynamic)
+			.transpose()
+	}
+}
+
+fn message_field<T: Name>(
+	message: &DynamicMessage,
+	field: &str,
+) -> Result<(FieldDescriptor, MessageDescriptor), ReflectError> {
+	let field_desc = message
+		.descriptor()
+		.get_field_by_name(field)
+		.ok_or_else(|| ReflectError::FieldNotFound(field.to_string()))?;
+
+	let expected = T::full_name();
+
+	match field_desc.kind() {
+		Kind::Message(desc) if desc.full_name() == expected => Ok((field_desc, desc)),
+		Kind::Message(desc) => Err(ReflectError::TypeMismatch {
+			expected,
+			found: desc.full_name().to_string(),
+		}),
+		kind => Err(ReflectError::TypeMismatch {
+			expected,
+			found: format!("{kind:?}"),
+		}),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{Any, Duration, Timestamp};
+
+	#[test]
+	fn round_trip() {
+		let pool = DescriptorPool::global();
+		let timestamp = Timestamp::new(1_700_000_000, 42);
+
+		let dynamic = to_dynamic(&timestamp, &pool).unwrap();
+		assert_eq!(from_dynamic::<Timestamp>(&dynamic).unwrap(), timestamp);
+		assert!(matches!(
+			from_dynamic::<Duration>(&dynamic),
+			Err(ReflectError::TypeMismatch { .. })
+		));
+	}
+
+	#[test]
+	fn crate_descriptors() {
+		let mut pool = DescriptorPool::new();
+		register_well_known_types(&mut pool).unwrap();
+
+		let money = crate::Money::new("USD", 12, 340_000_000).unwrap();
+		let dynamic = to_dynamic(&money, &pool).unwrap();
+		assert_eq!(from_dynamic::<crate::Money>(&dynamic).unwrap(), money);
+
+		let status = crate::Status::builder(crate::Code::NotFound, "missing")
+			.any_detail(Any::from_msg(&Duration::new(5, 0)).unwrap())
+			.build();
+		let dynamic = to_dynamic(&status, &pool).unwrap();
+		assert_eq!(from_dynamic::<crate::Status>(&dynamic).unwrap(), status);
+
+		for name in [
+			"google.type.DateTime",
+			"google.rpc.BadRequest.FieldViolation",
+			"buf.validate.FieldRules",
+			"buf.validate.Violations",
+		] {
+			assert!(pool.get_message_by_name(name).is_some(), "{name}");
+		}
+
+		// Files that are already present are skipped
+		let files = pool.files().len();
+		register_well_known_types(&mut pool).unwrap();
+		assert_eq!(pool.files().len(), files);
+
+		let mut prefilled = DescriptorPool::global();
+		prefilled
+			.decode_file_descriptor_set(FILE_DESCRIPTOR_SET_BYTES)
+			.unwrap();
+		register_well_known_types(&mut prefilled).unwrap();
+		assert_eq!(prefilled.files().len(), files);
+	}
+
+	#[test]
+	fn message_fields() {
+		let pool = DescriptorPool::global();
+		let mut option = DynamicMessage::new(
+			pool.get_message_by_name("google.protobuf.Option")
+				.unwrap(),
+		);
+
+		assert_eq!(option.get_message_field::<Any>("value").unwrap(), None);
+
+		let any = Any::from_msg(&Duration::new(5, 0)).unwrap();
+		option.set_message_field("value", &any).unwrap();
+		assert_eq!(option.get_message_field("value").unwrap(), Some(any));
+
+		assert!(matches!(
+			option.set_timestamp("value", Timestamp::default()),
+			Err(ReflectError::TypeMismatch { .. })
+		));
+		assert!(matches!(
+			option.get_duration("name"),
+			Err(ReflectError::TypeMismatch { .. })
+		));
+		assert!(matches!(
+			option.get_duration("missing"),
+			Err(ReflectError::FieldNotFound(_))
+		));
+	}
+}
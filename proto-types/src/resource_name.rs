@@ -0,0 +1,267 @@
+//! Typed resource names in the style described by [AIP-122](https://google.aip.dev/122), such as `projects/my-project/locations/us-east1/books/1234`.
+//!
+//! The [`resource_name!`](crate::resource_name!) macro generates an opaque newtype for a given pattern,
+//! with a typed accessor for each variable segment, along with [`Display`](core::fmt::Display) and [`FromStr`](core::str::FromStr) implementations.
+//!
+//! # Examples
+//! ```rust
+//! proto_types::resource_name! {
+//!   /// The name of a book resource.
+//!   pub struct BookName = projects/{project}/locations/{location}/books/{book};
+//! }
+//!
+//! let name: BookName = "projects/p1/locations/us-east1/books/1234".parse().unwrap();
+//!
+//! assert_eq!(name.project(), "p1");
+//! assert_eq!(name.book(), "1234");
+//! assert_eq!(BookName::PATTERN, "projects/{project}/locations/{location}/books/{book}");
+//!
+//! let built = BookName::new("p1", "us-east1", "1234").unwrap();
+//! assert_eq!(built, name);
+//! assert_eq!(built.to_string(), "projects/p1/locations/us-east1/books/1234");
+//!
+//! assert!("projects/p1/books/1234".parse::<BookName>().is_err());
+//! assert!(BookName::new("p1", "us/east1", "1234").is_err());
+//! ```
+
+use thiserror::Error;
+
+use crate::{String, ToString};
+
+/// Errors that can occur while parsing or building a resource name.
+#[derive(Debug, Error, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum ResourceNameError {
+	#[error("Resource name '{name}' does not match the pattern '{pattern}'")]
+	PatternMismatch { pattern: &'static str, name: String },
+	#[error(
+		"Invalid value '{value}' for the segment '{segment}' (must be non-empty and cannot contain slashes, whitespace or control characters)"
+	)]
+	InvalidSegment {
+		segment: &'static str,
+		value: String,
+	},
+}
+
+/// Checks that the value of a variable segment is valid.
+///
+/// Segments must be non-empty and cannot contain slashes, whitespace or control characters.
+#[must_use]
+pub fn is_valid_segment(value: &str) -> bool {
+	!value.is_empty()
+		&& !value
+			.chars()
+			.any(|c| c == '/' || c.is_whitespace() || c.is_control())
+}
+
+/// Validates the value of the given variable segment.
+#[doc(hidden)]
+pub fn validate_segment(segment: &'static str, value: &str) -> Result<(), ResourceNameError> {
+	if is_valid_segment(value) {
+		Ok(())
+	} else {
+		Err(ResourceNameError::InvalidSegment {
+			segment,
+			value: value.to_string(),
+		})
+	}
+}
+
+/// Splits a resource name into the values of its variable segments, checking that the collection identifiers match the given ones.
+#[doc(hidden)]
+pub fn parse_segments<'a, const N: usize>(
+	pattern: &'static str,
+	collections: [&'static str; N],
+	variables: [&'static str; N],
+	name: &'a str,
+) -> Result<[&'a str; N], ResourceNameError> {
+	let mismatch = || ResourceNameError::PatternMismatch {
+		pattern,
+		name: name.to_string(),
+	};
+
+	let mut parts = name.split('/');
+	let mut values = [""; N];
+
+	for (i, collection) in collections.into_iter().enumerate() {
+		if parts.next() != Some(collection) {
+			return Err(mismatch());
+		}
+
+		let value = parts.next().ok_or_else(mismatch)?;
+		validate_segment(variables[i], value)?;
+		values[i] = value;
+	}
+
+	if parts.next().is_some() {
+		return Err(mismatch());
+	}
+
+	Ok(values)
+}
+
+/// Generates an opaque newtype for a resource name with the given [AIP-122](https://google.aip.dev/122) pattern.
+///
+/// The pattern is made of alternating collection identifiers and `{variable}` segments.
+/// The generated struct has:
+///
+/// - A `PATTERN` constant with the pattern as a string.
+/// - A `new` constructor, which takes the value of each variable in order and validates it.
+/// - An accessor for each variable, named after it.
+/// - [`Display`](core::fmt::Display), [`FromStr`](core::str::FromStr) and `TryFrom<&str>` implementations.
+///
+/// See the [`resource_name`](crate::resource_name) module for an example.
+#[macro_export]
+macro_rules! resource_name {
+	(
+		$(#[$meta:meta])*
+		$vis:vis struct $name:ident = $($collection:ident / { $var:ident })/+ ;
+	) => {
+		$(#[$meta])*
+		#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+		$vis struct $name {
+			$($var: $crate::resource_name::__private::String,)+
+		}
+
+		impl $name {
+			/// The pattern of this resource name.
+			pub const PATTERN: &'static str = $crate::resource_name::__private::trim_start_slash(
+				::core::concat!($("/", ::core::stringify!($collection), "/{", ::core::stringify!($var), "}"),+)
+			);
+
+			/// Creates a new instance, validating the value of each segment.
+			pub fn new(
+				$($var: impl ::core::convert::Into<$crate::resource_name::__private::String>),+
+			) -> ::core::result::Result<Self, $crate::resource_name::ResourceNameError> {
+				$(
+					let $var = $var.into();
+					$crate::resource_name::validate_segment(::core::stringify!($var), &$var)?;
+				)+
+
+				::core::result::Result::Ok(Self { $($var),+ })
+			}
+
+			$(
+				#[doc = ::core::concat!("Returns the value of the `", ::core::stringify!($var), "` segment.")]
+				#[must_use]
+				#[inline]
+				pub fn $var(&self) -> &str {
+					&self.$var
+				}
+			)+
+		}
+
+		impl ::core::fmt::Display for $name {
+			fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+				let mut separator = "";
+
+				$(
+					::core::write!(f, "{separator}{}/{}", ::core::stringify!($collection), self.$var)?;
+					separator = "/";
+				)+
+
+				let _ = separator;
+				::core::result::Result::Ok(())
+			}
+		}
+
+		impl ::core::str::FromStr for $name {
+			type Err = $crate::resource_name::ResourceNameError;
+
+			fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+				let [$($var),+] = $crate::resource_name::parse_segments(
+					Self::PATTERN,
+					[$(::core::stringify!($collection)),+],
+					[$(::core::stringify!($var)),+],
+					s,
+				)?;
+
+				::core::result::Result::Ok(Self {
+					$($var: $var.into()),+
+				})
+			}
+		}
+
+		impl ::core::convert::TryFrom<&str> for $name {
+			type Error = $crate::resource_name::ResourceNameError;
+
+			#[inline]
+			fn try_from(value: &str) -> ::core::result::Result<Self, Self::Error> {
+				value.parse()
+			}
+		}
+	};
+}
+
+#[doc(hidden)]
+pub mod __private {
+	pub use alloc::string::String;
+
+	/// Removes the leading slash produced by the `concat!` in the [`resource_name!`](crate::resource_name!) macro.
+	#[must_use]
+	pub const fn trim_start_slash(pattern: &'static str) -> &'static str {
+		match pattern.as_bytes() {
+			[b'/', rest @ ..] => match core::str::from_utf8(rest) {
+				Ok(trimmed) => trimmed,
+				Err(_) => pattern,
+			},
+			_ => pattern,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	crate::resource_name! {
+		struct TopicName = projects/{project}/topics/{topic};
+	}
+
+	crate::resource_name! {
+		struct ProjectName = projects/{project};
+	}
+
+	#[test]
+	#[allow(clippy::literal_string_with_formatting_args)]
+	fn round_trip() {
+		let name: TopicName = "projects/p1/topics/orders".parse().unwrap();
+
+		assert_eq!(name.project(), "p1");
+		assert_eq!(name.topic(), "orders");
+		assert_eq!(name.to_string(), "projects/p1/topics/orders");
+		assert_eq!(TopicName::PATTERN, "projects/{project}/topics/{topic}");
+
+		let project = ProjectName::try_from("projects/p1").unwrap();
+		assert_eq!(project.project(), "p1");
+		assert_eq!(project, ProjectName::new("p1").unwrap());
+	}
+
+	#[test]
+	fn invalid_names() {
+		for name in [
+			"",
+			"projects/p1",
+			"projects/p1/topics",
+			"projects/p1/subscriptions/orders",
+			"projects/p1/topics/orders/extra",
+		] {
+			assert!(
+				matches!(
+					name.parse::<TopicName>(),
+					Err(ResourceNameError::PatternMismatch { .. })
+				),
+				"{name}"
+			);
+		}
+
+		assert_eq!(
+			"projects//topics/orders".parse::<TopicName>(),
+			Err(ResourceNameError::InvalidSegment {
+				segment: "project",
+				value: String::new(),
+			})
+		);
+		assert!(TopicName::new("p1", "has space").is_err());
+	}
+}
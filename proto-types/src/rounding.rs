@@ -0,0 +1,66 @@
+/// The rounding strategy used when a value has to be aligned to a coarser unit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum RoundingMode {
+	/// Rounds towards negative infinity.
+	#[default]
+	Floor,
+	/// Rounds towards positive infinity.
+	Ceil,
+	/// Rounds towards zero.
+	TowardZero,
+	/// Rounds to the nearest value, and ties to the even one (banker's rounding).
+	HalfEven,
+}
+
+impl RoundingMode {
+	/// Divides `numerator` by `denominator` (which must be positive), rounding the quotient according to this mode.
+	pub(crate) const fn div(self, numerator: i128, denominator: i128) -> i128 {
+		let quotient = numerator.div_euclid(denominator);
+		let remainder = numerator.rem_euclid(denominator);
+
+		if remainder == 0 {
+			return quotient;
+		}
+
+		match self {
+			Self::Floor => quotient,
+			Self::Ceil => quotient + 1,
+			Self::TowardZero => {
+				if numerator < 0 {
+					quotient + 1
+				} else {
+					quotient
+				}
+			}
+			Self::HalfEven => {
+				let doubled = remainder * 2;
+
+				if doubled < denominator {
+					quotient
+				} else if doubled > denominator {
+					quotient + 1
+				} else {
+					quotient + (quotient & 1)
+				}
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn division_modes() {
+		assert_eq!(RoundingMode::Floor.div(-7, 2), -4);
+		assert_eq!(RoundingMode::Ceil.div(-7, 2), -3);
+		assert_eq!(RoundingMode::TowardZero.div(-7, 2), -3);
+		assert_eq!(RoundingMode::TowardZero.div(7, 2), 3);
+		assert_eq!(RoundingMode::HalfEven.div(5, 2), 2);
+		assert_eq!(RoundingMode::HalfEven.div(7, 2), 4);
+		assert_eq!(RoundingMode::HalfEven.div(-5, 2), -2);
+		assert_eq!(RoundingMode::Ceil.div(6, 2), 3);
+	}
+}
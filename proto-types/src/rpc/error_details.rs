@@ -1,8 +1,45 @@
-use crate::rpc::{
-	ErrorInfo, LocalizedMessage, RequestInfo, ResourceInfo, bad_request::FieldViolation,
-	precondition_failure, quota_failure,
+use prost::Name;
+
+use crate::{
+	String,
+	rpc::{
+		BadRequest, DebugInfo, ErrorInfo, Help, LocalizedMessage, PreconditionFailure,
+		QuotaFailure, RequestInfo, ResourceInfo, RetryInfo, Status, bad_request::FieldViolation,
+		precondition_failure, quota_failure,
+	},
+	type_url_for,
 };
 
+macro_rules! impl_name {
+	($($name:ident),*) => {
+		$(
+			impl Name for $name {
+				const PACKAGE: &'static str = "google.rpc";
+
+				const NAME: &'static str = stringify!($name);
+
+				fn type_url() -> String {
+					type_url_for::<Self>()
+				}
+			}
+		)*
+	};
+}
+
+impl_name!(
+	Status,
+	ErrorInfo,
+	RetryInfo,
+	DebugInfo,
+	QuotaFailure,
+	PreconditionFailure,
+	BadRequest,
+	RequestInfo,
+	ResourceInfo,
+	Help,
+	LocalizedMessage
+);
+
 impl ErrorInfo {
 	has_impl!(reason);
 	has_impl!(domain);
@@ -0,0 +1,261 @@
+use base64::{
+	Engine,
+	alphabet::STANDARD,
+	engine::{DecodePaddingMode, GeneralPurpose, GeneralPurposeConfig},
+};
+use prost::Message;
+use thiserror::Error;
+
+use crate::{
+	HttpHeader, String, ToString, Vec,
+	rpc::{Code, Status},
+};
+
+/// The trailer that contains the numeric status code.
+pub const GRPC_STATUS: &str = "grpc-status";
+/// The trailer that contains the percent-encoded status message.
+pub const GRPC_MESSAGE: &str = "grpc-message";
+/// The trailer that contains the base64-encoded [`Status`] message, with its details.
+pub const GRPC_STATUS_DETAILS_BIN: &str = "grpc-status-details-bin";
+
+/// Binary headers are sent without padding, but both forms must be accepted when decoding.
+const BINARY_HEADER_ENGINE: GeneralPurpose = GeneralPurpose::new(
+	&STANDARD,
+	GeneralPurposeConfig::new()
+		.with_encode_padding(false)
+		.with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
+
+/// Errors that can occur while reading a [`Status`] from gRPC trailers.
+#[derive(Debug, Error, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum GrpcTrailersError {
+	#[error("The grpc-status trailer is missing")]
+	MissingStatus,
+	#[error("Invalid grpc-status trailer: {0:?}")]
+	InvalidStatus(String),
+	#[error("Invalid grpc-status-details-bin trailer: {0}")]
+	InvalidDetails(String),
+}
+
+/// Percent-encodes a status message, as required for the `grpc-message` trailer.
+///
+/// All the bytes outside of the printable ASCII range, and the `%` character itself, are encoded as `%XX`.
+fn percent_encode(message: &str) -> String {
+	const HEX: &[u8; 16] = b"0123456789ABCDEF";
+
+	let mut encoded = String::with_capacity(message.len());
+
+	for byte in message.bytes() {
+		if (0x20..=0x7e).contains(&byte) && byte != b'%' {
+			encoded.push(char::from(byte));
+		} else {
+			encoded.push('%');
+			encoded.push(char::from(HEX[usize::from(byte >> 4)]));
+			encoded.push(char::from(HEX[usize::from(byte & 0xf)]));
+		}
+	}
+
+	encoded
+}
+
+/// Decodes a percent-encoded status message.
+///
+/// As required by the gRPC spec, invalid sequences are kept as they are, and invalid UTF-8 is replaced rather than rejected.
+fn percent_decode(encoded: &str) -> String {
+	let bytes = encoded.as_bytes();
+	let mut decoded = Vec::with_capacity(bytes.len());
+	let mut i = 0;
+
+	while i < bytes.len() {
+		let escaped = (bytes[i] == b'%')
+			.then(|| bytes.get(i + 1..i + 3))
+			.flatten()
+			.and_then(|hex| core::str::from_utf8(hex).ok())
+			.and_then(|hex| u8::from_str_radix(hex, 16).ok());
+
+		if let Some(byte) = escaped {
+			decoded.push(byte);
+			i += 3;
+		} else {
+			decoded.push(bytes[i]);
+			i += 1;
+		}
+	}
+
+	String::from_utf8_lossy(&decoded).into_owned()
+}
+
+impl Status {
+	/// Renders the status into the trailers sent at the end of a gRPC response.
+	///
+	/// The message is only included if it is not empty, and the details are sent in `grpc-status-details-bin` (as the whole encoded [`Status`]) only if there are any.
+	///
+	/// # Examples
+	/// ```rust
+	/// use proto_types::{Code, Status};
+	///
+	/// let status = Status::builder(Code::NotFound, "user 42: not found (100%)").build();
+	/// let trailers = status.to_grpc_trailers();
+	///
+	/// assert_eq!(trailers[0].value, "5");
+	/// assert_eq!(trailers[1].value, "user 42: not found (100%25)");
+	/// assert_eq!(Status::from_grpc_trailers(&trailers).unwrap(), status);
+	/// ```
+	#[must_use]
+	pub fn to_grpc_trailers(&self) -> Vec<HttpHeader> {
+		let mut trailers = Vec::with_capacity(3);
+
+		trailers.push(HttpHeader {
+			key: GRPC_STATUS.to_string(),
+			value: self.code.to_string(),
+		});
+
+		if !self.message.is_empty() {
+			trailers.push(HttpHeader {
+				key: GRPC_MESSAGE.to_string(),
+				value: percent_encode(&self.message),
+			});
+		}
+
+		if !self.details.is_empty() {
+			trailers.push(HttpHeader {
+				key: GRPC_STATUS_DETAILS_BIN.to_string(),
+				value: BINARY_HEADER_ENGINE.encode(self.encode_to_vec()),
+			});
+		}
+
+		trailers
+	}
+
+	/// Reads a status from the trailers of a gRPC response. Header names are matched case-insensitively, and unrelated headers are ignored.
+	///
+	/// The code and message are taken from `grpc-status` and `grpc-message`, while the details (if present) are taken from `grpc-status-details-bin`.
+	pub fn from_grpc_trailers<'a>(
+		trailers: impl IntoIterator<Item = &'a HttpHeader>,
+	) -> Result<Self, GrpcTrailersError> {
+		let mut code = None;
+		let mut message = String::new();
+		let mut details = Vec::new();
+
+		for header in trailers {
+			if header.key.eq_ignore_ascii_case(GRPC_STATUS) {
+				code = Some(
+					header
+						.value
+						.trim()
+						.parse::<i32>()
+						.map_err(|_| GrpcTrailersError::InvalidStatus(header.value.clone()))?,
+				);
+			} else if header.key.eq_ignore_ascii_case(GRPC_MESSAGE) {
+				message = percent_decode(&header.value);
+			} else if header
+				.key
+				.eq_ignore_ascii_case(GRPC_STATUS_DETAILS_BIN)
+			{
+				let bytes = BINARY_HEADER_ENGINE
+					.decode(header.value.trim())
+					.map_err(|e| GrpcTrailersError::InvalidDetails(e.to_string()))?;
+
+				details = Self::decode(bytes.as_slice())
+					.map_err(|e| GrpcTrailersError::InvalidDetails(e.to_string()))?
+					.details;
+			}
+		}
+
+		Ok(Self {
+			code: code.ok_or(GrpcTrailersError::MissingStatus)?,
+			message,
+			details,
+		})
+	}
+}
+
+impl Code {
+	/// Returns the code for a response that did not include a `grpc-status`, based on its HTTP status, as described in the [gRPC spec](https://github.com/grpc/grpc/blob/master/doc/http-grpc-status-mapping.md).
+	#[must_use]
+	#[inline]
+	pub const fn from_http_status(status: u16) -> Self {
+		match status {
+			400 => Self::Internal,
+			401 => Self::Unauthenticated,
+			403 => Self::PermissionDenied,
+			404 => Self::Unimplemented,
+			429 | 502..=504 => Self::Unavailable,
+			_ => Self::Unknown,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{Any, Duration};
+
+	fn header(key: &str, value: &str) -> HttpHeader {
+		HttpHeader {
+			key: key.to_string(),
+			value: value.to_string(),
+		}
+	}
+
+	#[test]
+	fn message_encoding() {
+		assert_eq!(percent_encode("a%b\nc"), "a%25b%0Ac");
+		assert_eq!(percent_encode("résumé"), "r%C3%A9sum%C3%A9");
+
+		assert_eq!(percent_decode("r%C3%A9sum%C3%A9"), "résumé");
+		assert_eq!(percent_decode("100%"), "100%");
+		assert_eq!(percent_decode("%zz%4"), "%zz%4");
+		assert_eq!(percent_decode("%FF"), "\u{FFFD}");
+	}
+
+	#[test]
+	fn round_trip_with_details() {
+		let status = Status::builder(Code::InvalidArgument, "bad input: ✓ 50%")
+			.any_detail(Any::from_msg(&Duration::new(5, 0)).unwrap())
+			.build();
+
+		let trailers = status.to_grpc_trailers();
+
+		assert_eq!(trailers.len(), 3);
+		assert_eq!(trailers[0].value, "3");
+		assert!(!trailers[2].value.ends_with('='));
+		assert_eq!(Status::from_grpc_trailers(&trailers), Ok(status.clone()));
+
+		// Padded base64 and mixed case header names are accepted too
+		let padded = [
+			header("Grpc-Status", "3"),
+			header("grpc-message", &trailers[1].value),
+			header(
+				"grpc-status-details-bin",
+				&base64::prelude::BASE64_STANDARD.encode(status.encode_to_vec()),
+			),
+			header("content-type", "application/grpc"),
+		];
+		assert_eq!(Status::from_grpc_trailers(&padded), Ok(status));
+	}
+
+	#[test]
+	fn invalid_trailers() {
+		assert_eq!(
+			Status::from_grpc_trailers(&[header(GRPC_MESSAGE, "oops")]),
+			Err(GrpcTrailersError::MissingStatus)
+		);
+		assert_eq!(
+			Status::from_grpc_trailers(&[header(GRPC_STATUS, "five")]),
+			Err(GrpcTrailersError::InvalidStatus("five".into()))
+		);
+		assert!(matches!(
+			Status::from_grpc_trailers(&[
+				header(GRPC_STATUS, "2"),
+				header(GRPC_STATUS_DETAILS_BIN, "!!")
+			]),
+			Err(GrpcTrailersError::InvalidDetails(_))
+		));
+
+		assert_eq!(Code::from_http_status(404), Code::Unimplemented);
+		assert_eq!(Code::from_http_status(503), Code::Unavailable);
+		assert_eq!(Code::from_http_status(418), Code::Unknown);
+	}
+}
@@ -33,6 +33,21 @@ mod error_details;
 #[cfg(feature = "rpc")]
 mod http;
 
+#[cfg(feature = "rpc")]
+mod grpc_trailers;
+#[cfg(feature = "rpc")]
+pub use grpc_trailers::{GRPC_MESSAGE, GRPC_STATUS, GRPC_STATUS_DETAILS_BIN, GrpcTrailersError};
+
+#[cfg(feature = "rpc")]
+mod retry;
+#[cfg(feature = "rpc")]
+pub use retry::Backoff;
+
+#[cfg(feature = "rpc")]
+mod status_builder;
+#[cfg(feature = "rpc")]
+pub use status_builder::StatusBuilder;
+
 #[cfg(all(feature = "cel", feature = "rpc"))]
 mod rpc_cel_impls;
 
@@ -0,0 +1,143 @@
+use crate::{Duration, rpc::Code};
+
+/// An exponential backoff configuration, used to describe how a failed request should be retried.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Backoff {
+	/// The delay to wait before the first retry.
+	pub initial_delay: Duration,
+	/// The upper bound for the delay between retries.
+	pub max_delay: Duration,
+	/// The factor by which the delay grows after each attempt.
+	pub multiplier: u32,
+	/// The maximum number of retries to attempt.
+	pub max_attempts: u32,
+}
+
+impl Backoff {
+	/// Creates a new instance.
+	#[must_use]
+	#[inline]
+	pub const fn new(
+		initial_delay: Duration,
+		max_delay: Duration,
+		multiplier: u32,
+		max_attempts: u32,
+	) -> Self {
+		Self {
+			initial_delay,
+			max_delay,
+			multiplier,
+			max_attempts,
+		}
+	}
+
+	/// Returns the delay to wait before the given retry attempt (starting from 0), capped at `max_delay`.
+	///
+	/// Returns `None` if the attempt exceeds `max_attempts`.
+	#[must_use]
+	pub fn delay_for_attempt(&self, attempt: u32) -> Option<Duration> {
+		if attempt >= self.max_attempts {
+			return None;
+		}
+
+		let delay = i64::from(self.multiplier)
+			.checked_pow(attempt)
+			.and_then(|factor| self.initial_delay.checked_mul(factor))
+			.map_or(self.max_delay, |delay| delay.min(self.max_delay));
+
+		Some(delay)
+	}
+}
+
+const fn millis(millis: i32) -> Duration {
+	Duration {
+		seconds: 0,
+		nanos: millis * 1_000_000,
+	}
+}
+
+const fn secs(seconds: i64) -> Duration {
+	Duration { seconds, nanos: 0 }
+}
+
+impl Code {
+	/// Returns `true` if a request that failed with this code can be retried, according to the gRPC guidelines.
+	///
+	/// This includes `Unavailable`, `DeadlineExceeded`, `ResourceExhausted` and `Aborted`. Keep in mind that
+	/// it is not always safe to retry non-idempotent operations.
+	#[must_use]
+	#[inline]
+	pub const fn is_retryable(&self) -> bool {
+		matches!(
+			self,
+			Self::Unavailable | Self::DeadlineExceeded | Self::ResourceExhausted | Self::Aborted
+		)
+	}
+
+	/// Returns `true` if the code indicates a problem with the request (mapped to a 4xx HTTP status).
+	#[must_use]
+	#[inline]
+	pub const fn is_client_error(&self) -> bool {
+		matches!(self.to_http_status(), 400..=499)
+	}
+
+	/// Returns `true` if the code indicates a problem with the server (mapped to a 5xx HTTP status).
+	#[must_use]
+	#[inline]
+	pub const fn is_server_error(&self) -> bool {
+		matches!(self.to_http_status(), 500..=599)
+	}
+
+	/// Returns the default [`Backoff`] policy for this code, or `None` if the code should not be retried.
+	#[must_use]
+	pub const fn default_retry_policy(&self) -> Option<Backoff> {
+		match self {
+			Self::Unavailable => Some(Backoff::new(millis(100), secs(10), 2, 5)),
+			Self::DeadlineExceeded => Some(Backoff::new(millis(200), secs(10), 2, 3)),
+			Self::ResourceExhausted => Some(Backoff::new(secs(1), secs(60), 2, 5)),
+			Self::Aborted => Some(Backoff::new(millis(50), secs(1), 2, 3)),
+			_ => None,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn backoff_delays() {
+		let backoff = Backoff::new(millis(100), millis(500), 2, 5);
+
+		assert_eq!(backoff.delay_for_attempt(0), Some(millis(100)));
+		assert_eq!(backoff.delay_for_attempt(1), Some(millis(200)));
+		assert_eq!(backoff.delay_for_attempt(2), Some(millis(400)));
+		assert_eq!(backoff.delay_for_attempt(3), Some(millis(500)));
+		assert_eq!(backoff.delay_for_attempt(5), None);
+	}
+
+	#[test]
+	fn backoff_overflow_caps_at_max() {
+		let backoff = Backoff::new(secs(1), secs(30), u32::MAX, 10);
+
+		assert_eq!(backoff.delay_for_attempt(9), Some(secs(30)));
+	}
+
+	#[test]
+	fn code_classification() {
+		assert!(Code::Unavailable.is_retryable());
+		assert!(!Code::InvalidArgument.is_retryable());
+
+		assert!(Code::NotFound.is_client_error());
+		assert!(!Code::NotFound.is_server_error());
+		assert!(Code::Internal.is_server_error());
+		assert!(!Code::Ok.is_client_error() && !Code::Ok.is_server_error());
+
+		assert!(Code::Unavailable.default_retry_policy().is_some());
+		assert!(
+			Code::PermissionDenied
+				.default_retry_policy()
+				.is_none()
+		);
+	}
+}
@@ -0,0 +1,180 @@
+use prost::{Message, Name};
+
+use crate::{
+	Any, String, Vec,
+	rpc::{Code, Status},
+};
+
+/// A builder for [`Status`] messages, which takes care of packing the error details into [`Any`] messages.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StatusBuilder {
+	code: Code,
+	message: String,
+	details: Vec<Any>,
+}
+
+impl Status {
+	/// Creates a new [`StatusBuilder`] with the given code and message.
+	#[must_use]
+	#[inline]
+	pub fn builder(code: Code, message: impl Into<String>) -> StatusBuilder {
+		StatusBuilder::new(code, message)
+	}
+
+	/// Encodes the message into a byte-stable form, suitable for content hashing and deduplication of repeated errors.
+	///
+	/// The details are sorted by their type url (and then by their payload), so that the same set of details always produces the same bytes regardless of the order in which they were added.
+	#[must_use]
+	pub fn encode_canonical(&self) -> Vec<u8> {
+		let mut canonical = self.clone();
+		canonical.details.sort();
+		canonical.encode_to_vec()
+	}
+}
+
+impl StatusBuilder {
+	/// Creates a new instance.
+	#[must_use]
+	#[inline]
+	pub fn new(code: Code, message: impl Into<String>) -> Self {
+		Self {
+			code,
+			message: message.into(),
+			details: Vec::new(),
+		}
+	}
+
+	/// Packs the given message into an [`Any`] and adds it to the details.
+	#[must_use]
+	pub fn detail<M: Name>(mut self, detail: &M) -> Self {
+		self.details.push(Any {
+			type_url: M::type_url(),
+			value: detail.encode_to_vec(),
+		});
+		self
+	}
+
+	/// Adds an already packed [`Any`] message to the details.
+	#[must_use]
+	pub fn any_detail(mut self, detail: Any) -> Self {
+		self.details.push(detail);
+		self
+	}
+
+	/// Builds the [`Status`].
+	#[must_use]
+	pub fn build(self) -> Status {
+		Status {
+			code: self.code as i32,
+			message: self.message,
+			details: self.details,
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+mod stamp {
+	use super::StatusBuilder;
+	use crate::{
+		Timestamp, format,
+		rpc::{RequestInfo, Status},
+	};
+
+	const OCCURRED_AT_PREFIX: &str = "occurred_at=";
+
+	impl StatusBuilder {
+		/// Attaches a [`RequestInfo`] detail containing the current time and an optional request id.
+		///
+		/// The timestamp is stored in the `serving_data` field as `occurred_at=<RFC 3339 timestamp>`,
+		/// and can be retrieved with [`Status::occurred_at`].
+		#[must_use]
+		pub fn stamp_now(self, request_id: Option<&str>) -> Self {
+			self.stamp_at(Timestamp::now(), request_id)
+		}
+
+		/// Same as [`stamp_now`](Self::stamp_now), but with a custom timestamp.
+		#[must_use]
+		pub fn stamp_at(self, timestamp: Timestamp, request_id: Option<&str>) -> Self {
+			let info = RequestInfo {
+				request_id: request_id.unwrap_or_default().into(),
+				serving_data: format!("{OCCURRED_AT_PREFIX}{timestamp}"),
+			};
+
+			self.detail(&info)
+		}
+	}
+
+	impl Status {
+		/// Returns the occurrence time attached by [`StatusBuilder::stamp_now`], if present.
+		#[must_use]
+		pub fn occurred_at(&self) -> Option<Timestamp> {
+			self.details
+				.iter()
+				.filter_map(|detail| detail.to_msg::<RequestInfo>().ok())
+				.find_map(|info| {
+					info.serving_data
+						.strip_prefix(OCCURRED_AT_PREFIX)?
+						.parse()
+						.ok()
+				})
+		}
+	}
+
+	#[cfg(test)]
+	mod tests {
+		use super::*;
+		use crate::rpc::Code;
+
+		#[test]
+		fn stamped_status_round_trip() {
+			let timestamp = Timestamp::new(1_700_000_000, 500);
+
+			let status = Status::builder(Code::Internal, "boom")
+				.stamp_at(timestamp, Some("req-1"))
+				.build();
+
+			assert_eq!(status.code, Code::Internal as i32);
+			assert_eq!(status.occurred_at(), Some(timestamp));
+
+			let info: RequestInfo = status.details[0].to_msg().unwrap();
+			assert_eq!(info.request_id, "req-1");
+		}
+
+		#[test]
+		fn unstamped_status() {
+			let status = Status::builder(Code::Internal, "boom").build();
+
+			assert_eq!(status.occurred_at(), None);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::rpc::{ErrorInfo, RequestInfo};
+
+	#[test]
+	fn canonical_encoding_ignores_detail_order() {
+		let info = ErrorInfo {
+			reason: "QUOTA".into(),
+			..Default::default()
+		};
+		let request = RequestInfo {
+			request_id: "req-1".into(),
+			..Default::default()
+		};
+
+		let a = Status::builder(Code::Unavailable, "busy")
+			.detail(&info)
+			.detail(&request)
+			.build();
+		let b = Status::builder(Code::Unavailable, "busy")
+			.detail(&request)
+			.detail(&info)
+			.build();
+
+		assert_ne!(a.encode_to_vec(), b.encode_to_vec());
+		assert_eq!(a.encode_canonical(), b.encode_canonical());
+	}
+}
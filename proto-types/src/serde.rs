@@ -0,0 +1,276 @@
+//! Serde adapters for configuration files (such as YAML or TOML), which accept more lenient representations than the canonical proto3 JSON mapping.
+//!
+//! They are meant to be used with `#[serde(with = "...")]`, and they serialize values in their canonical form.
+//!
+//! # Examples
+//! ```rust
+//! use proto_types::{Duration, Timestamp};
+//! use serde::Deserialize;
+//!
+//! #[derive(Deserialize)]
+//! struct Config {
+//!   #[serde(with = "proto_types::serde::flexible_duration")]
+//!   timeout: Duration,
+//!   #[serde(with = "proto_types::serde::flexible_duration")]
+//!   retry_after: Duration,
+//!   #[serde(with = "proto_types::serde::flexible_timestamp")]
+//!   not_before: Timestamp,
+//! }
+//!
+//! let config: Config = serde_json::from_str(
+//!   r#"{ "timeout": 30, "retry_after": "1m30s", "not_before": "2024-05-01T10:00:00Z" }"#,
+//! )
+//! .unwrap();
+//!
+//! assert_eq!(config.timeout, Duration::new(30, 0));
+//! assert_eq!(config.retry_after, Duration::new(90, 0));
+//! ```
+
+use core::fmt;
+
+use ::serde::de::{Error, Visitor};
+
+use crate::{Duration, String, Timestamp, ToString, format, macros};
+
+/// Parses a duration in the proto3 JSON format (`1.5s`) or in a human-friendly format with units (`1h 30m`, `250ms`).
+fn parse_flexible_duration(s: &str) -> Option<Duration> {
+	if let Ok(duration) = s.parse() {
+		return Some(duration);
+	}
+
+	if s.contains(char::is_whitespace) {
+		let compact: String = s.chars().filter(|c| !c.is_whitespace()).collect();
+
+		macros::parse_duration(&compact)
+	} else {
+		macros::parse_duration(s)
+	}
+}
+
+/// Converts a fractional number of seconds into a [`Duration`], truncating it to whole nanoseconds.
+fn duration_from_secs_f64(seconds: f64) -> Option<Duration> {
+	if !seconds.is_finite() {
+		return None;
+	}
+
+	// SAFETY: The cast saturates, and out of range values are rejected by from_total_nanos
+	#[allow(clippy::cast_possible_truncation)]
+	let nanos = (seconds * 1e9) as i128;
+
+	Duration::from_total_nanos(nanos)
+}
+
+struct FlexibleDurationVisitor;
+
+impl Visitor<'_> for FlexibleDurationVisitor {
+	type Value = Duration;
+
+	fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+		formatter
+			.write_str("a number of seconds, or a duration string such as \"30s\" or \"1h30m\"")
+	}
+
+	fn visit_i64<E: Error>(self, v: i64) -> Result<Self::Value, E> {
+		Ok(Duration::const_from_secs(v))
+	}
+
+	fn visit_u64<E: Error>(self, v: u64) -> Result<Self::Value, E> {
+		i64::try_from(v)
+			.map(Duration::const_from_secs)
+			.map_err(|_| E::custom(format!("Duration of {v} seconds is out of range")))
+	}
+
+	fn visit_f64<E: Error>(self, v: f64) -> Result<Self::Value, E> {
+		duration_from_secs_f64(v)
+			.ok_or_else(|| E::custom(format!("Duration of {v} seconds is out of range")))
+	}
+
+	fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+		parse_flexible_duration(v).ok_or_else(|| E::custom(format!("Invalid duration: {v:?}")))
+	}
+}
+
+struct FlexibleTimestampVisitor;
+
+impl Visitor<'_> for FlexibleTimestampVisitor {
+	type Value = Timestamp;
+
+	fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+		formatter
+			.write_str("a number of seconds since the unix epoch, or an RFC 3339 timestamp string")
+	}
+
+	fn visit_i64<E: Error>(self, v: i64) -> Result<Self::Value, E> {
+		Ok(Timestamp::from_jwt_numeric_date(v))
+	}
+
+	fn visit_u64<E: Error>(self, v: u64) -> Result<Self::Value, E> {
+		i64::try_from(v)
+			.map(Timestamp::from_jwt_numeric_date)
+			.map_err(|_| E::custom(format!("Timestamp of {v} seconds is out of range")))
+	}
+
+	fn visit_f64<E: Error>(self, v: f64) -> Result<Self::Value, E> {
+		Timestamp::from_jwt_numeric_date_f64(v).map_err(|e| E::custom(e.to_string()))
+	}
+
+	fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+		v.parse()
+			.map_err(|_| E::custom(format!("Invalid timestamp: {v:?}")))
+	}
+}
+
+/// (De)serializes a [`Duration`] from either a number of seconds (integer or fractional) or a string.
+///
+/// Strings can be in the proto3 JSON format (`1.5s`) or contain one or more amounts with a unit (`d`, `h`, `m`, `s`, `ms`, `us` or `ns`), such as `1h30m` or `1h 30m`.
+/// Durations are always serialized in the proto3 JSON format.
+pub mod flexible_duration {
+	use ::serde::{Deserializer, Serialize, Serializer};
+
+	use super::FlexibleDurationVisitor;
+	use crate::Duration;
+
+	pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+		duration.serialize(serializer)
+	}
+
+	pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+		deserializer.deserialize_any(FlexibleDurationVisitor)
+	}
+
+	/// The same as [`flexible_duration`](super::flexible_duration), for optional fields.
+	pub mod option {
+		use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+		use crate::Duration;
+
+		pub fn serialize<S: Serializer>(
+			duration: &Option<Duration>,
+			serializer: S,
+		) -> Result<S::Ok, S::Error> {
+			duration.serialize(serializer)
+		}
+
+		pub fn deserialize<'de, D: Deserializer<'de>>(
+			deserializer: D,
+		) -> Result<Option<Duration>, D::Error> {
+			#[derive(Deserialize)]
+			struct Wrapper(#[serde(with = "super")] Duration);
+
+			Ok(Option::<Wrapper>::deserialize(deserializer)?.map(|Wrapper(duration)| duration))
+		}
+	}
+}
+
+/// (De)serializes a [`Timestamp`] from either a number of seconds since the unix epoch (integer or fractional) or an RFC 3339 string.
+///
+/// Timestamps are always serialized as RFC 3339 strings.
+pub mod flexible_timestamp {
+	use ::serde::{Deserializer, Serialize, Serializer};
+
+	use super::FlexibleTimestampVisitor;
+	use crate::Timestamp;
+
+	pub fn serialize<S: Serializer>(
+		timestamp: &Timestamp,
+		serializer: S,
+	) -> Result<S::Ok, S::Error> {
+		timestamp.serialize(serializer)
+	}
+
+	pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Timestamp, D::Error> {
+		deserializer.deserialize_any(FlexibleTimestampVisitor)
+	}
+
+	/// The same as [`flexible_timestamp`](super::flexible_timestamp), for optional fields.
+	pub mod option {
+		use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+		use crate::Timestamp;
+
+		pub fn serialize<S: Serializer>(
+			timestamp: &Option<Timestamp>,
+			serializer: S,
+		) -> Result<S::Ok, S::Error> {
+			timestamp.serialize(serializer)
+		}
+
+		pub fn deserialize<'de, D: Deserializer<'de>>(
+			deserializer: D,
+		) -> Result<Option<Timestamp>, D::Error> {
+			#[derive(Deserialize)]
+			struct Wrapper(#[serde(with = "super")] Timestamp);
+
+			Ok(Option::<Wrapper>::deserialize(deserializer)?.map(|Wrapper(timestamp)| timestamp))
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use ::serde::{Deserialize, Serialize};
+
+	use super::*;
+
+	#[derive(Debug, Serialize, Deserialize)]
+	struct Config {
+		#[serde(with = "flexible_duration")]
+		timeout: Duration,
+		#[serde(with = "flexible_timestamp")]
+		not_before: Timestamp,
+		#[serde(with = "flexible_duration::option", default)]
+		grace: Option<Duration>,
+	}
+
+	fn parse(json: &str) -> Result<Config, serde_json::Error> {
+		serde_json::from_str(json)
+	}
+
+	#[test]
+	fn flexible_inputs() {
+		let config =
+			parse(r#"{ "timeout": 30, "not_before": 1714557600, "grace": "1h 30m" }"#).unwrap();
+
+		assert_eq!(config.timeout, Duration::new(30, 0));
+		assert_eq!(
+			config.not_before,
+			"2024-05-01T10:00:00Z"
+				.parse::<Timestamp>()
+				.unwrap()
+		);
+		assert_eq!(config.grace, Some(Duration::new(5_400, 0)));
+
+		let config = parse(
+			r#"{ "timeout": "1.5s", "not_before": "2024-05-01T10:00:00.5Z", "grace": null }"#,
+		)
+		.unwrap();
+
+		assert_eq!(config.timeout, Duration::new(1, 500_000_000));
+		assert_eq!(config.not_before.nanos, 500_000_000);
+		assert_eq!(config.grace, None);
+
+		let config = parse(r#"{ "timeout": 0.25, "not_before": -1.5 }"#).unwrap();
+
+		assert_eq!(config.timeout, Duration::new(0, 250_000_000));
+		assert_eq!(config.not_before, Timestamp::new(-2, 500_000_000));
+		assert_eq!(config.grace, None);
+	}
+
+	#[test]
+	fn canonical_output() {
+		let config = parse(r#"{ "timeout": "250ms", "not_before": 0, "grace": "2m" }"#).unwrap();
+
+		assert_eq!(
+			serde_json::to_string(&config).unwrap(),
+			r#"{"timeout":"0.25s","not_before":"1970-01-01T00:00:00Z","grace":"120s"}"#
+		);
+	}
+
+	#[test]
+	fn invalid_inputs() {
+		assert!(parse(r#"{ "timeout": "30 parsecs", "not_before": 0 }"#).is_err());
+		assert!(parse(r#"{ "timeout": 30, "not_before": "yesterday" }"#).is_err());
+		assert!(parse(r#"{ "timeout": 1e300, "not_before": 0 }"#).is_err());
+		assert!(parse(r#"{ "timeout": true, "not_before": 0 }"#).is_err());
+	}
+}
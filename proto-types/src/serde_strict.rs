@@ -0,0 +1,202 @@
+//! Strict deserializers, which reject out of range or non-normalized values instead of accepting or normalizing them.
+//!
+//! They can be used on individual fields with `#[serde(deserialize_with = "Duration::deserialize_strict")]`.
+
+use serde::{Deserialize, Deserializer, de::Error};
+
+use crate::{Duration, Timestamp, duration, format, timestamp};
+
+impl Timestamp {
+	/// Deserializes an RFC 3339 timestamp, rejecting values outside of the `0001-01-01T00:00:00Z` to `9999-12-31T23:59:59.999999999Z` range.
+	pub fn deserialize_strict<'de, D: Deserializer<'de>>(
+		deserializer: D,
+	) -> Result<Self, D::Error> {
+		let timestamp = Self::deserialize(deserializer)?;
+
+		timestamp::check_invariants(&timestamp)
+			.into_result()
+			.map_err(|report| D::Error::custom(format!("Invalid timestamp: {report}")))?;
+
+		Ok(timestamp)
+	}
+}
+
+impl Duration {
+	/// Deserializes a duration, rejecting values outside of the ±10,000 years range allowed by the protobuf spec.
+	pub fn deserialize_strict<'de, D: Deserializer<'de>>(
+		deserializer: D,
+	) -> Result<Self, D::Error> {
+		let duration = Self::deserialize(deserializer)?;
+
+		duration::check_invariants(&duration)
+			.into_result()
+			.map_err(|report| D::Error::custom(format!("Invalid duration: {report}")))?;
+
+		Ok(duration)
+	}
+}
+
+#[cfg(feature = "date")]
+impl crate::Date {
+	/// Deserializes a date, rejecting the calendar dates that do not exist, such as February 30th.
+	pub fn deserialize_strict<'de, D: Deserializer<'de>>(
+		deserializer: D,
+	) -> Result<Self, D::Error> {
+		let date = Self::deserialize(deserializer)?;
+
+		if !date.is_valid() {
+			return Err(D::Error::custom(format!("Invalid date: {date}")));
+		}
+
+		Ok(date)
+	}
+}
+
+#[cfg(feature = "datetime")]
+impl crate::DateTime {
+	/// Deserializes a date and time from its proto3 JSON representation, rejecting unknown fields, invalid dates or times, and UTC offsets that are not whole seconds within ±18 hours.
+	pub fn deserialize_strict<'de, D: Deserializer<'de>>(
+		deserializer: D,
+	) -> Result<Self, D::Error> {
+		let datetime =
+			deserializer.deserialize_map(crate::common::DateTimeVisitor { strict: true })?;
+
+		datetime
+			.validate()
+			.map_err(|e| D::Error::custom(format!("Invalid datetime: {e}")))?;
+
+		if let Some(crate::date_time::TimeOffset::UtcOffset(offset)) = &datetime.time_offset
+			&& (offset.nanos != 0 || offset.seconds.abs() > 18 * 3600)
+		{
+			return Err(D::Error::custom(format!(
+				"Invalid datetime: UTC offset {offset} must be whole seconds between -18h and +18h"
+			)));
+		}
+
+		Ok(datetime)
+	}
+}
+
+#[cfg(feature = "money")]
+impl crate::Money {
+	/// Deserializes an amount of money, rejecting unknown fields, nanos outside of the `-999_999_999..=999_999_999` range, and units and nanos with different signs.
+	pub fn deserialize_strict<'de, D: Deserializer<'de>>(
+		deserializer: D,
+	) -> Result<Self, D::Error> {
+		#[derive(Deserialize)]
+		#[serde(rename_all = "camelCase", deny_unknown_fields)]
+		struct StrictMoney {
+			currency_code: crate::String,
+			units: i64,
+			nanos: i32,
+		}
+
+		let StrictMoney {
+			currency_code,
+			units,
+			nanos,
+		} = StrictMoney::deserialize(deserializer)?;
+
+		if !(-999_999_999..=999_999_999).contains(&nanos) {
+			return Err(D::Error::custom(format!(
+				"Invalid money: nanos value {nanos} is out of range"
+			)));
+		}
+
+		if (units > 0 && nanos < 0) || (units < 0 && nanos > 0) {
+			return Err(D::Error::custom(format!(
+				"Invalid money: units ({units}) and nanos ({nanos}) have different signs"
+			)));
+		}
+
+		Ok(Self {
+			currency_code,
+			units,
+			nanos,
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use serde_json::json;
+
+	use super::*;
+
+	#[test]
+	fn timestamps_and_durations() {
+		assert!(Timestamp::deserialize_strict(json!("2024-05-01T10:00:00Z")).is_ok());
+		assert!(Timestamp::deserialize_strict(json!("0000-12-31T23:59:59Z")).is_err());
+
+		assert!(Duration::deserialize_strict(json!("1.5s")).is_ok());
+		assert!(Duration::deserialize_strict(json!("315576000001s")).is_err());
+	}
+
+	#[cfg(feature = "date")]
+	#[test]
+	fn dates() {
+		assert!(crate::Date::deserialize_strict(json!("2024-02-29")).is_ok());
+		assert!(crate::Date::deserialize_strict(json!("2023-02-29")).is_err());
+	}
+
+	#[cfg(feature = "datetime")]
+	#[test]
+	fn datetimes() {
+		use crate::DateTime;
+
+		let canonical = json!({
+			"year": 2024,
+			"month": 5,
+			"day": 1,
+			"hours": 10,
+			"utcOffset": "-14400s"
+		});
+		assert!(DateTime::deserialize_strict(canonical).is_ok());
+
+		assert!(
+			DateTime::deserialize_strict(json!({ "month": 5, "day": 1, "extra": true })).is_err()
+		);
+		assert!(DateTime::deserialize_strict(json!({ "month": 2, "day": 30 })).is_err());
+		assert!(
+			DateTime::deserialize_strict(json!({ "month": 5, "day": 1, "utcOffset": "0.5s" }))
+				.is_err()
+		);
+		assert!(
+			DateTime::deserialize_strict(json!({ "month": 5, "day": 1, "utcOffset": "86400s" }))
+				.is_err()
+		);
+		assert!(
+			DateTime::deserialize_strict(
+				json!({ "month": 5, "day": 1, "timeZone": { "id": "UTC", "abbr": "UTC" } })
+			)
+			.is_err()
+		);
+	}
+
+	#[cfg(feature = "money")]
+	#[test]
+	fn money() {
+		use crate::Money;
+
+		assert!(
+			Money::deserialize_strict(json!({ "currencyCode": "USD", "units": 1, "nanos": 5 }))
+				.is_ok()
+		);
+		assert!(
+			Money::deserialize_strict(
+				json!({ "currencyCode": "USD", "units": 1, "nanos": 5, "extra": true })
+			)
+			.is_err()
+		);
+		assert!(
+			Money::deserialize_strict(json!({ "currencyCode": "USD", "units": 1, "nanos": -5 }))
+				.is_err()
+		);
+		assert!(
+			Money::deserialize_strict(
+				json!({ "currencyCode": "USD", "units": 0, "nanos": 1_000_000_000 })
+			)
+			.is_err()
+		);
+	}
+}
@@ -0,0 +1,133 @@
+use prost::Message;
+use sha2::{Digest, Sha256};
+
+use crate::{Any, Duration, FieldMask, Timestamp, Vec};
+
+/// Content hashes computed over the canonical encodings of the well known types.
+///
+/// The hash only depends on the value, and not on how it was built or on the order of unordered collections, so it can be used as a deduplication key or an idempotency token.
+/// Tuples are hashed by combining the length-prefixed encodings of their elements, so `(Timestamp, Money)` pairs can be hashed directly.
+pub trait StableHash {
+	/// Returns the canonical binary encoding of the value.
+	fn canonical_bytes(&self) -> Vec<u8>;
+
+	/// Returns the SHA-256 digest of the canonical encoding of the value.
+	#[must_use]
+	fn stable_hash_sha256(&self) -> [u8; 32] {
+		Sha256::digest(self.canonical_bytes()).into()
+	}
+}
+
+impl<T: StableHash + ?Sized> StableHash for &T {
+	#[inline]
+	fn canonical_bytes(&self) -> Vec<u8> {
+		(**self).canonical_bytes()
+	}
+}
+
+macro_rules! impl_stable_hash {
+	($($(#[$meta:meta])* $ty:ty => |$value:ident| $encode:expr),* $(,)?) => {
+		$(
+			$(#[$meta])*
+			impl StableHash for $ty {
+				#[inline]
+				fn canonical_bytes(&self) -> Vec<u8> {
+					let $value = self;
+					$encode
+				}
+			}
+		)*
+	};
+}
+
+impl_stable_hash!(
+	Timestamp => |value| value.normalized().encode_to_vec(),
+	Duration => |value| value.normalized().encode_to_vec(),
+	Any => |value| value.encode_canonical(),
+	FieldMask => |value| value.encode_to_vec(),
+	#[cfg(feature = "money")]
+	crate::Money => |value| value
+		.clone()
+		.normalize()
+		.as_ref()
+		.unwrap_or(value)
+		.encode_to_vec(),
+	#[cfg(feature = "date")]
+	crate::Date => |value| value.encode_to_vec(),
+	#[cfg(feature = "timeofday")]
+	crate::TimeOfDay => |value| value.encode_to_vec(),
+	#[cfg(feature = "latlng")]
+	crate::LatLng => |value| value.encode_to_vec(),
+	#[cfg(feature = "fraction")]
+	crate::Fraction => |value| value.encode_to_vec(),
+	#[cfg(feature = "interval")]
+	crate::Interval => |value| crate::Interval {
+		start_time: value.start_time.map(|ts| ts.normalized()),
+		end_time: value.end_time.map(|ts| ts.normalized()),
+	}
+	.encode_to_vec(),
+	#[cfg(feature = "rpc")]
+	crate::rpc::Status => |value| value.encode_canonical(),
+	#[cfg(feature = "protovalidate")]
+	crate::protovalidate::Violations => |value| value.encode_canonical(),
+);
+
+macro_rules! impl_stable_hash_tuple {
+	($($name:ident),+) => {
+		impl<$($name: StableHash),+> StableHash for ($($name,)+) {
+			#[allow(non_snake_case)]
+			fn canonical_bytes(&self) -> Vec<u8> {
+				let ($($name,)+) = self;
+				let mut bytes = Vec::new();
+
+				$(
+					let element = $name.canonical_bytes();
+					bytes.extend_from_slice(&(element.len() as u64).to_le_bytes());
+					bytes.extend_from_slice(&element);
+				)+
+
+				bytes
+			}
+		}
+	};
+}
+
+impl_stable_hash_tuple!(A);
+impl_stable_hash_tuple!(A, B);
+impl_stable_hash_tuple!(A, B, C);
+impl_stable_hash_tuple!(A, B, C, D);
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn normalized_values_hash_equally() {
+		let normalized = Timestamp::new(10, 0);
+		let denormalized = Timestamp {
+			seconds: 9,
+			nanos: 1_000_000_000,
+		};
+
+		assert_eq!(
+			normalized.stable_hash_sha256(),
+			denormalized.stable_hash_sha256()
+		);
+		assert_ne!(
+			normalized.stable_hash_sha256(),
+			Timestamp::new(11, 0).stable_hash_sha256()
+		);
+	}
+
+	#[test]
+	fn tuple_elements_are_delimited() {
+		let a = (Timestamp::new(1, 0), Duration::new(0, 0));
+		let b = (Timestamp::new(0, 0), Duration::new(1, 0));
+
+		assert_ne!(a.stable_hash_sha256(), b.stable_hash_sha256());
+		assert_eq!(
+			(Timestamp::new(0, 0),).stable_hash_sha256(),
+			(&Timestamp::new(0, 0),).stable_hash_sha256()
+		);
+	}
+}
@@ -0,0 +1,75 @@
+use crate::{duration::RepeatingInterval, *};
+
+macro_rules! impl_try_from_str {
+	($($ty:ty),* $(,)?) => {
+		$(
+			impl TryFrom<&str> for $ty {
+				type Error = <$ty as FromStr>::Err;
+
+				#[inline]
+				fn try_from(value: &str) -> Result<Self, Self::Error> {
+					value.parse()
+				}
+			}
+		)*
+	};
+}
+
+macro_rules! impl_into_string {
+	($($(#[$meta:meta])* $ty:ty),* $(,)?) => {
+		$(
+			$(#[$meta])*
+			impl From<$ty> for String {
+				#[inline]
+				fn from(value: $ty) -> Self {
+					value.to_string()
+				}
+			}
+		)*
+	};
+}
+
+impl_try_from_str!(Timestamp, Duration, RepeatingInterval);
+
+impl_into_string!(
+	Timestamp,
+	Duration,
+	RepeatingInterval,
+	DayOfWeek,
+	Month,
+	#[cfg(feature = "rpc")]
+	Code,
+	#[cfg(feature = "date")]
+	Date,
+	#[cfg(feature = "timeofday")]
+	TimeOfDay,
+	#[cfg(feature = "datetime")]
+	DateTime,
+	#[cfg(feature = "datetime")]
+	TimeZone,
+	#[cfg(feature = "latlng")]
+	LatLng,
+	#[cfg(feature = "color")]
+	Color,
+	#[cfg(feature = "decimal")]
+	Decimal,
+	#[cfg(feature = "fraction")]
+	Fraction,
+);
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn str_round_trip() {
+		let ts = Timestamp::try_from("2024-01-15T10:30:00Z").unwrap();
+		assert_eq!(String::from(ts), "2024-01-15T10:30:00Z");
+
+		let dur = Duration::try_from("1.5s").unwrap();
+		assert_eq!(String::from(dur), "1.5s");
+
+		assert!(Timestamp::try_from("not a timestamp").is_err());
+		assert!(Duration::try_from("1.5").is_err());
+	}
+}
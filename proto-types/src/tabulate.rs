@@ -0,0 +1,199 @@
+use core::fmt::{self, Display, Write};
+
+use crate::{String, ToString, Vec};
+
+/// The separator used to group the digits of the integer part of aligned amounts.
+pub(crate) const THIN_SPACE: char = '\u{2009}';
+
+/// Groups the digits of an unsigned integer in groups of three, separated by a thin space (e.g. `1 234 567`).
+pub(crate) fn group_digits(digits: &str) -> String {
+	let mut output = String::with_capacity(digits.len() + digits.len() / 3 * THIN_SPACE.len_utf8());
+
+	for (i, digit) in digits.chars().enumerate() {
+		if i > 0 && (digits.len() - i).is_multiple_of(3) {
+			output.push(THIN_SPACE);
+		}
+		output.push(digit);
+	}
+
+	output
+}
+
+/// The alignment of a column in a [`Tabulate`] table.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Align {
+	Left,
+	#[default]
+	Right,
+}
+
+/// A minimal helper to format rows of values into aligned columns, for reports in command line tools.
+///
+/// Columns are right-aligned by default, which works well with the output of
+/// [`Money::to_aligned_string`](crate::Money::to_aligned_string) and [`Duration::to_aligned_string`](crate::Duration::to_aligned_string).
+/// The width of each column is the width of its widest cell, measured in characters.
+///
+/// # Examples
+/// ```rust
+/// use proto_types::{Align, Duration, Tabulate};
+///
+/// let mut table = Tabulate::new(["task", "elapsed"]).align(0, Align::Left);
+/// table.push_row(["build", &Duration::new(75, 0).to_aligned_string(1, 0)]);
+/// table.push_row(["test", &Duration::new(4, 500_000_000).to_aligned_string(1, 0)]);
+///
+/// assert_eq!(
+///     table.to_string(),
+///     "task   elapsed\n-----  -------\nbuild    75.0s\ntest      4.5s\n"
+/// );
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Tabulate {
+	headers: Vec<String>,
+	alignments: Vec<Align>,
+	rows: Vec<Vec<String>>,
+}
+
+impl Tabulate {
+	/// Creates a new table with the given column headers.
+	#[must_use]
+	pub fn new<I, S>(headers: I) -> Self
+	where
+		I: IntoIterator<Item = S>,
+		S: Display,
+	{
+		let headers: Vec<String> = headers
+			.into_iter()
+			.map(|h| h.to_string())
+			.collect();
+		let alignments = headers.iter().map(|_| Align::default()).collect();
+
+		Self {
+			headers,
+			alignments,
+			rows: Vec::new(),
+		}
+	}
+
+	/// Sets the alignment of the column at the given index. Indexes out of bounds are ignored.
+	#[must_use]
+	pub fn align(mut self, column: usize, align: Align) -> Self {
+		if let Some(current) = self.alignments.get_mut(column) {
+			*current = align;
+		}
+		self
+	}
+
+	/// Adds a row to the table. Cells beyond the number of headers are ignored, and missing cells are left empty.
+	pub fn push_row<I, S>(&mut self, cells: I)
+	where
+		I: IntoIterator<Item = S>,
+		S: Display,
+	{
+		let row = cells
+			.into_iter()
+			.take(self.headers.len())
+			.map(|c| c.to_string())
+			.collect();
+		self.rows.push(row);
+	}
+
+	/// Adds a row for each item of the slice, using the given function to produce the cells.
+	#[must_use]
+	pub fn with_rows<T, F, I, S>(mut self, items: &[T], mut cells: F) -> Self
+	where
+		F: FnMut(&T) -> I,
+		I: IntoIterator<Item = S>,
+		S: Display,
+	{
+		for item in items {
+			self.push_row(cells(item));
+		}
+		self
+	}
+
+	fn widths(&self) -> Vec<usize> {
+		self.headers
+			.iter()
+			.enumerate()
+			.map(|(i, header)| {
+				self.rows
+					.iter()
+					.filter_map(|row| row.get(i))
+					.chain(core::iter::once(header))
+					.map(|cell| cell.chars().count())
+					.max()
+					.unwrap_or_default()
+			})
+			.collect()
+	}
+
+	fn write_line<'a>(
+		&self,
+		f: &mut fmt::Formatter<'_>,
+		widths: &[usize],
+		cells: impl Iterator<Item = &'a str>,
+	) -> fmt::Result {
+		let mut line = String::new();
+
+		for (i, (cell, width)) in cells.zip(widths).enumerate() {
+			if i > 0 {
+				line.push_str("  ");
+			}
+
+			let _ = match self.alignments[i] {
+				Align::Left => write!(line, "{cell:<width$}"),
+				Align::Right => write!(line, "{cell:>width$}"),
+			};
+		}
+
+		writeln!(f, "{}", line.trim_end())
+	}
+}
+
+impl Display for Tabulate {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let widths = self.widths();
+
+		self.write_line(f, &widths, self.headers.iter().map(String::as_str))?;
+
+		let rules: Vec<String> = widths.iter().map(|w| "-".repeat(*w)).collect();
+		self.write_line(f, &widths, rules.iter().map(String::as_str))?;
+
+		for row in &self.rows {
+			let cells = row
+				.iter()
+				.map(String::as_str)
+				.chain(core::iter::repeat(""));
+			self.write_line(f, &widths, cells)?;
+		}
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn digit_grouping() {
+		assert_eq!(group_digits("0"), "0");
+		assert_eq!(group_digits("123"), "123");
+		assert_eq!(group_digits("1234"), "1\u{2009}234");
+		assert_eq!(group_digits("1234567"), "1\u{2009}234\u{2009}567");
+	}
+
+	#[test]
+	fn table_layout() {
+		let table = Tabulate::new(["name", "count"])
+			.align(0, Align::Left)
+			.with_rows(&[("a", 1), ("longer", 1000)], |(name, count)| {
+				[name.to_string(), count.to_string()]
+			});
+
+		assert_eq!(
+			table.to_string(),
+			"name    count\n------  -----\na           1\nlonger   1000\n"
+		);
+	}
+}
@@ -0,0 +1,230 @@
+//! Canonical pairs of values and their JSON and binary encodings, for asserting wire compatibility across implementations.
+//!
+//! The JSON strings are the forms produced by this crate's serde implementations, and they are chosen so that they also match the canonical proto3 JSON mapping.
+//! The binary forms are the protobuf wire encodings, with default values omitted. Types whose JSON form depends on the packed message, such as [`Any`](crate::Any), only have binary vectors.
+
+use crate::{Duration, Timestamp};
+
+/// A value paired with its expected JSON and binary encodings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TestVector<T: 'static> {
+	/// A short description of the case.
+	pub name: &'static str,
+	/// The decoded value.
+	pub value: T,
+	/// The JSON encoding of the value, including the quotes for string values.
+	pub json: &'static str,
+	/// The protobuf binary encoding of the value.
+	pub binary: &'static [u8],
+}
+
+/// A value paired with its expected binary encoding, for the types whose JSON form produced by this crate is not the canonical one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BinaryTestVector<T: 'static> {
+	/// A short description of the case.
+	pub name: &'static str,
+	/// The decoded value.
+	pub value: T,
+	/// The protobuf binary encoding of the value.
+	pub binary: &'static [u8],
+}
+
+/// The decoded form of an [`Any`](crate::Any) test vector.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AnyParts {
+	pub type_url: &'static str,
+	pub value: &'static [u8],
+}
+
+/// Test vectors for [`Timestamp`].
+pub const TIMESTAMP: &[TestVector<Timestamp>] = &[
+	TestVector {
+		name: "unix epoch",
+		value: Timestamp {
+			seconds: 0,
+			nanos: 0,
+		},
+		json: r#""1970-01-01T00:00:00Z""#,
+		binary: b"",
+	},
+	TestVector {
+		name: "whole seconds",
+		value: Timestamp {
+			seconds: 1_700_000_000,
+			nanos: 0,
+		},
+		json: r#""2023-11-14T22:13:20Z""#,
+		binary: b"\x08\x80\xe2\xcf\xaa\x06",
+	},
+	TestVector {
+		name: "millisecond precision",
+		value: Timestamp {
+			seconds: 1_700_000_000,
+			nanos: 123_000_000,
+		},
+		json: r#""2023-11-14T22:13:20.123Z""#,
+		binary: b"\x08\x80\xe2\xcf\xaa\x06\x10\xc0\xa9\xd3\x3a",
+	},
+	TestVector {
+		name: "nanosecond precision",
+		value: Timestamp {
+			seconds: 1_700_000_000,
+			nanos: 123_456_789,
+		},
+		json: r#""2023-11-14T22:13:20.123456789Z""#,
+		binary: b"\x08\x80\xe2\xcf\xaa\x06\x10\x95\x9a\xef\x3a",
+	},
+	TestVector {
+		name: "before the unix epoch",
+		value: Timestamp {
+			seconds: -1,
+			nanos: 0,
+		},
+		json: r#""1969-12-31T23:59:59Z""#,
+		binary: b"\x08\xff\xff\xff\xff\xff\xff\xff\xff\xff\x01",
+	},
+];
+
+/// Test vectors for [`Duration`].
+pub const DURATION: &[TestVector<Duration>] = &[
+	TestVector {
+		name: "zero",
+		value: Duration {
+			seconds: 0,
+			nanos: 0,
+		},
+		json: r#""0s""#,
+		binary: b"",
+	},
+	TestVector {
+		name: "millisecond precision",
+		value: Duration {
+			seconds: 1,
+			nanos: 123_000_000,
+		},
+		json: r#""1.123s""#,
+		binary: b"\x08\x01\x10\xc0\xa9\xd3\x3a",
+	},
+	TestVector {
+		name: "negative",
+		value: Duration {
+			seconds: -3,
+			nanos: -123_000_000,
+		},
+		json: r#""-3.123s""#,
+		binary: b"\x08\xfd\xff\xff\xff\xff\xff\xff\xff\xff\x01\x10\xc0\xd6\xac\xc5\xff\xff\xff\xff\xff\x01",
+	},
+	TestVector {
+		name: "maximum",
+		value: Duration {
+			seconds: 315_576_000_000,
+			nanos: 0,
+		},
+		json: r#""315576000000s""#,
+		binary: b"\x08\x80\xbc\xae\xce\x97\x09",
+	},
+];
+
+/// Test vectors for [`FieldMask`](crate::FieldMask), whose value is the list of paths.
+pub const FIELD_MASK: &[TestVector<&[&str]>] = &[
+	TestVector {
+		name: "empty",
+		value: &[],
+		json: r#""""#,
+		binary: b"",
+	},
+	TestVector {
+		name: "nested paths",
+		value: &["name", "address.city"],
+		json: r#""name,address.city""#,
+		binary: b"\x0a\x04name\x0a\x0caddress.city",
+	},
+];
+
+/// Test vectors for [`Any`](crate::Any).
+///
+/// Only the binary form is provided, because the canonical JSON form embeds the fields of the packed message (or the JSON form of a well-known type), which requires knowing its type.
+pub const ANY: &[BinaryTestVector<AnyParts>] = &[BinaryTestVector {
+	name: "packed timestamp",
+	value: AnyParts {
+		type_url: "type.googleapis.com/google.protobuf.Timestamp",
+		value: b"\x08\x80\xe2\xcf\xaa\x06",
+	},
+	binary:
+		b"\x0a\x2dtype.googleapis.com/google.protobuf.Timestamp\x12\x06\x08\x80\xe2\xcf\xaa\x06",
+}];
+
+#[cfg(test)]
+mod tests {
+	use prost::Message;
+
+	use super::*;
+	use crate::{Any, FieldMask, ToString};
+
+	fn field_mask(paths: &[&str]) -> FieldMask {
+		FieldMask {
+			paths: paths.iter().map(ToString::to_string).collect(),
+		}
+	}
+
+	fn any(parts: AnyParts) -> Any {
+		Any {
+			type_url: parts.type_url.to_string(),
+			value: parts.value.to_vec(),
+		}
+	}
+
+	#[test]
+	fn binary_round_trips() {
+		for vector in TIMESTAMP {
+			assert_eq!(
+				vector.value.encode_to_vec(),
+				vector.binary,
+				"{}",
+				vector.name
+			);
+			assert_eq!(Timestamp::decode(vector.binary).unwrap(), vector.value);
+		}
+		for vector in DURATION {
+			assert_eq!(
+				vector.value.encode_to_vec(),
+				vector.binary,
+				"{}",
+				vector.name
+			);
+			assert_eq!(Duration::decode(vector.binary).unwrap(), vector.value);
+		}
+		for vector in FIELD_MASK {
+			let mask = field_mask(vector.value);
+			assert_eq!(mask.encode_to_vec(), vector.binary, "{}", vector.name);
+			assert_eq!(FieldMask::decode(vector.binary).unwrap(), mask);
+		}
+		for vector in ANY {
+			let any = any(vector.value);
+			assert_eq!(any.encode_to_vec(), vector.binary, "{}", vector.name);
+			assert_eq!(Any::decode(vector.binary).unwrap(), any);
+		}
+	}
+
+	#[cfg(feature = "serde")]
+	#[test]
+	fn json_round_trips() {
+		fn check<T>(value: &T, json: &str, name: &str)
+		where
+			T: ::serde::Serialize + ::serde::de::DeserializeOwned + PartialEq + core::fmt::Debug,
+		{
+			assert_eq!(serde_json::to_string(value).unwrap(), json, "{name}");
+			assert_eq!(&serde_json::from_str::<T>(json).unwrap(), value, "{name}");
+		}
+
+		for vector in TIMESTAMP {
+			check(&vector.value, vector.json, vector.name);
+		}
+		for vector in DURATION {
+			check(&vector.value, vector.json, vector.name);
+		}
+		for vector in FIELD_MASK {
+			check(&field_mask(vector.value), vector.json, vector.name);
+		}
+	}
+}
@@ -0,0 +1,286 @@
+//! Fixtures for the test suites of crates that use the well known types: controllable clocks, short constructors and assertion macros.
+//!
+//! # Examples
+//! ```rust
+//! use proto_types::testkit::{Clock, SteppingClock, dur, ts};
+//!
+//! let clock = SteppingClock::new(ts(100), dur(5));
+//!
+//! assert_eq!(clock.now(), ts(100));
+//! assert_eq!(clock.now(), ts(105));
+//!
+//! proto_types::assert_duration_close!(dur(10), dur(11), dur(1));
+//! ```
+
+use core::cell::Cell;
+
+pub use crate::clock::Clock;
+use crate::{Duration, Timestamp};
+
+/// A clock that always returns the same time, until it is changed manually.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FixedClock {
+	now: Cell<Timestamp>,
+}
+
+impl FixedClock {
+	/// Creates a new clock fixed at the given time.
+	#[must_use]
+	#[inline]
+	pub const fn new(now: Timestamp) -> Self {
+		Self {
+			now: Cell::new(now),
+		}
+	}
+
+	/// Sets the time returned by the clock.
+	#[inline]
+	pub fn set(&self, now: Timestamp) {
+		self.now.set(now);
+	}
+
+	/// Moves the clock forward (or backward, for negative durations) by the given amount.
+	#[inline]
+	pub fn advance(&self, by: Duration) {
+		self.now.set(self.now.get() + by);
+	}
+}
+
+impl Clock for FixedClock {
+	#[inline]
+	fn now(&self) -> Timestamp {
+		self.now.get()
+	}
+}
+
+/// A clock that moves forward by a fixed step every time it is read.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SteppingClock {
+	next: Cell<Timestamp>,
+	step: Duration,
+}
+
+impl SteppingClock {
+	/// Creates a new clock, which returns `start` on the first read and then moves forward by `step` on each subsequent read.
+	#[must_use]
+	#[inline]
+	pub const fn new(start: Timestamp, step: Duration) -> Self {
+		Self {
+			next: Cell::new(start),
+			step,
+		}
+	}
+
+	/// Returns the time that will be returned by the next read, without advancing the clock.
+	#[must_use]
+	#[inline]
+	pub const fn peek(&self) -> Timestamp {
+		self.next.get()
+	}
+}
+
+impl Clock for SteppingClock {
+	fn now(&self) -> Timestamp {
+		let now = self.next.get();
+		self.next.set(now + self.step);
+		now
+	}
+}
+
+/// Creates a [`Timestamp`] from a number of seconds since the Unix epoch.
+#[must_use]
+#[inline]
+pub const fn ts(seconds: i64) -> Timestamp {
+	Timestamp { seconds, nanos: 0 }
+}
+
+/// Creates a [`Duration`] from a number of seconds.
+#[must_use]
+#[inline]
+pub const fn dur(seconds: i64) -> Duration {
+	Duration::const_from_secs(seconds)
+}
+
+/// Creates a [`Money`](crate::Money) amount, panicking if the units and nanos are not valid.
+#[cfg(feature = "money")]
+#[must_use]
+#[track_caller]
+pub fn money(currency_code: &str, units: i64, nanos: i32) -> crate::Money {
+	crate::Money::new(currency_code, units, nanos).expect("invalid money amount")
+}
+
+/// Creates a [`Violation`](crate::protovalidate::Violation) for the given field path, rule id and message.
+///
+/// The path is made of compact tokens joined by dots (e.g. `items[2].name`), and an empty path creates a violation without a field.
+/// It panics if a token cannot be parsed.
+#[cfg(feature = "protovalidate")]
+#[must_use]
+#[track_caller]
+pub fn violation(
+	field_path: &str,
+	rule_id: &str,
+	message: &str,
+) -> crate::protovalidate::Violation {
+	use crate::protovalidate::{FieldPath, FieldPathElement, Violation};
+
+	let field = (!field_path.is_empty()).then(|| FieldPath {
+		elements: field_path
+			.split('.')
+			.map(|token| {
+				token
+					.parse::<FieldPathElement>()
+					.expect("invalid field path token")
+			})
+			.collect(),
+	});
+
+	Violation {
+		field,
+		rule_id: Some(rule_id.into()),
+		message: Some(message.into()),
+		..Default::default()
+	}
+}
+
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_duration_close(left: &Duration, right: &Duration, tolerance: &Duration) {
+	let difference = (left.total_nanos() - right.total_nanos()).abs();
+
+	assert!(
+		difference <= tolerance.total_nanos().abs(),
+		"assertion failed: durations are not within {tolerance} of each other\n  left: {left}\n right: {right}"
+	);
+}
+
+#[cfg(feature = "protovalidate")]
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_violations_contain(
+	violations: &crate::protovalidate::Violations,
+	field_path: Option<&str>,
+	rule_id: &str,
+) {
+	let found = violations.violations.iter().any(|v| {
+		v.rule_id() == rule_id
+			&& field_path.is_none_or(|path| v.field_path_str().as_deref() == Some(path))
+	});
+
+	if !found {
+		let present: crate::Vec<_> = violations
+			.violations
+			.iter()
+			.map(|v| (v.field_path_str().unwrap_or_default(), v.rule_id()))
+			.collect();
+
+		panic!(
+			"assertion failed: no violation with rule id `{rule_id}`{}\n present (field, rule id) pairs: {present:?}",
+			field_path
+				.map(|path| crate::format!(" on the field `{path}`"))
+				.unwrap_or_default()
+		);
+	}
+}
+
+/// Asserts that two [`Duration`]s differ by at most the given tolerance.
+///
+/// ```rust
+/// use proto_types::Duration;
+///
+/// proto_types::assert_duration_close!(
+///   Duration::new(1, 0),
+///   Duration::new(1, 400_000_000),
+///   Duration::new(0, 500_000_000)
+/// );
+/// ```
+#[macro_export]
+macro_rules! assert_duration_close {
+	($left:expr, $right:expr, $tolerance:expr $(,)?) => {
+		$crate::testkit::assert_duration_close(&$left, &$right, &$tolerance)
+	};
+}
+
+/// Asserts that a [`Violations`](crate::protovalidate::Violations) collection contains a violation with the given rule id.
+///
+/// An optional field path can be passed before the rule id, joined by dots as returned by [`Violation::field_path_str`](crate::protovalidate::Violation::field_path_str).
+///
+/// ```rust
+/// use proto_types::protovalidate::{Violation, Violations};
+///
+/// let violations: Violations = core::iter::once(Violation {
+///   rule_id: Some("string.min_len".into()),
+///   ..Default::default()
+/// })
+/// .collect();
+///
+/// proto_types::assert_violations_contain!(violations, "string.min_len");
+/// ```
+#[cfg(feature = "protovalidate")]
+#[macro_export]
+macro_rules! assert_violations_contain {
+	($violations:expr, $rule_id:expr $(,)?) => {
+		$crate::testkit::assert_violations_contain(
+			&$violations,
+			::core::option::Option::None,
+			$rule_id,
+		)
+	};
+	($violations:expr, $field_path:expr, $rule_id:expr $(,)?) => {
+		$crate::testkit::assert_violations_contain(
+			&$violations,
+			::core::option::Option::Some($field_path),
+			$rule_id,
+		)
+	};
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn clocks() {
+		let fixed = FixedClock::new(ts(10));
+		assert_eq!(fixed.now(), ts(10));
+
+		fixed.advance(dur(5));
+		assert_eq!(fixed.now(), ts(15));
+
+		let stepping = SteppingClock::new(ts(0), dur(2));
+		assert_eq!(stepping.now(), ts(0));
+		assert_eq!(stepping.now(), ts(2));
+		assert_eq!(stepping.peek(), ts(4));
+	}
+
+	#[test]
+	#[should_panic(expected = "not within")]
+	fn duration_close_failure() {
+		crate::assert_duration_close!(dur(1), dur(3), dur(1));
+	}
+
+	#[cfg(feature = "protovalidate")]
+	#[test]
+	fn violation_assertions() {
+		use crate::protovalidate::{FieldPath, FieldPathElement, Violation, Violations};
+
+		let violations: Violations = core::iter::once(Violation {
+			field: Some(FieldPath {
+				elements: alloc::vec![FieldPathElement {
+					field_name: Some("name".into()),
+					..Default::default()
+				}],
+			}),
+			rule_id: Some("string.min_len".into()),
+			..Default::default()
+		})
+		.collect();
+
+		crate::assert_violations_contain!(violations, "string.min_len");
+		crate::assert_violations_contain!(violations, "name", "string.min_len");
+
+		let result = std::panic::catch_unwind(|| {
+			crate::assert_violations_contain!(violations, "other", "string.min_len");
+		});
+		assert!(result.is_err());
+	}
+}
@@ -0,0 +1,378 @@
+use core::fmt::Write;
+
+use crate::{Any, Duration, String, Timestamp, duration::DurationError, timestamp::TimestampError};
+
+/// A small writer for the protobuf text format, which takes care of indentation and escaping.
+pub(crate) struct TextWriter {
+	out: String,
+	indent: usize,
+}
+
+impl TextWriter {
+	pub(crate) const fn new() -> Self {
+		Self {
+			out: String::new(),
+			indent: 0,
+		}
+	}
+
+	fn write_indent(&mut self) {
+		for _ in 0..self.indent {
+			self.out.push_str("  ");
+		}
+	}
+
+	/// Writes a scalar field, skipping it if it holds the default value.
+	pub(crate) fn scalar<T: core::fmt::Display + Default + PartialEq>(
+		&mut self,
+		name: &str,
+		value: &T,
+	) {
+		if *value != T::default() {
+			self.write_indent();
+			let _ = writeln!(self.out, "{name}: {value}");
+		}
+	}
+
+	/// Writes a scalar field, unless it is `None`.
+	#[cfg(feature = "protovalidate")]
+	pub(crate) fn optional<T: core::fmt::Display>(&mut self, name: &str, value: Option<T>) {
+		if let Some(value) = value {
+			self.write_indent();
+			let _ = writeln!(self.out, "{name}: {value}");
+		}
+	}
+
+	/// Writes an escaped string or bytes field, skipping it if it's empty.
+	pub(crate) fn bytes(&mut self, name: &str, value: &[u8]) {
+		if !value.is_empty() {
+			self.write_indent();
+			let _ = write!(self.out, "{name}: \"");
+
+			for byte in value {
+				match byte {
+					b'"' => self.out.push_str("\\\""),
+					b'\\' => self.out.push_str("\\\\"),
+					b'\n' => self.out.push_str("\\n"),
+					b'\r' => self.out.push_str("\\r"),
+					b'\t' => self.out.push_str("\\t"),
+					0x20..=0x7e => self.out.push(char::from(*byte)),
+					_ => {
+						let _ = write!(self.out, "\\{byte:03o}");
+					}
+				}
+			}
+
+			self.out.push_str("\"\n");
+		}
+	}
+
+	/// Writes a nested message field.
+	pub(crate) fn message(&mut self, name: &str, write_fields: impl FnOnce(&mut Self)) {
+		self.write_indent();
+		let _ = writeln!(self.out, "{name} {{");
+
+		self.indent += 1;
+		write_fields(self);
+		self.indent -= 1;
+
+		self.write_indent();
+		self.out.push_str("}\n");
+	}
+
+	pub(crate) fn finish(self) -> String {
+		self.out
+	}
+}
+
+/// Parses the `seconds` and `nanos` fields from their text format representation.
+fn parse_seconds_and_nanos(text: &str) -> Option<(i64, i32)> {
+	let text = text.trim();
+	let text = text
+		.strip_prefix('{')
+		.and_then(|t| t.strip_suffix('}'))
+		.unwrap_or(text);
+
+	let mut seconds = 0;
+	let mut nanos = 0;
+
+	let mut tokens = text
+		.split(|c: char| c.is_whitespace() || matches!(c, ':' | ',' | ';'))
+		.filter(|t| !t.is_empty());
+
+	while let Some(name) = tokens.next() {
+		let value = tokens.next()?;
+
+		match name {
+			"seconds" => seconds = value.parse().ok()?,
+			"nanos" => nanos = value.parse().ok()?,
+			_ => return None,
+		}
+	}
+
+	Some((seconds, nanos))
+}
+
+impl Timestamp {
+	/// Returns the protobuf text format representation of this timestamp.
+	#[must_use]
+	pub fn to_text_format(&self) -> String {
+		let mut writer = TextWriter::new();
+		self.write_text_format(&mut writer);
+		writer.finish()
+	}
+
+	pub(crate) fn write_text_format(&self, writer: &mut TextWriter) {
+		writer.scalar("seconds", &self.seconds);
+		writer.scalar("nanos", &self.nanos);
+	}
+
+	/// Parses a timestamp from its protobuf text format representation (e.g. `seconds: 10 nanos: 5`).
+	///
+	/// Fails if the nanos are not within `0..=999_999_999`.
+	pub fn from_text_format(text: &str) -> Result<Self, TimestampError> {
+		let (seconds, nanos) = parse_seconds_and_nanos(text).ok_or(TimestampError::ParseFailure)?;
+
+		let mut timestamp = Self { seconds, nanos: 0 };
+		timestamp
+			.set_nanos_checked(nanos)
+			.map_err(|_| TimestampError::ParseFailure)?;
+
+		Ok(timestamp)
+	}
+}
+
+impl Duration {
+	/// Returns the protobuf text format representation of this duration.
+	#[must_use]
+	pub fn to_text_format(&self) -> String {
+		let mut writer = TextWriter::new();
+		self.write_text_format(&mut writer);
+		writer.finish()
+	}
+
+	pub(crate) fn write_text_format(&self, writer: &mut TextWriter) {
+		writer.scalar("seconds", &self.seconds);
+		writer.scalar("nanos", &self.nanos);
+	}
+
+	/// Parses a duration from its protobuf text format representation (e.g. `seconds: 10 nanos: 5`).
+	///
+	/// Fails if the nanos are not within `-999_999_999..=999_999_999` or if their sign does not match the seconds.
+	pub fn from_text_format(text: &str) -> Result<Self, DurationError> {
+		let (seconds, nanos) = parse_seconds_and_nanos(text).ok_or(DurationError::ParseFailure)?;
+
+		let mut duration = Self { seconds, nanos: 0 };
+		duration
+			.set_nanos_checked(nanos)
+			.map_err(|_| DurationError::ParseFailure)?;
+
+		Ok(duration)
+	}
+}
+
+impl Any {
+	/// Returns the protobuf text format representation of this message, with the value as an escaped bytes literal.
+	#[must_use]
+	pub fn to_text_format(&self) -> String {
+		let mut writer = TextWriter::new();
+		self.write_text_format(&mut writer);
+		writer.finish()
+	}
+
+	pub(crate) fn write_text_format(&self, writer: &mut TextWriter) {
+		writer.bytes("type_url", self.type_url.as_bytes());
+		writer.bytes("value", &self.value);
+	}
+}
+
+impl crate::Status {
+	/// Returns the protobuf text format representation of this status.
+	#[must_use]
+	pub fn to_text_format(&self) -> String {
+		let mut writer = TextWriter::new();
+
+		writer.scalar("code", &self.code);
+		writer.bytes("message", self.message.as_bytes());
+
+		for detail in &self.details {
+			writer.message("details", |w| detail.write_text_format(w));
+		}
+
+		writer.finish()
+	}
+}
+
+#[cfg(feature = "protovalidate")]
+mod protovalidate {
+	use super::TextWriter;
+	use crate::{
+		String,
+		field_descriptor_proto::Type,
+		protovalidate::{FieldPath, Violation, Violations, field_path_element::Subscript},
+	};
+
+	fn write_type(writer: &mut TextWriter, name: &str, value: Option<i32>) {
+		writer.optional(
+			name,
+			value.map(|v| Type::try_from(v).map_or("UNKNOWN", |t| t.as_str_name())),
+		);
+	}
+
+	impl FieldPath {
+		pub(crate) fn write_text_format(&self, writer: &mut TextWriter) {
+			for element in &self.elements {
+				writer.message("elements", |w| {
+					w.optional("field_number", element.field_number);
+
+					if let Some(name) = &element.field_name {
+						w.bytes("field_name", name.as_bytes());
+					}
+
+					write_type(w, "field_type", element.field_type);
+					write_type(w, "key_type", element.key_type);
+					write_type(w, "value_type", element.value_type);
+
+					match &element.subscript {
+						Some(Subscript::Index(v)) => w.optional("index", Some(v)),
+						Some(Subscript::BoolKey(v)) => w.optional("bool_key", Some(v)),
+						Some(Subscript::IntKey(v)) => w.optional("int_key", Some(v)),
+						Some(Subscript::UintKey(v)) => w.optional("uint_key", Some(v)),
+						Some(Subscript::StringKey(v)) => w.bytes("string_key", v.as_bytes()),
+						None => {}
+					}
+				});
+			}
+		}
+	}
+
+	impl Violation {
+		/// Returns the protobuf text format representation of this violation.
+		#[must_use]
+		pub fn to_text_format(&self) -> String {
+			let mut writer = TextWriter::new();
+			self.write_text_format(&mut writer);
+			writer.finish()
+		}
+
+		pub(crate) fn write_text_format(&self, writer: &mut TextWriter) {
+			if let Some(field) = &self.field {
+				writer.message("field", |w| field.write_text_format(w));
+			}
+
+			if let Some(rule) = &self.rule {
+				writer.message("rule", |w| rule.write_text_format(w));
+			}
+
+			if let Some(rule_id) = &self.rule_id {
+				writer.bytes("rule_id", rule_id.as_bytes());
+			}
+
+			if let Some(message) = &self.message {
+				writer.bytes("message", message.as_bytes());
+			}
+
+			writer.optional("for_key", self.for_key);
+		}
+	}
+
+	impl Violations {
+		/// Returns the protobuf text format representation of these violations.
+		#[must_use]
+		pub fn to_text_format(&self) -> String {
+			let mut writer = TextWriter::new();
+
+			for violation in &self.violations {
+				writer.message("violations", |w| violation.write_text_format(w));
+			}
+
+			writer.finish()
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use alloc::vec;
+
+	use super::*;
+	use crate::Status;
+
+	#[test]
+	fn timestamp_and_duration() {
+		let timestamp = Timestamp::new(10, 5);
+
+		assert_eq!(timestamp.to_text_format(), "seconds: 10\nnanos: 5\n");
+		assert_eq!(Timestamp::default().to_text_format(), "");
+
+		assert_eq!(
+			Timestamp::from_text_format(&timestamp.to_text_format()),
+			Ok(timestamp)
+		);
+		assert_eq!(
+			Duration::from_text_format("{ seconds: -3, nanos: -20 }"),
+			Ok(Duration {
+				seconds: -3,
+				nanos: -20
+			})
+		);
+		assert_eq!(Duration::from_text_format(""), Ok(Duration::default()));
+		assert_eq!(
+			Duration::from_text_format("seconds 10 minutes: 5"),
+			Err(DurationError::ParseFailure)
+		);
+		assert_eq!(
+			Timestamp::from_text_format("seconds:"),
+			Err(TimestampError::ParseFailure)
+		);
+	}
+
+	#[test]
+	fn invalid_nanos() {
+		for text in ["nanos: -1", "seconds: 10 nanos: 1000000000"] {
+			assert_eq!(
+				Timestamp::from_text_format(text),
+				Err(TimestampError::ParseFailure),
+				"{text}"
+			);
+		}
+
+		for text in [
+			"nanos: 1000000000",
+			"nanos: -1000000000",
+			"seconds: 3 nanos: -20",
+			"seconds: -3 nanos: 20",
+		] {
+			assert_eq!(
+				Duration::from_text_format(text),
+				Err(DurationError::ParseFailure),
+				"{text}"
+			);
+		}
+
+		assert_eq!(
+			Duration::from_text_format("nanos: -999999999"),
+			Ok(Duration {
+				seconds: 0,
+				nanos: -999_999_999
+			})
+		);
+	}
+
+	#[test]
+	fn status() {
+		let status = Status {
+			code: 3,
+			message: "invalid \"name\"".into(),
+			details: vec![Any {
+				type_url: "type.googleapis.com/google.protobuf.Duration".into(),
+				value: vec![8, 10, b'a'],
+			}],
+		};
+
+		assert_eq!(
+			status.to_text_format(),
+			"code: 3\nmessage: \"invalid \\\"name\\\"\"\ndetails {\n  type_url: \"type.googleapis.com/google.protobuf.Duration\"\n  value: \"\\010\\na\"\n}\n"
+		);
+	}
+}
@@ -0,0 +1,267 @@
+use crate::{Duration, Timestamp};
+
+/// An expiration time, expressed as an absolute [`Timestamp`], as a time to live counted from the moment it was issued, or both.
+///
+/// Relative expiries always carry the instant they were issued at, so that the time left can be computed at any later point.
+///
+/// With the `serde` feature, it is (de)serialized as a timestamp string (`"2024-01-01T00:00:00Z"`) or as an object with `at`
+/// and/or `issued_at` and `ttl`, such as `{ "issued_at": "2024-01-01T00:00:00Z", "ttl": "3600s" }`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Expiry {
+	/// Expires at the given point in time.
+	At(Timestamp),
+	/// Expires once the time to live has elapsed since the time of issue.
+	After { issued_at: Timestamp, ttl: Duration },
+	/// Expires at whichever of the two limits comes first.
+	Earliest {
+		at: Timestamp,
+		issued_at: Timestamp,
+		ttl: Duration,
+	},
+}
+
+impl Expiry {
+	/// Creates an expiry that is reached once `ttl` has elapsed since `issued_at`.
+	#[must_use]
+	#[inline]
+	pub const fn after(issued_at: Timestamp, ttl: Duration) -> Self {
+		Self::After { issued_at, ttl }
+	}
+
+	/// Returns the absolute expiration time.
+	#[must_use]
+	pub fn resolve(&self) -> Timestamp {
+		match self {
+			Self::At(at) => *at,
+			Self::After { issued_at, ttl } => issued_at + ttl,
+			Self::Earliest { at, issued_at, ttl } => (*at).min(issued_at + ttl),
+		}
+	}
+
+	/// Returns the time left until the expiration, or `None` if it has already expired at the time `now`.
+	#[must_use]
+	pub fn remaining(&self, now: &Timestamp) -> Option<Duration> {
+		let remaining = Duration::from_total_nanos(
+			self.resolve()
+				.total_nanos()
+				.checked_sub(now.total_nanos())?,
+		)?;
+
+		(remaining > Duration::default()).then_some(remaining)
+	}
+
+	/// Returns `true` if the expiration time has been reached at the time `now`.
+	#[must_use]
+	#[inline]
+	pub fn is_expired(&self, now: &Timestamp) -> bool {
+		self.remaining(now).is_none()
+	}
+}
+
+impl From<Timestamp> for Expiry {
+	#[inline]
+	fn from(value: Timestamp) -> Self {
+		Self::At(value)
+	}
+}
+
+#[cfg(feature = "serde")]
+mod serde {
+	use core::fmt;
+
+	use serde::{
+		Deserialize, Deserializer, Serialize, Serializer,
+		de::{self, MapAccess},
+		ser::SerializeStruct,
+	};
+
+	use super::Expiry;
+	use crate::{Duration, String, Timestamp};
+
+	impl Serialize for Expiry {
+		fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+		where
+			S: Serializer,
+		{
+			match self {
+				Self::At(at) => at.serialize(serializer),
+				Self::After { issued_at, ttl } => {
+					let mut state = serializer.serialize_struct("Expiry", 2)?;
+					state.serialize_field("issued_at", issued_at)?;
+					state.serialize_field("ttl", ttl)?;
+					state.end()
+				}
+				Self::Earliest { at, issued_at, ttl } => {
+					let mut state = serializer.serialize_struct("Expiry", 3)?;
+					state.serialize_field("at", at)?;
+					state.serialize_field("issued_at", issued_at)?;
+					state.serialize_field("ttl", ttl)?;
+					state.end()
+				}
+			}
+		}
+	}
+
+	impl<'de> Deserialize<'de> for Expiry {
+		fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+		where
+			D: Deserializer<'de>,
+		{
+			const FIELDS: &[&str] = &["at", "issued_at", "ttl"];
+
+			struct ExpiryVisitor;
+
+			impl<'de> de::Visitor<'de> for ExpiryVisitor {
+				type Value = Expiry;
+
+				fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+					formatter.write_str(
+						"an RFC 3339 timestamp string or an object with `at` and/or `issued_at` and `ttl`",
+					)
+				}
+
+				fn visit_str<E>(self, value: &str) -> Result<Expiry, E>
+				where
+					E: de::Error,
+				{
+					value
+						.parse::<Timestamp>()
+						.map(Expiry::At)
+						.map_err(|_| de::Error::invalid_value(de::Unexpected::Str(value), &self))
+				}
+
+				fn visit_map<A>(self, mut map: A) -> Result<Expiry, A::Error>
+				where
+					A: MapAccess<'de>,
+				{
+					let mut at: Option<Timestamp> = None;
+					let mut issued_at: Option<Timestamp> = None;
+					let mut ttl: Option<Duration> = None;
+
+					while let Some(key) = map.next_key::<String>()? {
+						match key.as_str() {
+							"at" => {
+								if at.is_some() {
+									return Err(de::Error::duplicate_field("at"));
+								}
+								at = Some(map.next_value()?);
+							}
+							"issued_at" => {
+								if issued_at.is_some() {
+									return Err(de::Error::duplicate_field("issued_at"));
+								}
+								issued_at = Some(map.next_value()?);
+							}
+							"ttl" => {
+								if ttl.is_some() {
+									return Err(de::Error::duplicate_field("ttl"));
+								}
+								ttl = Some(map.next_value()?);
+							}
+							_ => return Err(de::Error::unknown_field(&key, FIELDS)),
+						}
+					}
+
+					match (at, issued_at, ttl) {
+						(Some(at), Some(issued_at), Some(ttl)) => {
+							Ok(Expiry::Earliest { at, issued_at, ttl })
+						}
+						(Some(at), None, None) => Ok(Expiry::At(at)),
+						(None, Some(issued_at), Some(ttl)) => Ok(Expiry::After { issued_at, ttl }),
+						(_, None, Some(_)) => Err(de::Error::missing_field("issued_at")),
+						(_, Some(_), None) => Err(de::Error::missing_field("ttl")),
+						(None, None, None) => Err(de::Error::missing_field("at")),
+					}
+				}
+			}
+
+			deserializer.deserialize_any(ExpiryVisitor)
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn resolve_and_remaining() {
+		let issued_at = Timestamp::new(1_000, 0);
+
+		let absolute = Expiry::At(Timestamp::new(1_060, 0));
+		let relative = Expiry::after(issued_at, Duration::new(30, 0));
+		let both = Expiry::Earliest {
+			at: Timestamp::new(1_060, 0),
+			issued_at,
+			ttl: Duration::new(90, 0),
+		};
+
+		assert_eq!(absolute.resolve(), Timestamp::new(1_060, 0));
+		assert_eq!(relative.resolve(), Timestamp::new(1_030, 0));
+		assert_eq!(both.resolve(), Timestamp::new(1_060, 0));
+
+		assert_eq!(absolute.remaining(&issued_at), Some(Duration::new(60, 0)));
+		assert_eq!(relative.remaining(&issued_at), Some(Duration::new(30, 0)));
+		assert_eq!(
+			relative.remaining(&Timestamp::new(1_020, 0)),
+			Some(Duration::new(10, 0))
+		);
+		assert_eq!(absolute.remaining(&Timestamp::new(1_060, 0)), None);
+		assert!(absolute.is_expired(&Timestamp::new(2_000, 0)));
+		assert!(relative.is_expired(&Timestamp::new(1_030, 0)));
+		assert!(relative.is_expired(&Timestamp::new(2_000, 0)));
+		assert!(!both.is_expired(&Timestamp::new(1_059, 0)));
+	}
+
+	#[cfg(feature = "serde")]
+	#[test]
+	fn serde_forms() {
+		use ::serde::Deserialize;
+		use serde_json::json;
+
+		let parse = |value: serde_json::Value| Expiry::deserialize(value).unwrap();
+
+		assert_eq!(
+			parse(json!("1970-01-01T00:16:40Z")),
+			Expiry::At(Timestamp::new(1_000, 0))
+		);
+		assert_eq!(
+			parse(json!({ "issued_at": "1970-01-01T00:16:40Z", "ttl": "1.5s" })),
+			Expiry::after(Timestamp::new(1_000, 0), Duration::new(1, 500_000_000))
+		);
+		assert_eq!(
+			parse(
+				json!({ "at": "1970-01-01T00:16:40Z", "issued_at": "1970-01-01T00:00:00Z", "ttl": "60s" })
+			),
+			Expiry::Earliest {
+				at: Timestamp::new(1_000, 0),
+				issued_at: Timestamp::new(0, 0),
+				ttl: Duration::new(60, 0)
+			}
+		);
+		assert_eq!(
+			parse(json!({ "at": "1970-01-01T00:16:40Z" })),
+			Expiry::At(Timestamp::new(1_000, 0))
+		);
+		assert!(Expiry::deserialize(json!("60s")).is_err());
+		assert!(Expiry::deserialize(json!({ "ttl": "60s" })).is_err());
+		assert!(Expiry::deserialize(json!({ "issued_at": "1970-01-01T00:16:40Z" })).is_err());
+		assert!(Expiry::deserialize(json!("tomorrow")).is_err());
+		assert!(Expiry::deserialize(json!({})).is_err());
+
+		let relative = Expiry::after(Timestamp::new(1_000, 0), Duration::new(60, 0));
+		assert_eq!(
+			serde_json::to_value(relative).unwrap(),
+			json!({ "issued_at": "1970-01-01T00:16:40Z", "ttl": "60s" })
+		);
+		assert_eq!(
+			serde_json::to_value(Expiry::Earliest {
+				at: Timestamp::new(1_000, 0),
+				issued_at: Timestamp::new(0, 0),
+				ttl: Duration::new(60, 0)
+			})
+			.unwrap(),
+			json!({ "at": "1970-01-01T00:16:40Z", "issued_at": "1970-01-01T00:00:00Z", "ttl": "60s" })
+		);
+	}
+}
@@ -4,15 +4,36 @@
 #[cfg(feature = "serde")]
 mod serde;
 
+mod expiry;
+pub use expiry::Expiry;
+mod timestamp_bytes;
+mod timestamp_calendar;
 mod timestamp_conversions;
+mod timestamp_grid;
+mod timestamp_http;
 mod timestamp_impls;
+mod timestamp_jwt;
 mod timestamp_operations;
+mod timestamp_partition;
+mod timestamp_precision;
+pub use timestamp_precision::{PrecisionTimestamp, TimePrecision};
+mod timestamp_rate;
+pub use timestamp_rate::{Debouncer, Throttle};
+mod timestamp_skew;
+mod timestamp_sql;
+#[cfg(feature = "interval")]
+pub(crate) use timestamp_sql::quote_sql_identifier;
+#[cfg(feature = "std")]
+mod timestamp_system_time;
+#[cfg(feature = "std")]
+pub use timestamp_system_time::SystemTimeRangeError;
 
 use super::*;
 use crate::{
-	Timestamp,
+	ProtoValidate, Timestamp,
 	constants::{NANOS_PER_SECOND, PACKAGE_PREFIX},
 	datetime_internal::DateTime,
+	invariants::{InvariantReport, InvariantViolation},
 };
 
 impl Timestamp {
@@ -21,7 +42,18 @@ impl Timestamp {
 	/// Based on [`google::protobuf::util::CreateNormalized`][1].
 	///
 	/// [1]: https://github.com/google/protobuf/blob/v3.3.2/src/google/protobuf/util/time_util.cc#L59-L77
+	#[inline]
 	pub fn normalize(&mut self) {
+		// Fast path for values that are already normalized, which is the case for almost every decoded message
+		if (0..NANOS_PER_SECOND).contains(&self.nanos) {
+			return;
+		}
+
+		self.normalize_slow();
+	}
+
+	#[cold]
+	fn normalize_slow(&mut self) {
 		// Make sure nanos is in the range.
 		if self.nanos <= -NANOS_PER_SECOND || self.nanos >= NANOS_PER_SECOND {
 			if let Some(seconds) = self
@@ -225,6 +257,8 @@ pub enum TimestampError {
 	ParseFailure,
 	/// Indicates an error when constructing a timestamp due to invalid date or time data.
 	InvalidDateTime,
+	/// Indicates that a time zone ID is not present in the IANA time zone database.
+	UnknownTimeZone(String),
 }
 
 impl fmt::Display for TimestampError {
@@ -244,49 +278,85 @@ impl fmt::Display for TimestampError {
 			Self::InvalidDateTime => {
 				write!(f, "invalid date or time")
 			}
+
+			Self::UnknownTimeZone(id) => {
+				write!(f, "unknown time zone: {id}")
+			}
 		}
 	}
 }
 
 impl core::error::Error for TimestampError {}
 
-#[cfg(feature = "std")]
-impl TryFrom<Timestamp> for std::time::SystemTime {
-	type Error = TimestampError;
+impl ProtoValidate for Timestamp {
+	type Error = InvariantReport;
 
-	fn try_from(mut timestamp: Timestamp) -> Result<Self, Self::Error> {
-		let orig_timestamp = timestamp;
+	/// Checks the invariants described in [`check_invariants`].
+	#[inline]
+	fn validate(&self) -> Result<(), Self::Error> {
+		check_invariants(self).into_result()
+	}
+}
 
-		timestamp.normalize();
+/// The seconds of `0001-01-01T00:00:00Z`, the earliest timestamp allowed by the protobuf spec.
+const MIN_SECONDS: i64 = -62_135_596_800;
+/// The seconds of `9999-12-31T23:59:59Z`, the latest timestamp allowed by the protobuf spec.
+const MAX_SECONDS: i64 = 253_402_300_799;
+
+/// Checks that the timestamp is within the range allowed by the protobuf spec, that the nanos are within `0..=999_999_999` and that normalization is idempotent.
+///
+/// Intended for sanity checks on values decoded from untrusted sources, in `debug_assert!` calls or fuzzing harnesses.
+#[must_use]
+pub fn check_invariants(timestamp: &Timestamp) -> InvariantReport {
+	let mut report = InvariantReport::default();
+
+	report.check(
+		(0..NANOS_PER_SECOND).contains(&timestamp.nanos),
+		InvariantViolation::NanosOutOfRange {
+			nanos: timestamp.nanos,
+		},
+	);
+	report.check(
+		(MIN_SECONDS..=MAX_SECONDS).contains(&timestamp.seconds),
+		InvariantViolation::SecondsOutOfRange {
+			seconds: timestamp.seconds,
+		},
+	);
+
+	let normalized = timestamp.normalized();
+
+	report.check(normalized == *timestamp, InvariantViolation::NotNormalized);
+	report.check(
+		normalized.normalized() == normalized,
+		InvariantViolation::NormalizationNotIdempotent,
+	);
+
+	report
+}
 
-		let system_time = if timestamp.seconds >= 0 {
-			std::time::UNIX_EPOCH.checked_add(time::Duration::from_secs(
-				timestamp
-					.seconds
-					.try_into()
-					.map_err(|_| TimestampError::OutOfSystemRange(timestamp))?,
-			))
-		} else {
-			std::time::UNIX_EPOCH.checked_sub(time::Duration::from_secs(
-				timestamp
-					.seconds
-					.checked_neg()
-					.and_then(|s| s.try_into().ok())
-					.ok_or(TimestampError::OutOfSystemRange(timestamp))?,
-			))
-		};
+impl Timestamp {
+	/// Sets the seconds, checking that they are within the range allowed by the protobuf spec (`0001-01-01T00:00:00Z` to `9999-12-31T23:59:59Z`).
+	///
+	/// The timestamp is left unchanged if the check fails.
+	pub const fn set_seconds_checked(&mut self, seconds: i64) -> Result<(), InvariantViolation> {
+		if seconds < MIN_SECONDS || seconds > MAX_SECONDS {
+			return Err(InvariantViolation::SecondsOutOfRange { seconds });
+		}
 
-		let system_time = system_time
-			.map(|time| {
-				let nanos = u64::try_from(timestamp.nanos)
-					.map_err(|_| TimestampError::OutOfSystemRange(timestamp))?;
+		self.seconds = seconds;
+		Ok(())
+	}
 
-				time.checked_add(core::time::Duration::from_nanos(nanos))
-					.ok_or(TimestampError::OutOfSystemRange(timestamp))
-			})
-			.transpose()?;
+	/// Sets the nanos, checking that they are within `0..=999_999_999`.
+	///
+	/// The timestamp is left unchanged if the check fails.
+	pub const fn set_nanos_checked(&mut self, nanos: i32) -> Result<(), InvariantViolation> {
+		if nanos < 0 || nanos >= NANOS_PER_SECOND {
+			return Err(InvariantViolation::NanosOutOfRange { nanos });
+		}
 
-		system_time.ok_or(TimestampError::OutOfSystemRange(orig_timestamp))
+		self.nanos = nanos;
+		Ok(())
 	}
 }
 
@@ -298,8 +368,66 @@ impl FromStr for Timestamp {
 	}
 }
 
+impl Timestamp {
+	/// Writes the RFC 3339 representation of this timestamp (the same as its [`Display`](fmt::Display) output) into the given writer, without allocating.
+	#[inline]
+	pub fn write_rfc3339<W: fmt::Write + ?Sized>(&self, w: &mut W) -> fmt::Result {
+		DateTime::from(*self).write_rfc3339(w)
+	}
+}
+
 impl fmt::Display for Timestamp {
 	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-		core::fmt::Display::fmt(&DateTime::from(*self), f)
+		self.write_rfc3339(f)
+	}
+}
+
+impl fmt::Debug for Timestamp {
+	/// The alternate form (`{:#?}`) shows both the RFC 3339 rendering and the raw fields.
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if f.alternate() {
+			write!(
+				f,
+				"Timestamp({self}; seconds={}, nanos={})",
+				self.seconds, self.nanos
+			)
+		} else {
+			f.debug_struct("Timestamp")
+				.field("seconds", &self.seconds)
+				.field("nanos", &self.nanos)
+				.finish()
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn fast_path_matches_full_normalization() {
+		let seconds = [i64::MIN, -1, 0, 1, i64::MAX];
+		let nanos = [
+			i32::MIN,
+			-NANOS_PER_SECOND,
+			-1,
+			0,
+			1,
+			NANOS_PER_SECOND - 1,
+			NANOS_PER_SECOND,
+			i32::MAX,
+		];
+
+		for seconds in seconds {
+			for nanos in nanos {
+				let mut fast = Timestamp { seconds, nanos };
+				let mut slow = fast;
+
+				fast.normalize();
+				slow.normalize_slow();
+
+				assert_eq!(fast, slow, "{seconds}s {nanos}ns");
+			}
+		}
 	}
 }
@@ -2,17 +2,15 @@ use core::fmt;
 
 use serde::{de, ser};
 
-use crate::{Timestamp, ToString, format};
+use crate::{Timestamp, format};
 
 impl ser::Serialize for Timestamp {
 	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
 	where
 		S: ser::Serializer,
 	{
-		let mut ts_normalized = *self;
-		ts_normalized.normalize();
-
-		serializer.serialize_str(&self.to_string())
+		// Written directly into the serializer, without an intermediate String
+		serializer.collect_str(&self.normalized())
 	}
 }
 
@@ -34,16 +32,10 @@ impl<'de> de::Deserialize<'de> for Timestamp {
 			where
 				E: de::Error,
 			{
-				let datetime = value.parse::<Timestamp>().map_err(|e| {
-					de::Error::custom(format!("Invalid timestamp string format: {e}"))
-				})?;
-
-				let prost_timestamp = Timestamp {
-					seconds: datetime.seconds,
-					nanos: datetime.nanos,
-				};
-
-				Ok(prost_timestamp)
+				// Parsed in place, so borrowed and transient strings are handled without allocating
+				value
+					.parse::<Timestamp>()
+					.map_err(|e| de::Error::custom(format!("Invalid timestamp string format: {e}")))
 			}
 		}
 
@@ -0,0 +1,97 @@
+use super::*;
+use crate::fixed_bytes;
+
+impl Timestamp {
+	/// The size of the fixed-size binary encoding of a [`Timestamp`].
+	pub const BYTES_LEN: usize = fixed_bytes::BYTES_LEN;
+
+	/// Encodes the timestamp into 12 big-endian bytes, for compact storage in custom binary formats or FFI.
+	///
+	/// The layout is the normalized seconds as an `i64`, followed by the nanos as an `i32`, both in big-endian order.
+	/// This is unrelated to the protobuf wire encoding. Unlike the protobuf encoding, timestamps at or after the unix epoch sort bytewise in chronological order.
+	#[must_use]
+	pub fn to_be_bytes(&self) -> [u8; Self::BYTES_LEN] {
+		let normalized = self.normalized();
+
+		fixed_bytes::to_be_bytes(normalized.seconds, normalized.nanos)
+	}
+
+	/// Encodes the timestamp into 12 little-endian bytes, with the same layout as [`to_be_bytes`](Self::to_be_bytes).
+	#[must_use]
+	pub fn to_le_bytes(&self) -> [u8; Self::BYTES_LEN] {
+		let normalized = self.normalized();
+
+		fixed_bytes::to_le_bytes(normalized.seconds, normalized.nanos)
+	}
+
+	/// Decodes a timestamp from the 12 big-endian bytes produced by [`to_be_bytes`](Self::to_be_bytes).
+	///
+	/// Fails if the nanos are not within `0..=999_999_999`.
+	pub fn from_be_bytes(bytes: [u8; Self::BYTES_LEN]) -> Result<Self, InvariantViolation> {
+		let (seconds, nanos) = fixed_bytes::from_be_bytes(bytes);
+
+		Self::from_parts_checked(seconds, nanos)
+	}
+
+	/// Decodes a timestamp from the 12 little-endian bytes produced by [`to_le_bytes`](Self::to_le_bytes).
+	///
+	/// Fails if the nanos are not within `0..=999_999_999`.
+	pub fn from_le_bytes(bytes: [u8; Self::BYTES_LEN]) -> Result<Self, InvariantViolation> {
+		let (seconds, nanos) = fixed_bytes::from_le_bytes(bytes);
+
+		Self::from_parts_checked(seconds, nanos)
+	}
+
+	/// Checks the decoded nanos with the same rules as [`set_nanos_checked`](Self::set_nanos_checked).
+	const fn from_parts_checked(seconds: i64, nanos: i32) -> Result<Self, InvariantViolation> {
+		let mut value = Self { seconds, nanos: 0 };
+
+		match value.set_nanos_checked(nanos) {
+			Ok(()) => Ok(value),
+			Err(e) => Err(e),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn bytes_round_trip() {
+		let ts = Timestamp::new(1_700_000_000, 123_456_789);
+
+		let be = ts.to_be_bytes();
+		assert_eq!(&be[..8], &1_700_000_000_i64.to_be_bytes());
+		assert_eq!(&be[8..], &123_456_789_i32.to_be_bytes());
+		assert_eq!(Timestamp::from_be_bytes(be), Ok(ts));
+		assert_eq!(Timestamp::from_le_bytes(ts.to_le_bytes()), Ok(ts));
+
+		// Not normalized
+		let unnormalized = Timestamp {
+			seconds: 10,
+			nanos: -1,
+		};
+		assert_eq!(
+			Timestamp::from_be_bytes(unnormalized.to_be_bytes()),
+			Ok(unnormalized.normalized())
+		);
+
+		let mut invalid = be;
+		invalid[8..].copy_from_slice(&1_000_000_000_i32.to_be_bytes());
+		assert_eq!(
+			Timestamp::from_be_bytes(invalid),
+			Err(InvariantViolation::NanosOutOfRange {
+				nanos: 1_000_000_000
+			})
+		);
+	}
+
+	#[test]
+	fn big_endian_order() {
+		let earlier = Timestamp::new(100, 999_999_999).to_be_bytes();
+		let later = Timestamp::new(101, 0).to_be_bytes();
+
+		assert!(earlier < later);
+	}
+}
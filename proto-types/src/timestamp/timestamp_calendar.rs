@@ -0,0 +1,123 @@
+use crate::{
+	Timestamp,
+	calendar::{days_in_month, days_in_year, is_leap_year},
+	datetime_internal::DateTime,
+	timestamp::TimestampError,
+};
+
+impl Timestamp {
+	/// Returns `true` if this timestamp falls in a leap year, in UTC.
+	#[must_use]
+	#[inline]
+	pub fn is_leap_year_utc(&self) -> bool {
+		is_leap_year(DateTime::from(*self).year)
+	}
+
+	/// Returns the number of days (365 or 366) in the year of this timestamp, in UTC.
+	#[must_use]
+	#[inline]
+	pub fn days_in_year_utc(&self) -> u16 {
+		days_in_year(DateTime::from(*self).year)
+	}
+
+	/// Returns `true` if this timestamp falls on the last day of its month, in UTC.
+	#[must_use]
+	#[inline]
+	pub fn is_last_day_of_month_utc(&self) -> bool {
+		let datetime = DateTime::from(*self);
+
+		datetime.day == days_in_month(datetime.year, datetime.month)
+	}
+
+	/// Returns the quarter of the year (from 1 to 4) that this timestamp falls in, in UTC.
+	#[must_use]
+	#[inline]
+	pub fn quarter(&self) -> u8 {
+		(DateTime::from(*self).month - 1) / 3 + 1
+	}
+
+	/// Returns the start of the first day of the month of this timestamp, in UTC.
+	#[inline]
+	pub fn first_of_month(&self) -> Result<Self, TimestampError> {
+		let datetime = DateTime::from(*self);
+
+		Self::date(datetime.year, datetime.month, 1)
+	}
+
+	/// Returns the start of the last day of the month of this timestamp, in UTC.
+	#[inline]
+	pub fn last_of_month(&self) -> Result<Self, TimestampError> {
+		let datetime = DateTime::from(*self);
+
+		Self::date(
+			datetime.year,
+			datetime.month,
+			days_in_month(datetime.year, datetime.month),
+		)
+	}
+
+	/// Returns the start of the first day of the quarter of this timestamp, in UTC.
+	#[inline]
+	pub fn first_of_quarter(&self) -> Result<Self, TimestampError> {
+		let datetime = DateTime::from(*self);
+
+		Self::date(datetime.year, (self.quarter() - 1) * 3 + 1, 1)
+	}
+
+	/// Returns the start of the last day of the quarter of this timestamp, in UTC.
+	#[inline]
+	pub fn last_of_quarter(&self) -> Result<Self, TimestampError> {
+		let datetime = DateTime::from(*self);
+		let month = self.quarter() * 3;
+
+		Self::date(datetime.year, month, days_in_month(datetime.year, month))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn ts(value: &str) -> Timestamp {
+		value.parse().unwrap()
+	}
+
+	#[test]
+	fn month_and_quarter_boundaries() {
+		let leap = ts("2024-02-10T15:30:00Z");
+
+		assert_eq!(leap.quarter(), 1);
+		assert_eq!(leap.first_of_month(), Ok(ts("2024-02-01T00:00:00Z")));
+		assert_eq!(leap.last_of_month(), Ok(ts("2024-02-29T00:00:00Z")));
+		assert_eq!(leap.first_of_quarter(), Ok(ts("2024-01-01T00:00:00Z")));
+		assert_eq!(leap.last_of_quarter(), Ok(ts("2024-03-31T00:00:00Z")));
+
+		assert_eq!(
+			ts("1900-02-10T00:00:00Z").last_of_month(),
+			Ok(ts("1900-02-28T00:00:00Z"))
+		);
+		assert_eq!(
+			ts("2000-02-10T00:00:00Z").last_of_month(),
+			Ok(ts("2000-02-29T00:00:00Z"))
+		);
+
+		let summer = ts("2023-08-31T23:59:59.999Z");
+		assert_eq!(summer.quarter(), 3);
+		assert_eq!(summer.first_of_quarter(), Ok(ts("2023-07-01T00:00:00Z")));
+		assert_eq!(summer.last_of_quarter(), Ok(ts("2023-09-30T00:00:00Z")));
+	}
+
+	#[test]
+	fn calendar_predicates() {
+		assert!(ts("2024-07-01T00:00:00Z").is_leap_year_utc());
+		assert!(!ts("1900-07-01T00:00:00Z").is_leap_year_utc());
+		assert_eq!(ts("2000-01-01T00:00:00Z").days_in_year_utc(), 366);
+		assert_eq!(ts("2023-01-01T00:00:00Z").days_in_year_utc(), 365);
+
+		assert!(ts("2024-02-29T23:59:59Z").is_last_day_of_month_utc());
+		assert!(!ts("2024-02-28T12:00:00Z").is_last_day_of_month_utc());
+		assert!(ts("2023-04-30T00:00:00Z").is_last_day_of_month_utc());
+		// The day is taken in UTC, regardless of the offset used for parsing
+		assert!(ts("2023-05-01T01:00:00+02:00").is_last_day_of_month_utc());
+	}
+}
@@ -0,0 +1,56 @@
+use crate::{Duration, RoundingMode, Timestamp};
+
+impl Timestamp {
+	/// Aligns this timestamp to the start of the slot that contains it, in a grid of slots of length `step` starting at `origin`.
+	///
+	/// For example, with 15-minute booking slots, `10:07` snaps to `10:00`. Timestamps before `origin` snap to the slots that precede it.
+	/// The sign of `step` is ignored. Returns `None` if `step` is zero or if the result overflows.
+	#[must_use]
+	pub fn snap_to_grid(&self, origin: Self, step: Duration) -> Option<Self> {
+		self.snap_to_grid_with(origin, step, RoundingMode::Floor)
+	}
+
+	/// Aligns this timestamp to a boundary in a grid of slots of length `step` starting at `origin`, using the given [`RoundingMode`].
+	///
+	/// The sign of `step` is ignored. Returns `None` if `step` is zero or if the result overflows.
+	#[must_use]
+	pub fn snap_to_grid_with(
+		&self,
+		origin: Self,
+		step: Duration,
+		mode: RoundingMode,
+	) -> Option<Self> {
+		let offset = Duration::from_total_nanos(self.total_nanos() - origin.total_nanos())?;
+		let snapped = offset.quantize(step, mode)?;
+
+		Self::from_total_nanos(origin.total_nanos() + snapped.total_nanos())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn booking_slots() {
+		let origin = Timestamp::new(36_000, 0);
+		let slot = Duration::new(900, 0);
+
+		assert_eq!(
+			Timestamp::new(36_420, 5).snap_to_grid(origin, slot),
+			Some(origin)
+		);
+		assert_eq!(
+			Timestamp::new(35_999, 0).snap_to_grid(origin, slot),
+			Some(Timestamp::new(35_100, 0))
+		);
+		assert_eq!(
+			Timestamp::new(36_420, 0).snap_to_grid_with(origin, slot, RoundingMode::Ceil),
+			Some(Timestamp::new(36_900, 0))
+		);
+		assert_eq!(
+			Timestamp::new(36_420, 0).snap_to_grid(origin, Duration::new(0, 0)),
+			None
+		);
+	}
+}
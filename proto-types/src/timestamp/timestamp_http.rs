@@ -0,0 +1,138 @@
+use core::fmt::Write;
+
+use super::{MAX_SECONDS, MIN_SECONDS, TimestampError};
+use crate::{String, Timestamp, datetime_internal::DateTime};
+
+const WEEKDAYS: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+const MONTHS: [&str; 12] = [
+	"Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// Returns the index of the day of the week in [`WEEKDAYS`] (1970-01-01 was a Thursday).
+const fn weekday_index(seconds: i64) -> usize {
+	// SAFETY: rem_euclid(7) is always within 0..7
+	#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+	let index = (seconds.div_euclid(86_400) + 4).rem_euclid(7) as usize;
+	index
+}
+
+impl Timestamp {
+	/// Formats the timestamp as an HTTP date in the IMF-fixdate format, such as `Tue, 07 May 2024 10:00:00 GMT`, for headers such as `Last-Modified` or `Expires`.
+	///
+	/// HTTP dates have a precision of one second, so the nanos are truncated.
+	/// Fails if the timestamp is outside of the range allowed by the protobuf spec, which cannot be written as a 4-digit year.
+	pub fn to_http_date(&self) -> Result<String, TimestampError> {
+		let timestamp = self.normalized();
+
+		if !(MIN_SECONDS..=MAX_SECONDS).contains(&timestamp.seconds) {
+			return Err(TimestampError::InvalidDateTime);
+		}
+
+		let dt = DateTime::from(timestamp);
+		let mut output = String::with_capacity(29);
+
+		let _ = write!(
+			output,
+			"{}, {:02} {} {:04} {:02}:{:02}:{:02} GMT",
+			WEEKDAYS[weekday_index(timestamp.seconds)],
+			dt.day,
+			MONTHS[usize::from(dt.month - 1)],
+			dt.year,
+			dt.hour,
+			dt.minute,
+			dt.second
+		);
+
+		Ok(output)
+	}
+
+	/// Parses an HTTP date in the IMF-fixdate format, such as `Tue, 07 May 2024 10:00:00 GMT`.
+	///
+	/// The obsolete RFC 850 and asctime formats are not supported. Fails if the day of the week does not match the date.
+	pub fn from_http_date(s: &str) -> Result<Self, TimestampError> {
+		parse_http_date(s).ok_or(TimestampError::ParseFailure)
+	}
+}
+
+fn parse_http_date(s: &str) -> Option<Timestamp> {
+	let (weekday, rest) = s.split_once(", ")?;
+	let mut parts = rest.split(' ');
+
+	let day = parts
+		.next()
+		.filter(|d| d.len() == 2)?
+		.parse()
+		.ok()?;
+	let month_name = parts.next()?;
+	let year = parts
+		.next()
+		.filter(|y| y.len() == 4)?
+		.parse()
+		.ok()?;
+	let time = parts.next()?;
+
+	if parts.next() != Some("GMT") || parts.next().is_some() {
+		return None;
+	}
+
+	let month = MONTHS.iter().position(|m| *m == month_name)? + 1;
+
+	let mut time_parts = time.split(':');
+	let mut next_time_part = || -> Option<u8> {
+		time_parts
+			.next()
+			.filter(|p| p.len() == 2)?
+			.parse()
+			.ok()
+	};
+
+	let (hour, minute, second) = (next_time_part()?, next_time_part()?, next_time_part()?);
+
+	if time_parts.next().is_some() {
+		return None;
+	}
+
+	let timestamp =
+		Timestamp::date_time(year, u8::try_from(month).ok()?, day, hour, minute, second).ok()?;
+
+	(WEEKDAYS[weekday_index(timestamp.seconds)] == weekday).then_some(timestamp)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::timestamp::TimePrecision;
+
+	#[test]
+	fn http_date_round_trip() {
+		let ts: Timestamp = "2024-05-07T10:00:00.75Z".parse().unwrap();
+
+		assert_eq!(ts.to_http_date().unwrap(), "Tue, 07 May 2024 10:00:00 GMT");
+		assert_eq!(
+			Timestamp::from_http_date("Tue, 07 May 2024 10:00:00 GMT").unwrap(),
+			ts.truncated(TimePrecision::Seconds)
+		);
+		assert_eq!(
+			Timestamp::new(-1, 0).to_http_date().unwrap(),
+			"Wed, 31 Dec 1969 23:59:59 GMT"
+		);
+	}
+
+	#[test]
+	fn invalid_http_dates() {
+		for s in [
+			"Wed, 07 May 2024 10:00:00 GMT",
+			"Tue, 7 May 2024 10:00:00 GMT",
+			"Tue, 07 May 2024 10:00:00 UTC",
+			"Tue, 07 Foo 2024 10:00:00 GMT",
+			"Tue, 07 May 2024 10:00 GMT",
+			"Tuesday, 07-May-24 10:00:00 GMT",
+		] {
+			assert_eq!(
+				Timestamp::from_http_date(s),
+				Err(TimestampError::ParseFailure),
+				"{s}"
+			);
+		}
+	}
+}
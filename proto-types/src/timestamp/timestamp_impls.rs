@@ -1,4 +1,6 @@
-use crate::{Duration, Timestamp};
+#[cfg(any(test, feature = "std", feature = "chrono-wasm"))]
+use crate::Duration;
+use crate::Timestamp;
 
 #[cfg(not(feature = "chrono"))]
 impl crate::Timestamp {
@@ -31,6 +33,87 @@ mod chrono {
 			(*self).try_into()
 		}
 	}
+
+	#[cfg(feature = "std")]
+	impl Timestamp {
+		/// Returns the current time as a [`chrono::DateTime`] in the local time zone of the host.
+		#[must_use]
+		#[inline]
+		pub fn now_local_datetime() -> chrono::DateTime<chrono::Local> {
+			chrono::Local::now()
+		}
+
+		/// Converts this [`Timestamp`] instance to chrono::[`DateTime`](::chrono::DateTime) in the local time zone of the host.
+		#[inline]
+		pub fn to_local_datetime(&self) -> Result<chrono::DateTime<chrono::Local>, TimestampError> {
+			Ok(self
+				.as_datetime_utc()?
+				.with_timezone(&chrono::Local))
+		}
+	}
+}
+
+#[cfg(all(feature = "chrono", feature = "chrono-tz", feature = "datetime"))]
+mod chrono_tz {
+	use core::str::FromStr;
+
+	use chrono::DateTime;
+	use chrono_tz::Tz;
+
+	use crate::{String, TimeZone, Timestamp, ToString, timestamp::TimestampError};
+
+	impl Timestamp {
+		/// Converts this timestamp into the local date and time of the given time zone.
+		pub fn to_datetime_in_zone(&self, zone: &TimeZone) -> Result<DateTime<Tz>, TimestampError> {
+			let tz = Tz::from_str(&zone.id)
+				.map_err(|_| TimestampError::UnknownTimeZone(zone.id.clone()))?;
+
+			Ok(self.as_datetime_utc()?.with_timezone(&tz))
+		}
+
+		/// Formats this timestamp as the local date and time of the given time zone, using the [`chrono` format syntax](chrono::format::strftime).
+		pub fn format_in_zone(&self, zone: &TimeZone, fmt: &str) -> Result<String, TimestampError> {
+			Ok(self
+				.to_datetime_in_zone(zone)?
+				.format(fmt)
+				.to_string())
+		}
+	}
+
+	#[cfg(test)]
+	mod tests {
+		use super::*;
+
+		fn zone(id: &str) -> TimeZone {
+			TimeZone {
+				id: id.into(),
+				version: String::new(),
+			}
+		}
+
+		#[test]
+		fn format_in_zone() {
+			let timestamp: Timestamp = "2024-07-01T10:00:00Z".parse().unwrap();
+
+			assert_eq!(
+				timestamp
+					.format_in_zone(&zone("Europe/Rome"), "%Y-%m-%d %H:%M %Z")
+					.unwrap(),
+				"2024-07-01 12:00 CEST"
+			);
+			assert_eq!(
+				timestamp
+					.to_datetime_in_zone(&zone("America/New_York"))
+					.unwrap()
+					.to_rfc3339(),
+				"2024-07-01T06:00:00-04:00"
+			);
+			assert_eq!(
+				timestamp.format_in_zone(&zone("Mars/Olympus"), "%H"),
+				Err(TimestampError::UnknownTimeZone("Mars/Olympus".into()))
+			);
+		}
+	}
 }
 
 impl Timestamp {
@@ -40,6 +123,15 @@ impl Timestamp {
 	pub const fn new(seconds: i64, nanos: i32) -> Self {
 		Self { seconds, nanos }
 	}
+
+	/// Parses an RFC 3339 timestamp in a const context, such as `2024-05-01T10:00:00Z`.
+	///
+	/// Accepts a `Z` or `±HH:MM` offset and up to 9 fractional digits. Returns `None` if the string is invalid.
+	#[must_use]
+	#[inline]
+	pub const fn const_from_rfc3339(value: &str) -> Option<Self> {
+		crate::macros::parse_timestamp(value)
+	}
 }
 
 #[cfg(all(not(feature = "std"), feature = "chrono-wasm"))]
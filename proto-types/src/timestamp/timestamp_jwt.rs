@@ -0,0 +1,125 @@
+use crate::{Duration, Timestamp, constants::NANOS_PER_SECOND, timestamp::TimestampError};
+
+impl Timestamp {
+	/// Creates a timestamp from a JWT `NumericDate` (the number of seconds since the unix epoch), as found in the `exp`, `nbf` and `iat` claims.
+	#[must_use]
+	#[inline]
+	pub const fn from_jwt_numeric_date(seconds: i64) -> Self {
+		Self { seconds, nanos: 0 }
+	}
+
+	/// Creates a timestamp from a fractional JWT `NumericDate`, rounding to the nearest nanosecond.
+	///
+	/// Negative values are handled correctly, so that `-1.5` becomes 1.5 seconds before the epoch. Returns an error if the value is not finite or out of range.
+	pub fn from_jwt_numeric_date_f64(value: f64) -> Result<Self, TimestampError> {
+		// The bounds of i64 as f64, with the upper one being exclusive
+		const MIN: f64 = -9_223_372_036_854_775_808.0;
+		const MAX: f64 = 9_223_372_036_854_775_808.0;
+
+		if !value.is_finite() || !(MIN..MAX).contains(&value) {
+			return Err(TimestampError::InvalidDateTime);
+		}
+
+		// SAFETY: the value was checked to be within the range of i64
+		#[allow(clippy::cast_possible_truncation)]
+		let mut seconds = value as i64;
+
+		// Casting truncates towards zero, so negative values must be floored manually
+		#[allow(clippy::cast_precision_loss)]
+		if (seconds as f64) > value {
+			seconds -= 1;
+		}
+
+		#[allow(clippy::cast_precision_loss)]
+		let fraction = value - seconds as f64;
+
+		// SAFETY: the fraction is within [0, 1)
+		#[allow(clippy::cast_possible_truncation)]
+		let nanos = (fraction * f64::from(NANOS_PER_SECOND) + 0.5) as i32;
+
+		let mut timestamp = Self { seconds, nanos };
+		timestamp.normalize();
+
+		Ok(timestamp)
+	}
+
+	/// Returns this timestamp as an integer JWT `NumericDate`, discarding the fractional seconds.
+	#[must_use]
+	pub fn to_jwt_numeric_date(&self) -> i64 {
+		self.normalized().seconds
+	}
+
+	/// Returns this timestamp as a fractional JWT `NumericDate`.
+	#[must_use]
+	pub fn to_jwt_numeric_date_f64(&self) -> f64 {
+		let normalized = self.normalized();
+
+		#[allow(clippy::cast_precision_loss)]
+		let seconds = normalized.seconds as f64;
+
+		seconds + f64::from(normalized.nanos) / f64::from(NANOS_PER_SECOND)
+	}
+
+	/// Returns `true` if this timestamp, used as an expiration time, has passed at the time `now`, allowing for a clock skew of `leeway`.
+	#[must_use]
+	#[inline]
+	pub fn is_expired_at(&self, now: &Self, leeway: Duration) -> bool {
+		!now.is_before_with_skew(self, leeway)
+	}
+}
+
+#[cfg(any(feature = "std", feature = "chrono-wasm"))]
+impl Timestamp {
+	/// Returns `true` if this timestamp, used as an expiration time, has already passed, allowing for a clock skew of `leeway`.
+	#[must_use]
+	#[inline]
+	pub fn is_expired(&self, leeway: Duration) -> bool {
+		self.is_expired_at(&Self::now(), leeway)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn numeric_dates() {
+		assert_eq!(
+			Timestamp::from_jwt_numeric_date(1_700_000_000),
+			Timestamp::new(1_700_000_000, 0)
+		);
+		assert_eq!(
+			Timestamp::from_jwt_numeric_date_f64(1_700_000_000.25),
+			Ok(Timestamp::new(1_700_000_000, 250_000_000))
+		);
+		assert_eq!(
+			Timestamp::from_jwt_numeric_date_f64(-1.5),
+			Ok(Timestamp::new(-2, 500_000_000))
+		);
+		assert_eq!(
+			Timestamp::from_jwt_numeric_date_f64(f64::NAN),
+			Err(TimestampError::InvalidDateTime)
+		);
+		assert_eq!(
+			Timestamp::from_jwt_numeric_date_f64(1e19),
+			Err(TimestampError::InvalidDateTime)
+		);
+
+		let timestamp = Timestamp::new(-2, 500_000_000);
+		assert_eq!(timestamp.to_jwt_numeric_date(), -2);
+		assert!((timestamp.to_jwt_numeric_date_f64() + 1.5).abs() < f64::EPSILON);
+	}
+
+	#[test]
+	fn expiration() {
+		let exp = Timestamp::new(1_000, 0);
+		let leeway = Duration::new(30, 0);
+
+		assert!(!exp.is_expired_at(&Timestamp::new(999, 0), Duration::default()));
+		assert!(exp.is_expired_at(&Timestamp::new(1_000, 0), Duration::default()));
+		assert!(!exp.is_expired_at(&Timestamp::new(1_029, 0), leeway));
+		assert!(exp.is_expired_at(&Timestamp::new(1_030, 0), leeway));
+
+		assert!(Timestamp::new(0, 0).is_expired(leeway));
+	}
+}
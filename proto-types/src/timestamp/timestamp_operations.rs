@@ -10,11 +10,12 @@ impl<'b> Sub<&'b Duration> for &Timestamp {
 	type Output = Timestamp;
 
 	fn sub(self, rhs: &'b Duration) -> Self::Output {
-		let duration = rhs.normalized();
+		let (base, duration) = (self.normalized(), rhs.normalized());
 
+		// Both nanos are within one second after normalization, so they cannot overflow
 		let mut new = Timestamp {
-			seconds: self.seconds.saturating_sub(duration.seconds),
-			nanos: self.nanos - duration.nanos,
+			seconds: base.seconds.saturating_sub(duration.seconds),
+			nanos: base.nanos - duration.nanos,
 		};
 
 		new.normalize();
@@ -86,11 +87,12 @@ impl<'b> Add<&'b Duration> for &Timestamp {
 	type Output = Timestamp;
 
 	fn add(self, rhs: &'b Duration) -> Self::Output {
-		let duration = rhs.normalized();
+		let (base, duration) = (self.normalized(), rhs.normalized());
 
+		// Both nanos are within one second after normalization, so they cannot overflow
 		let mut new = Timestamp {
-			seconds: self.seconds.saturating_add(duration.seconds),
-			nanos: self.nanos + duration.nanos,
+			seconds: base.seconds.saturating_add(duration.seconds),
+			nanos: base.nanos + duration.nanos,
 		};
 
 		new.normalize();
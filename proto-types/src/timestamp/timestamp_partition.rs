@@ -0,0 +1,128 @@
+use core::fmt::Write;
+
+use crate::{String, Timestamp, datetime_internal::DateTime};
+
+impl Timestamp {
+	/// Formats the UTC date and hour of this timestamp with a pattern, for building object store paths or partition keys.
+	///
+	/// The tokens `YYYY`, `MM`, `DD` and `HH` are replaced with the zero-padded year, month, day and hour. Everything else is copied as is.
+	///
+	/// # Examples
+	/// ```rust
+	/// use proto_types::Timestamp;
+	///
+	/// let timestamp: Timestamp = "2024-05-01T09:30:00Z".parse().unwrap();
+	///
+	/// assert_eq!(timestamp.to_partition_string("events/YYYY/MM/DD/HH"), "events/2024/05/01/09");
+	/// assert_eq!(timestamp.to_partition_string("dt=YYYY-MM-DD"), "dt=2024-05-01");
+	/// ```
+	#[must_use]
+	pub fn to_partition_string(&self, pattern: &str) -> String {
+		let datetime = DateTime::from(*self);
+		let mut output = String::with_capacity(pattern.len());
+		let mut rest = pattern;
+
+		while !rest.is_empty() {
+			if let Some(remaining) = rest.strip_prefix("YYYY") {
+				let _ = write!(output, "{:04}", datetime.year);
+				rest = remaining;
+			} else if let Some((token, remaining)) = ["MM", "DD", "HH"]
+				.iter()
+				.find_map(|token| rest.strip_prefix(token).map(|r| (*token, r)))
+			{
+				let value = match token {
+					"MM" => datetime.month,
+					"DD" => datetime.day,
+					_ => datetime.hour,
+				};
+				let _ = write!(output, "{value:02}");
+				rest = remaining;
+			} else {
+				let mut chars = rest.chars();
+				if let Some(c) = chars.next() {
+					output.push(c);
+				}
+				rest = chars.as_str();
+			}
+		}
+
+		output
+	}
+}
+
+#[cfg(feature = "date")]
+impl Timestamp {
+	/// Returns the UTC date of this timestamp, to be used as a daily partition key.
+	///
+	/// Returns `None` if the year is outside of the `1..=9999` range supported by [`Date`](crate::Date).
+	#[must_use]
+	pub fn partition_key_daily(&self) -> Option<crate::Date> {
+		let datetime = DateTime::from(*self);
+
+		if !(1..=9999).contains(&datetime.year) {
+			return None;
+		}
+
+		// SAFETY: The year was checked above
+		#[allow(clippy::cast_possible_truncation)]
+		let year = datetime.year as i32;
+
+		Some(crate::Date {
+			year,
+			month: i32::from(datetime.month),
+			day: i32::from(datetime.day),
+		})
+	}
+
+	/// Returns the UTC date and hour of this timestamp, to be used as an hourly partition key.
+	///
+	/// Returns `None` if the year is outside of the `1..=9999` range supported by [`Date`](crate::Date).
+	#[must_use]
+	pub fn partition_key_hourly(&self) -> Option<(crate::Date, u8)> {
+		let date = self.partition_key_daily()?;
+
+		Some((date, DateTime::from(*self).hour))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn partition_strings() {
+		let timestamp: Timestamp = "2024-12-31T23:59:59.9Z".parse().unwrap();
+
+		assert_eq!(
+			timestamp.to_partition_string("YYYY/MM/DD/HH"),
+			"2024/12/31/23"
+		);
+		assert_eq!(
+			timestamp.to_partition_string("year=YYYY/month=MM/île"),
+			"year=2024/month=12/île"
+		);
+		assert_eq!(
+			Timestamp::default().to_partition_string("HHDDMMYYYY"),
+			"0001011970"
+		);
+	}
+
+	#[cfg(feature = "date")]
+	#[test]
+	fn partition_keys() {
+		let timestamp: Timestamp = "2024-02-29T05:00:00Z".parse().unwrap();
+
+		assert_eq!(
+			timestamp.partition_key_hourly(),
+			Some((crate::Date::new(2024, 2, 29).unwrap(), 5))
+		);
+		assert_eq!(
+			Timestamp {
+				seconds: i64::MAX,
+				nanos: 0
+			}
+			.partition_key_daily(),
+			None
+		);
+	}
+}
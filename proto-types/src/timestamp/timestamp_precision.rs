@@ -0,0 +1,186 @@
+use super::*;
+
+/// The precision with which a [`Timestamp`] was recorded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub enum TimePrecision {
+	Seconds,
+	Millis,
+	Micros,
+	#[default]
+	Nanos,
+}
+
+impl TimePrecision {
+	/// Returns the amount of nanoseconds in one unit of this precision.
+	#[must_use]
+	#[inline]
+	pub const fn nanos_per_unit(self) -> i32 {
+		match self {
+			Self::Seconds => 1_000_000_000,
+			Self::Millis => 1_000_000,
+			Self::Micros => 1_000,
+			Self::Nanos => 1,
+		}
+	}
+
+	/// Returns the amount of fractional digits used to represent this precision.
+	#[must_use]
+	#[inline]
+	pub const fn fractional_digits(self) -> u32 {
+		match self {
+			Self::Seconds => 0,
+			Self::Millis => 3,
+			Self::Micros => 6,
+			Self::Nanos => 9,
+		}
+	}
+
+	/// Returns the coarser of the two precisions.
+	#[must_use]
+	#[inline]
+	pub fn coarsest(self, other: Self) -> Self {
+		self.min(other)
+	}
+}
+
+impl Timestamp {
+	/// Returns the coarsest precision that can represent this timestamp without losing information.
+	#[must_use]
+	pub const fn precision(&self) -> TimePrecision {
+		if self.nanos == 0 {
+			TimePrecision::Seconds
+		} else if self.nanos % 1_000_000 == 0 {
+			TimePrecision::Millis
+		} else if self.nanos % 1_000 == 0 {
+			TimePrecision::Micros
+		} else {
+			TimePrecision::Nanos
+		}
+	}
+
+	/// Truncates the timestamp to the given precision.
+	///
+	/// The timestamp is normalized first, so that the truncation always goes towards the past.
+	#[must_use]
+	pub fn truncated(&self, precision: TimePrecision) -> Self {
+		let mut ts = self.normalized();
+		ts.nanos -= ts.nanos % precision.nanos_per_unit();
+		ts
+	}
+}
+
+/// A [`Timestamp`] paired with the precision with which it was recorded.
+///
+/// Values coming from different sources often carry different precisions, so that comparing them naively leads to false mismatches (e.g. `10:00:00.123` from a source with millisecond precision versus `10:00:00` from a source with second precision).
+/// [`matches`](Self::matches) compares two values at the coarser of their precisions, and the [`Display`](fmt::Display) impl always prints the amount of fractional digits implied by the precision.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PrecisionTimestamp {
+	timestamp: Timestamp,
+	precision: TimePrecision,
+}
+
+impl PrecisionTimestamp {
+	/// Creates a new instance, truncating the timestamp to the given precision.
+	#[must_use]
+	#[inline]
+	pub fn new(timestamp: Timestamp, precision: TimePrecision) -> Self {
+		Self {
+			timestamp: timestamp.truncated(precision),
+			precision,
+		}
+	}
+
+	/// Returns the timestamp, truncated to the recorded precision.
+	#[must_use]
+	#[inline]
+	pub const fn timestamp(&self) -> Timestamp {
+		self.timestamp
+	}
+
+	/// Returns the recorded precision.
+	#[must_use]
+	#[inline]
+	pub const fn precision(&self) -> TimePrecision {
+		self.precision
+	}
+
+	/// Lowers the precision of this value, truncating the timestamp accordingly.
+	///
+	/// Raising the precision is not possible, so the current precision is kept if it is already coarser than the given one.
+	#[must_use]
+	#[inline]
+	pub fn with_precision(self, precision: TimePrecision) -> Self {
+		Self::new(self.timestamp, self.precision.coarsest(precision))
+	}
+
+	/// Checks if the two values represent the same instant, when compared at the coarser of their precisions.
+	#[must_use]
+	pub fn matches(&self, other: &Self) -> bool {
+		let precision = self.precision.coarsest(other.precision);
+
+		self.timestamp.truncated(precision) == other.timestamp.truncated(precision)
+	}
+}
+
+impl From<Timestamp> for PrecisionTimestamp {
+	/// Uses the coarsest precision that can represent the timestamp without losing information.
+	#[inline]
+	fn from(timestamp: Timestamp) -> Self {
+		Self::new(timestamp, timestamp.precision())
+	}
+}
+
+impl From<PrecisionTimestamp> for Timestamp {
+	#[inline]
+	fn from(value: PrecisionTimestamp) -> Self {
+		value.timestamp
+	}
+}
+
+impl fmt::Display for PrecisionTimestamp {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		DateTime::from(self.timestamp).write_rfc3339_fixed(f, self.precision.fractional_digits())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn precision_detection_and_truncation() {
+		let ts = Timestamp::new(10, 123_456_000);
+
+		assert_eq!(ts.precision(), TimePrecision::Micros);
+		assert_eq!(Timestamp::new(10, 0).precision(), TimePrecision::Seconds);
+		assert_eq!(
+			ts.truncated(TimePrecision::Millis),
+			Timestamp::new(10, 123_000_000)
+		);
+	}
+
+	#[test]
+	fn mixed_precision_matching() {
+		let coarse = PrecisionTimestamp::new(Timestamp::new(10, 0), TimePrecision::Seconds);
+		let fine = PrecisionTimestamp::new(Timestamp::new(10, 123_000_000), TimePrecision::Millis);
+		let later = PrecisionTimestamp::new(Timestamp::new(11, 0), TimePrecision::Millis);
+
+		assert_ne!(coarse, fine);
+		assert!(coarse.matches(&fine));
+		assert!(!coarse.matches(&later));
+	}
+
+	#[test]
+	fn fixed_digits_display() {
+		let ts = Timestamp::new(0, 120_000_000);
+
+		assert_eq!(
+			PrecisionTimestamp::new(ts, TimePrecision::Micros).to_string(),
+			"1970-01-01T00:00:00.120000Z"
+		);
+		assert_eq!(
+			PrecisionTimestamp::new(ts, TimePrecision::Seconds).to_string(),
+			"1970-01-01T00:00:00Z"
+		);
+	}
+}
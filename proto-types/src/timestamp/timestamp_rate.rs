@@ -0,0 +1,173 @@
+use crate::{Duration, Timestamp};
+
+/// Lets events through at most once per interval, dropping the ones that arrive in between (leading-edge throttling).
+///
+/// The current time is passed explicitly, so that the throttle can be driven by any clock.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Throttle {
+	interval: Duration,
+	last_fired: Option<Timestamp>,
+}
+
+impl Throttle {
+	/// Creates a new throttle with the given minimum interval between two events.
+	#[must_use]
+	#[inline]
+	pub const fn new(interval: Duration) -> Self {
+		Self {
+			interval,
+			last_fired: None,
+		}
+	}
+
+	/// Returns the minimum interval between two events.
+	#[must_use]
+	#[inline]
+	pub const fn interval(&self) -> Duration {
+		self.interval
+	}
+
+	/// Returns the time at which the last event was let through, if any.
+	#[must_use]
+	#[inline]
+	pub const fn last_fired(&self) -> Option<Timestamp> {
+		self.last_fired
+	}
+
+	/// Checks if an event that occurs at the time `now` should be let through, and records it if so.
+	pub fn should_fire(&mut self, now: &Timestamp) -> bool {
+		let fire = self
+			.last_fired
+			.is_none_or(|last| *now >= last + self.interval);
+
+		if fire {
+			self.last_fired = Some(*now);
+		}
+
+		fire
+	}
+
+	/// Same as [`should_fire`](Self::should_fire), using the current system time.
+	#[cfg(feature = "std")]
+	pub fn should_fire_now(&mut self) -> bool {
+		self.should_fire(&Timestamp::now())
+	}
+
+	/// Forgets the last event, so that the next one is always let through.
+	#[inline]
+	pub const fn reset(&mut self) {
+		self.last_fired = None;
+	}
+}
+
+/// Collapses bursts of events into a single one, which fires once no new events have occurred for the quiet period (trailing-edge debouncing).
+///
+/// Events are registered with [`record`](Self::record), and [`should_fire`](Self::should_fire) is meant to be polled periodically.
+/// The current time is passed explicitly, so that the debouncer can be driven by any clock.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Debouncer {
+	quiet_period: Duration,
+	last_event: Option<Timestamp>,
+}
+
+impl Debouncer {
+	/// Creates a new debouncer with the given quiet period.
+	#[must_use]
+	#[inline]
+	pub const fn new(quiet_period: Duration) -> Self {
+		Self {
+			quiet_period,
+			last_event: None,
+		}
+	}
+
+	/// Returns the quiet period.
+	#[must_use]
+	#[inline]
+	pub const fn quiet_period(&self) -> Duration {
+		self.quiet_period
+	}
+
+	/// Checks if there is an event that has not fired yet.
+	#[must_use]
+	#[inline]
+	pub const fn is_pending(&self) -> bool {
+		self.last_event.is_some()
+	}
+
+	/// Registers an event that occurred at the time `now`, restarting the quiet period.
+	#[inline]
+	pub const fn record(&mut self, now: &Timestamp) {
+		self.last_event = Some(*now);
+	}
+
+	/// Returns the time at which the pending event will fire, if there is one.
+	#[must_use]
+	pub fn deadline(&self) -> Option<Timestamp> {
+		self.last_event
+			.map(|last| last + self.quiet_period)
+	}
+
+	/// Checks if the pending event should fire at the time `now`, and clears it if so.
+	pub fn should_fire(&mut self, now: &Timestamp) -> bool {
+		let fire = self
+			.deadline()
+			.is_some_and(|deadline| *now >= deadline);
+
+		if fire {
+			self.last_event = None;
+		}
+
+		fire
+	}
+
+	/// Same as [`record`](Self::record), using the current system time.
+	#[cfg(feature = "std")]
+	pub fn record_now(&mut self) {
+		self.record(&Timestamp::now());
+	}
+
+	/// Same as [`should_fire`](Self::should_fire), using the current system time.
+	#[cfg(feature = "std")]
+	pub fn should_fire_now(&mut self) -> bool {
+		self.should_fire(&Timestamp::now())
+	}
+
+	/// Discards the pending event, if any.
+	#[inline]
+	pub const fn cancel(&mut self) {
+		self.last_event = None;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn throttle() {
+		let mut throttle = Throttle::new(Duration::new(10, 0));
+
+		assert!(throttle.should_fire(&Timestamp::new(100, 0)));
+		assert!(!throttle.should_fire(&Timestamp::new(105, 0)));
+		assert!(!throttle.should_fire(&Timestamp::new(109, 999_999_999)));
+		assert!(throttle.should_fire(&Timestamp::new(110, 0)));
+		assert_eq!(throttle.last_fired(), Some(Timestamp::new(110, 0)));
+	}
+
+	#[test]
+	fn debouncer() {
+		let mut debouncer = Debouncer::new(Duration::new(5, 0));
+
+		assert!(!debouncer.should_fire(&Timestamp::new(100, 0)));
+
+		debouncer.record(&Timestamp::new(100, 0));
+		debouncer.record(&Timestamp::new(103, 0));
+
+		assert!(!debouncer.should_fire(&Timestamp::new(107, 0)));
+		assert_eq!(debouncer.deadline(), Some(Timestamp::new(108, 0)));
+		assert!(debouncer.should_fire(&Timestamp::new(108, 0)));
+		assert!(!debouncer.is_pending());
+		assert!(!debouncer.should_fire(&Timestamp::new(200, 0)));
+	}
+}
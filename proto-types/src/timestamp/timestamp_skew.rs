@@ -0,0 +1,72 @@
+use crate::{Duration, Timestamp, constants::NANOS_PER_SECOND};
+
+impl Timestamp {
+	/// Returns the total nanoseconds since the unix epoch, which cannot overflow.
+	pub(crate) fn total_nanos(&self) -> i128 {
+		i128::from(self.seconds) * i128::from(NANOS_PER_SECOND) + i128::from(self.nanos)
+	}
+
+	/// Creates a normalized timestamp from the total nanoseconds since the unix epoch, returning `None` on overflow.
+	pub(crate) fn from_total_nanos(total: i128) -> Option<Self> {
+		let factor = i128::from(NANOS_PER_SECOND);
+		let seconds = i64::try_from(total.div_euclid(factor)).ok()?;
+
+		// SAFETY: The euclidean remainder is always within 0..NANOS_PER_SECOND
+		#[allow(clippy::cast_possible_truncation)]
+		let nanos = total.rem_euclid(factor) as i32;
+
+		Some(Self { seconds, nanos })
+	}
+
+	/// Returns `true` if the two timestamps are at most `tolerance` apart, in either direction.
+	///
+	/// The sign of `tolerance` is ignored.
+	#[must_use]
+	pub fn approx_eq(&self, other: &Self, tolerance: Duration) -> bool {
+		self.total_nanos().abs_diff(other.total_nanos()) <= tolerance.total_nanos().unsigned_abs()
+	}
+
+	/// Returns `true` if this timestamp comes before `other`, allowing for a clock skew of `skew`.
+	///
+	/// This is the check for `exp`-style fields: `now.is_before_with_skew(&expires_at, leeway)`.
+	/// The sign of `skew` is ignored.
+	#[must_use]
+	pub fn is_before_with_skew(&self, other: &Self, skew: Duration) -> bool {
+		self.total_nanos() < other.total_nanos() + skew.total_nanos().abs()
+	}
+
+	/// Returns `true` if this timestamp comes after `other`, allowing for a clock skew of `skew`.
+	///
+	/// This is the check for `nbf`-style fields: `now.is_after_with_skew(&not_before, leeway)`.
+	/// The sign of `skew` is ignored.
+	#[must_use]
+	pub fn is_after_with_skew(&self, other: &Self, skew: Duration) -> bool {
+		self.total_nanos() > other.total_nanos() - skew.total_nanos().abs()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn skew_comparisons() {
+		let base = Timestamp::new(1_000, 0);
+		let later = Timestamp::new(1_002, 500_000_000);
+		let leeway = Duration::new(3, 0);
+
+		assert!(base.approx_eq(&later, leeway));
+		assert!(later.approx_eq(&base, Duration::new(-3, 0)));
+		assert!(!base.approx_eq(&later, Duration::new(2, 0)));
+
+		assert!(later.is_before_with_skew(&base, leeway));
+		assert!(!later.is_before_with_skew(&base, Duration::new(1, 0)));
+
+		assert!(base.is_after_with_skew(&later, leeway));
+		assert!(!base.is_after_with_skew(&later, Duration::new(2, 500_000_000)));
+
+		let max = Timestamp::new(i64::MAX, 999_999_999);
+		assert!(max.approx_eq(&max, Duration::default()));
+		assert!(max.is_before_with_skew(&max, Duration::new(i64::MAX, 0)));
+	}
+}
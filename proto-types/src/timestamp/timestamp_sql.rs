@@ -0,0 +1,103 @@
+use core::fmt::Write;
+
+use super::*;
+use crate::String;
+
+impl Timestamp {
+	/// Formats the timestamp as a standard SQL `TIMESTAMP WITH TIME ZONE` literal, such as `TIMESTAMP '2024-01-15 10:30:00.5+00:00'`.
+	///
+	/// Fails if the timestamp is outside of the range allowed by the protobuf spec, which cannot be written as a 4-digit year.
+	pub fn to_sql_timestamp_literal(&self) -> Result<String, TimestampError> {
+		let mut output = String::from("TIMESTAMP '");
+		write_sql_datetime(*self, &mut output)?;
+		output.push_str("+00:00'");
+
+		Ok(output)
+	}
+
+	/// Formats the timestamp as a BigQuery `TIMESTAMP` literal, such as `TIMESTAMP '2024-01-15 10:30:00.123456 UTC'`.
+	///
+	/// BigQuery only supports microsecond precision, so the nanos are truncated.
+	/// Fails if the timestamp is outside of the range allowed by the protobuf spec, which is also the range supported by BigQuery.
+	pub fn to_bq_timestamp(&self) -> Result<String, TimestampError> {
+		let mut output = String::from("TIMESTAMP '");
+		write_sql_datetime(self.truncated(TimePrecision::Micros), &mut output)?;
+		output.push_str(" UTC'");
+
+		Ok(output)
+	}
+}
+
+fn write_sql_datetime(timestamp: Timestamp, output: &mut String) -> Result<(), TimestampError> {
+	let timestamp = timestamp.normalized();
+
+	if !(MIN_SECONDS..=MAX_SECONDS).contains(&timestamp.seconds) {
+		return Err(TimestampError::InvalidDateTime);
+	}
+
+	let dt = DateTime::from(timestamp);
+
+	let _ = write!(
+		output,
+		"{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+		dt.year, dt.month, dt.day, dt.hour, dt.minute, dt.second
+	);
+
+	if dt.nanos > 0 {
+		let mut nanos = dt.nanos;
+		let mut width = 9;
+
+		while nanos.is_multiple_of(10) {
+			nanos /= 10;
+			width -= 1;
+		}
+
+		let _ = write!(output, ".{nanos:0width$}");
+	}
+
+	Ok(())
+}
+
+/// Quotes an SQL identifier with double quotes, doubling any quotes it contains.
+#[cfg(feature = "interval")]
+pub(crate) fn quote_sql_identifier(identifier: &str) -> String {
+	let mut output = String::with_capacity(identifier.len() + 2);
+	output.push('"');
+
+	for c in identifier.chars() {
+		if c == '"' {
+			output.push('"');
+		}
+		output.push(c);
+	}
+
+	output.push('"');
+	output
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn sql_literals() {
+		let ts: Timestamp = "2024-01-15T10:30:00.123456789Z".parse().unwrap();
+
+		assert_eq!(
+			ts.to_sql_timestamp_literal().unwrap(),
+			"TIMESTAMP '2024-01-15 10:30:00.123456789+00:00'"
+		);
+		assert_eq!(
+			ts.to_bq_timestamp().unwrap(),
+			"TIMESTAMP '2024-01-15 10:30:00.123456 UTC'"
+		);
+		assert_eq!(
+			Timestamp::new(0, 0).to_bq_timestamp().unwrap(),
+			"TIMESTAMP '1970-01-01 00:00:00 UTC'"
+		);
+		assert_eq!(
+			Timestamp::new(i64::MAX, 0).to_sql_timestamp_literal(),
+			Err(TimestampError::InvalidDateTime)
+		);
+	}
+}
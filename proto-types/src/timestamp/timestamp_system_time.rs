@@ -0,0 +1,198 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use thiserror::Error;
+
+use crate::{Duration, Timestamp, timestamp::TimestampError};
+
+/// Indicates that a [`Timestamp`] could not be converted to a [`SystemTime`] because it is out of range.
+///
+/// The range of times that can be represented by `SystemTime` depends on the platform. All
+/// `Timestamp`s are likely representable on 64-bit Unix-like platforms, but other platforms,
+/// such as Windows and 32-bit Linux, may not be able to represent the full range of
+/// `Timestamp`s.
+#[derive(Debug, Error, PartialEq, Eq, Clone, Copy)]
+#[error(
+	"{timestamp} is not representable as a `SystemTime` because it is out of range by {excess}"
+)]
+#[non_exhaustive]
+pub struct SystemTimeRangeError {
+	/// The normalized timestamp that failed the conversion.
+	pub timestamp: Timestamp,
+	/// The distance between the timestamp and the closest representable `SystemTime`.
+	///
+	/// It is positive for timestamps past the latest representable time, and negative for timestamps before the earliest one.
+	pub excess: Duration,
+}
+
+impl From<SystemTimeRangeError> for TimestampError {
+	#[inline]
+	fn from(value: SystemTimeRangeError) -> Self {
+		Self::OutOfSystemRange(value.timestamp)
+	}
+}
+
+fn checked_system_time(timestamp: Timestamp) -> Option<SystemTime> {
+	let seconds = core::time::Duration::from_secs(timestamp.seconds.unsigned_abs());
+
+	let time = if timestamp.seconds >= 0 {
+		UNIX_EPOCH.checked_add(seconds)
+	} else {
+		UNIX_EPOCH.checked_sub(seconds)
+	}?;
+
+	// The timestamp is normalized, so nanos are never negative
+	time.checked_add(core::time::Duration::from_nanos(
+		timestamp.nanos.unsigned_abs().into(),
+	))
+}
+
+/// Returns the largest value within `0..=max` that fits, assuming that all the values below it fit as well.
+fn largest_fitting(max: u64, fits: impl Fn(u64) -> bool) -> u64 {
+	let (mut low, mut high) = (0_u64, max);
+
+	while low < high {
+		let mid = low + (high - low).div_ceil(2);
+
+		if fits(mid) {
+			low = mid;
+		} else {
+			high = mid - 1;
+		}
+	}
+
+	low
+}
+
+/// Finds the latest (or earliest) `SystemTime` that can be represented on this platform.
+///
+/// The resolution of `SystemTime` depends on the platform (100ns on Windows), so the sub-second offset is searched as well.
+fn find_system_time_bound(latest: bool) -> SystemTime {
+	let offset = |time: SystemTime, duration: core::time::Duration| {
+		if latest {
+			time.checked_add(duration)
+		} else {
+			time.checked_sub(duration)
+		}
+	};
+
+	let seconds = largest_fitting(u64::MAX, |seconds| {
+		offset(UNIX_EPOCH, core::time::Duration::from_secs(seconds)).is_some()
+	});
+	let bound = offset(UNIX_EPOCH, core::time::Duration::from_secs(seconds)).unwrap_or(UNIX_EPOCH);
+
+	let nanos = largest_fitting(999_999_999, |nanos| {
+		offset(bound, core::time::Duration::from_nanos(nanos)).is_some()
+	});
+
+	offset(bound, core::time::Duration::from_nanos(nanos)).unwrap_or(bound)
+}
+
+/// Returns the latest (or earliest) `SystemTime` that can be represented on this platform, which is only searched once.
+fn system_time_bound(latest: bool) -> SystemTime {
+	static BOUNDS: std::sync::OnceLock<(SystemTime, SystemTime)> = std::sync::OnceLock::new();
+
+	let (earliest, latest_time) =
+		*BOUNDS.get_or_init(|| (find_system_time_bound(false), find_system_time_bound(true)));
+
+	if latest { latest_time } else { earliest }
+}
+
+fn system_time_total_nanos(time: SystemTime) -> i128 {
+	match time.duration_since(UNIX_EPOCH) {
+		Ok(duration) => duration.as_nanos().cast_signed(),
+		Err(error) => -error.duration().as_nanos().cast_signed(),
+	}
+}
+
+impl TryFrom<Timestamp> for SystemTime {
+	type Error = SystemTimeRangeError;
+
+	fn try_from(timestamp: Timestamp) -> Result<Self, Self::Error> {
+		let timestamp = timestamp.normalized();
+
+		checked_system_time(timestamp).ok_or_else(|| {
+			let latest = timestamp.seconds >= 0;
+			let bound = system_time_total_nanos(system_time_bound(latest));
+
+			let excess =
+				Duration::from_total_nanos(timestamp.total_nanos() - bound).unwrap_or(if latest {
+					Duration {
+						seconds: i64::MAX,
+						nanos: 999_999_999,
+					}
+				} else {
+					Duration {
+						seconds: i64::MIN,
+						nanos: -999_999_999,
+					}
+				});
+
+			SystemTimeRangeError { timestamp, excess }
+		})
+	}
+}
+
+impl Timestamp {
+	/// Converts this timestamp to a [`SystemTime`], clamping it to the range that can be represented on this platform.
+	#[must_use]
+	pub fn to_system_time_saturating(&self) -> SystemTime {
+		SystemTime::try_from(*self)
+			.unwrap_or_else(|error| system_time_bound(!error.excess.is_negative()))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn system_time_bounds() {
+		let latest = system_time_bound(true);
+		let earliest = system_time_bound(false);
+
+		assert!(earliest < UNIX_EPOCH && UNIX_EPOCH < latest);
+		assert!(
+			latest
+				.checked_add(core::time::Duration::from_nanos(1))
+				.is_none()
+		);
+		assert!(
+			earliest
+				.checked_sub(core::time::Duration::from_nanos(1))
+				.is_none()
+		);
+	}
+
+	#[test]
+	fn saturating_conversion() {
+		let timestamp = Timestamp {
+			seconds: 1_700_000_000,
+			nanos: 5,
+		};
+		assert_eq!(
+			timestamp.to_system_time_saturating(),
+			SystemTime::try_from(timestamp).unwrap()
+		);
+
+		let extreme = Timestamp {
+			seconds: i64::MIN,
+			nanos: 0,
+		};
+		let saturated = extreme.to_system_time_saturating();
+		assert_eq!(saturated, system_time_bound(false));
+	}
+
+	#[test]
+	fn range_error_converts_to_timestamp_error() {
+		let timestamp = Timestamp::default();
+		let error = SystemTimeRangeError {
+			timestamp,
+			excess: Duration::new(1, 0),
+		};
+
+		assert_eq!(
+			TimestampError::from(error),
+			TimestampError::OutOfSystemRange(timestamp)
+		);
+	}
+}
@@ -16,14 +16,21 @@ use prost::Name;
 ///
 /// Schemes other than `http`, `https` (or the empty scheme) might be used with implementation
 /// specific semantics.
-#[derive(Debug, Eq, PartialEq)]
-pub(crate) struct TypeUrl<'a> {
+///
+/// Two type URLs are considered equal if they refer to the same type, regardless of their domain.
+#[derive(Debug, Clone, Copy)]
+pub struct TypeUrl<'a> {
+	url: &'a str,
 	/// Fully qualified name of the type, e.g. `google.protobuf.Duration`
-	pub(crate) full_name: &'a str,
+	full_name: &'a str,
 }
 
 impl<'a> TypeUrl<'a> {
-	pub(crate) fn new(s: &'a str) -> core::option::Option<Self> {
+	/// Parses a type URL, such as `type.googleapis.com/google.protobuf.Duration`.
+	///
+	/// Returns `None` if the URL does not contain a "/" or if the type name is not in its canonical form.
+	#[must_use]
+	pub fn parse(s: &'a str) -> core::option::Option<Self> {
 		// Must contain at least one "/" character.
 
 		let slash_pos = s.rfind('/')?;
@@ -39,7 +46,59 @@ impl<'a> TypeUrl<'a> {
 			return None;
 		}
 
-		Some(Self { full_name })
+		Some(Self { url: s, full_name })
+	}
+
+	/// Returns the type URL for the given message type, using `type.googleapis.com` as the domain.
+	#[must_use]
+	#[inline]
+	pub fn for_message<M: Name>() -> String {
+		type_url_for::<M>()
+	}
+
+	/// Returns the full type URL.
+	#[must_use]
+	#[inline]
+	pub const fn as_str(&self) -> &'a str {
+		self.url
+	}
+
+	/// Returns the part of the URL before the type name, such as `type.googleapis.com`.
+	#[must_use]
+	#[inline]
+	pub fn domain(&self) -> &'a str {
+		&self.url[..self.url.len() - self.full_name.len() - 1]
+	}
+
+	/// Returns the fully qualified name of the type, such as `google.protobuf.Duration`.
+	#[must_use]
+	#[inline]
+	pub const fn full_name(&self) -> &'a str {
+		self.full_name
+	}
+
+	/// Returns `true` if this URL refers to the message type `M`, regardless of its domain.
+	#[must_use]
+	pub fn matches<M: Name>(&self) -> bool {
+		self.full_name
+			.strip_prefix(M::PACKAGE)
+			.and_then(|rest| rest.strip_prefix('.'))
+			.is_some_and(|name| name == M::NAME)
+	}
+}
+
+impl PartialEq for TypeUrl<'_> {
+	#[inline]
+	fn eq(&self, other: &Self) -> bool {
+		self.full_name == other.full_name
+	}
+}
+
+impl Eq for TypeUrl<'_> {}
+
+impl fmt::Display for TypeUrl<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(self.url)
 	}
 }
 
@@ -48,3 +107,36 @@ impl<'a> TypeUrl<'a> {
 pub(crate) fn type_url_for<T: Name>() -> String {
 	format!("type.googleapis.com/{}.{}", T::PACKAGE, T::NAME)
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn type_urls() {
+		let url = TypeUrl::parse("type.googleapis.com/google.protobuf.Duration").unwrap();
+
+		assert_eq!(url.domain(), "type.googleapis.com");
+		assert_eq!(url.full_name(), "google.protobuf.Duration");
+		assert!(url.matches::<Duration>());
+		assert!(!url.matches::<Timestamp>());
+		assert_eq!(
+			url,
+			TypeUrl::parse("example.com/types/google.protobuf.Duration").unwrap()
+		);
+		assert_eq!(url.to_string(), url.as_str());
+		assert_eq!(
+			TypeUrl::for_message::<Duration>(),
+			"type.googleapis.com/google.protobuf.Duration"
+		);
+
+		assert_eq!(
+			TypeUrl::parse("/google.protobuf.Duration")
+				.unwrap()
+				.domain(),
+			""
+		);
+		assert!(TypeUrl::parse("google.protobuf.Duration").is_none());
+		assert!(TypeUrl::parse("type.googleapis.com/.google.protobuf.Duration").is_none());
+	}
+}
@@ -0,0 +1,66 @@
+use core::ops::Deref;
+
+/// A uniform way of validating the well known types that carry constraints beyond their wire format.
+///
+/// Generic code can use this trait to validate any of these types without knowing their specific validation methods.
+pub trait ProtoValidate {
+	/// The error returned when the value is not valid.
+	type Error;
+
+	/// Checks that the value respects the constraints described in its protobuf definition.
+	fn validate(&self) -> Result<(), Self::Error>;
+
+	/// Returns `true` if the value respects the constraints described in its protobuf definition.
+	#[must_use]
+	#[inline]
+	fn is_valid(&self) -> bool {
+		self.validate().is_ok()
+	}
+}
+
+/// A value that is known to have passed [`ProtoValidate::validate`].
+///
+/// Since the fields of the generated messages are public, this wrapper can be used on critical paths to ensure that a value cannot be mutated into an invalid state after being checked.
+#[must_use]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Validated<T>(T);
+
+impl<T: ProtoValidate> Validated<T> {
+	/// Validates the value and wraps it.
+	#[inline]
+	pub fn new(value: T) -> Result<Self, T::Error> {
+		value.validate()?;
+		Ok(Self(value))
+	}
+
+	/// Applies a mutation to the inner value, validating the result before wrapping it again.
+	pub fn try_map(self, f: impl FnOnce(&mut T)) -> Result<Self, T::Error> {
+		let mut value = self.0;
+		f(&mut value);
+		Self::new(value)
+	}
+}
+
+impl<T> Validated<T> {
+	/// Unwraps the inner value.
+	#[inline]
+	pub fn into_inner(self) -> T {
+		self.0
+	}
+}
+
+impl<T> Deref for Validated<T> {
+	type Target = T;
+
+	#[inline]
+	fn deref(&self) -> &Self::Target {
+		&self.0
+	}
+}
+
+impl<T> AsRef<T> for Validated<T> {
+	#[inline]
+	fn as_ref(&self) -> &T {
+		&self.0
+	}
+}
@@ -0,0 +1,297 @@
+//! Weighted selection and averaging helpers, for configurations such as traffic splits.
+//!
+//! Weights are normalized with exact integer arithmetic, so a split such as `1/3, 1/3, 1/3` is sampled with exactly the same probability for each entry, without the bias introduced by floating point sums.
+
+use rand::Rng;
+use thiserror::Error;
+
+use crate::{Duration, Fraction, RoundingMode, Vec, fraction::FractionError, integer::gcd_u128};
+
+/// Errors that can occur while normalizing or using a set of weights.
+#[derive(Debug, Error, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum WeightedError {
+	#[error("The list of weighted items is empty")]
+	Empty,
+	#[error("Weights cannot be negative")]
+	NegativeWeight,
+	#[error("The sum of the weights is zero")]
+	ZeroTotalWeight,
+	#[error("Invalid weight: {0}")]
+	InvalidWeight(FractionError),
+	#[error("The weighted computation resulted in an overflow")]
+	Overflow,
+}
+
+/// Scales the weights to a common denominator, returning the integer numerators and their sum.
+fn integer_weights<'a>(
+	weights: impl Iterator<Item = &'a Fraction> + Clone,
+) -> Result<(Vec<u128>, u128), WeightedError> {
+	// The fields are public, so the weights are brought into their canonical form first
+	let canonical = |weight: &Fraction| {
+		let weight = Fraction::new(weight.numerator, weight.denominator)
+			.map_err(WeightedError::InvalidWeight)?;
+
+		if weight.numerator < 0 {
+			return Err(WeightedError::NegativeWeight);
+		}
+
+		Ok((
+			weight.numerator.unsigned_abs(),
+			weight.denominator.unsigned_abs(),
+		))
+	};
+
+	let mut common_denominator: u128 = 1;
+
+	for weight in weights.clone() {
+		let (_, denominator) = canonical(weight)?;
+		let denominator = u128::from(denominator);
+
+		common_denominator = (common_denominator / gcd_u128(common_denominator, denominator))
+			.checked_mul(denominator)
+			.ok_or(WeightedError::Overflow)?;
+	}
+
+	let mut total: u128 = 0;
+	let scaled = weights
+		.map(|weight| {
+			let (numerator, denominator) = canonical(weight)?;
+			let scaled = u128::from(numerator)
+				.checked_mul(common_denominator / u128::from(denominator))
+				.ok_or(WeightedError::Overflow)?;
+
+			total = total
+				.checked_add(scaled)
+				.ok_or(WeightedError::Overflow)?;
+
+			Ok(scaled)
+		})
+		.collect::<Result<Vec<_>, WeightedError>>()?;
+
+	if scaled.is_empty() {
+		return Err(WeightedError::Empty);
+	}
+
+	if total == 0 {
+		return Err(WeightedError::ZeroTotalWeight);
+	}
+
+	Ok((scaled, total))
+}
+
+/// Normalizes the weights so that they sum up to exactly one, preserving their ratios.
+///
+/// # Examples
+/// ```rust
+/// use proto_types::{Fraction, weighted::normalize_weights};
+///
+/// let weights = [Fraction::new(1, 2).unwrap(), Fraction::new(1, 4).unwrap()];
+///
+/// assert_eq!(
+///   normalize_weights(&weights).unwrap(),
+///   [Fraction::new(2, 3).unwrap(), Fraction::new(1, 3).unwrap()]
+/// );
+/// ```
+pub fn normalize_weights(weights: &[Fraction]) -> Result<Vec<Fraction>, WeightedError> {
+	let (scaled, total) = integer_weights(weights.iter())?;
+
+	scaled
+		.into_iter()
+		.map(|weight| {
+			let divisor = gcd_u128(weight, total).max(1);
+			let numerator = i64::try_from(weight / divisor).map_err(|_| WeightedError::Overflow)?;
+			let denominator =
+				i64::try_from(total / divisor).map_err(|_| WeightedError::Overflow)?;
+
+			Fraction::new(numerator, denominator).map_err(WeightedError::InvalidWeight)
+		})
+		.collect()
+}
+
+/// Picks one of the items at random, with a probability proportional to its weight.
+///
+/// Items with a weight of zero are never picked. It returns an error if the list is empty, if any weight is negative or invalid, or if all the weights are zero.
+///
+/// # Examples
+/// ```rust
+/// use proto_types::{Fraction, weighted::choose_weighted};
+///
+/// let split = [
+///   ("stable", Fraction::new(9, 10).unwrap()),
+///   ("canary", Fraction::new(1, 10).unwrap()),
+///   ("disabled", Fraction::new(0, 1).unwrap()),
+/// ];
+///
+/// # use rand::{SeedableRng, rngs::SmallRng};
+/// let mut rng = SmallRng::seed_from_u64(42);
+/// let backend = choose_weighted(&mut rng, &split).unwrap();
+/// assert_ne!(*backend, "disabled");
+/// ```
+pub fn choose_weighted<'a, T, R>(
+	rng: &mut R,
+	items: &'a [(T, Fraction)],
+) -> Result<&'a T, WeightedError>
+where
+	R: Rng + ?Sized,
+{
+	let (scaled, total) = integer_weights(items.iter().map(|(_, weight)| weight))?;
+
+	let mut target = rng.random_range(0..total);
+
+	for ((item, _), weight) in items.iter().zip(scaled) {
+		if target < weight {
+			return Ok(item);
+		}
+
+		target -= weight;
+	}
+
+	// The target is always below the sum of the weights
+	unreachable!("the sampled value exceeds the total weight")
+}
+
+impl Duration {
+	/// Returns the average of the durations, each counted as many times as its weight.
+	///
+	/// The result is computed exactly on the nanoseconds, and then rounded to the nearest nanosecond (with ties to even).
+	///
+	/// # Examples
+	/// ```rust
+	/// use proto_types::Duration;
+	///
+	/// let latencies = [(Duration::new(0, 100_000_000), 3), (Duration::new(1, 0), 1)];
+	///
+	/// assert_eq!(
+	///   Duration::weighted_average(&latencies).unwrap(),
+	///   Duration::new(0, 325_000_000)
+	/// );
+	/// ```
+	pub fn weighted_average(items: &[(Self, u64)]) -> Result<Self, WeightedError> {
+		if items.is_empty() {
+			return Err(WeightedError::Empty);
+		}
+
+		let mut weighted_sum: i128 = 0;
+		let mut total_weight: i128 = 0;
+
+		for (duration, weight) in items {
+			let weight = i128::from(*weight);
+
+			weighted_sum = duration
+				.total_nanos()
+				.checked_mul(weight)
+				.and_then(|product| weighted_sum.checked_add(product))
+				.ok_or(WeightedError::Overflow)?;
+			total_weight += weight;
+		}
+
+		if total_weight == 0 {
+			return Err(WeightedError::ZeroTotalWeight);
+		}
+
+		Self::from_total_nanos(RoundingMode::HalfEven.div(weighted_sum, total_weight))
+			.ok_or(WeightedError::Overflow)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use rand::{SeedableRng, rngs::SmallRng};
+
+	use super::*;
+
+	#[test]
+	fn weighted_average() {
+		assert_eq!(
+			Duration::weighted_average(&[(Duration::new(1, 0), 1), (Duration::new(2, 0), 1)]),
+			Ok(Duration::new(1, 500_000_000))
+		);
+		assert_eq!(
+			Duration::weighted_average(&[(Duration::new(0, 1), 1), (Duration::new(0, 2), 1)]),
+			Ok(Duration::new(0, 2))
+		);
+		assert_eq!(
+			Duration::weighted_average(&[(Duration::new(-5, 0), 1), (Duration::new(5, 0), 0)]),
+			Ok(Duration::new(-5, 0))
+		);
+		assert_eq!(
+			Duration::weighted_average(&[(Duration::new(i64::MAX, 0), u64::MAX)]),
+			Err(WeightedError::Overflow)
+		);
+
+		assert_eq!(Duration::weighted_average(&[]), Err(WeightedError::Empty));
+		assert_eq!(
+			Duration::weighted_average(&[(Duration::new(1, 0), 0)]),
+			Err(WeightedError::ZeroTotalWeight)
+		);
+	}
+
+	#[test]
+	fn exact_normalization() {
+		let third = Fraction::new(1, 3).unwrap();
+
+		assert_eq!(
+			normalize_weights(&[third, third, third]),
+			Ok(alloc::vec![third, third, third])
+		);
+		assert_eq!(
+			normalize_weights(&[
+				Fraction::new(3, 7).unwrap(),
+				Fraction::new(0, 1).unwrap(),
+				Fraction::new(5, 11).unwrap()
+			]),
+			Ok(alloc::vec![
+				Fraction::new(33, 68).unwrap(),
+				Fraction::new(0, 1).unwrap(),
+				Fraction::new(35, 68).unwrap()
+			])
+		);
+
+		assert_eq!(normalize_weights(&[]), Err(WeightedError::Empty));
+		assert_eq!(
+			normalize_weights(&[Fraction::new(-1, 2).unwrap()]),
+			Err(WeightedError::NegativeWeight)
+		);
+		assert_eq!(
+			normalize_weights(&[Fraction::new(0, 1).unwrap()]),
+			Err(WeightedError::ZeroTotalWeight)
+		);
+		assert_eq!(
+			normalize_weights(&[Fraction {
+				numerator: 1,
+				denominator: 0
+			}]),
+			Err(WeightedError::InvalidWeight(FractionError::ZeroDenominator))
+		);
+	}
+
+	#[test]
+	fn weighted_choice() {
+		let mut rng = SmallRng::seed_from_u64(7);
+		let split = [
+			('a', Fraction::new(1, 2).unwrap()),
+			('b', Fraction::new(0, 1).unwrap()),
+			('c', Fraction::new(1, 4).unwrap()),
+			('d', Fraction::new(1, 4).unwrap()),
+		];
+
+		let mut counts = [0_u32; 4];
+
+		for _ in 0..40_000 {
+			let picked = choose_weighted(&mut rng, &split).unwrap();
+			counts[split
+				.iter()
+				.position(|(item, _)| item == picked)
+				.unwrap()] += 1;
+		}
+
+		assert_eq!(counts[1], 0);
+		assert!((19_000..21_000).contains(&counts[0]), "{counts:?}");
+		assert!((9_000..11_000).contains(&counts[2]), "{counts:?}");
+		assert!((9_000..11_000).contains(&counts[3]), "{counts:?}");
+
+		let empty: [(char, Fraction); 0] = [];
+		assert_eq!(choose_weighted(&mut rng, &empty), Err(WeightedError::Empty));
+	}
+}
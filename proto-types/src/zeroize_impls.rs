@@ -0,0 +1,85 @@
+//! [`Zeroize`] implementations for the well known types.
+//!
+//! Most of these types are `Copy` or are destructured by value throughout the ecosystem, so they cannot implement `Drop`.
+//! To scrub a value automatically when it goes out of scope, wrap it in [`Zeroizing`](zeroize::Zeroizing) instead.
+
+use zeroize::Zeroize;
+
+use crate::{Any, Duration, Timestamp};
+
+macro_rules! impl_zeroize {
+	($($(#[$meta:meta])* $ty:ty { $($field:ident),+ }),* $(,)?) => {
+		$(
+			$(#[$meta])*
+			impl Zeroize for $ty {
+				#[inline]
+				fn zeroize(&mut self) {
+					$(self.$field.zeroize();)+
+				}
+			}
+		)*
+	};
+}
+
+impl_zeroize!(
+	Timestamp { seconds, nanos },
+	Duration { seconds, nanos },
+	Any { type_url, value },
+	#[cfg(feature = "money")]
+	crate::Money {
+		currency_code,
+		units,
+		nanos
+	},
+	#[cfg(feature = "date")]
+	crate::Date { year, month, day },
+	#[cfg(feature = "timeofday")]
+	crate::TimeOfDay {
+		hours,
+		minutes,
+		seconds,
+		nanos
+	},
+	#[cfg(feature = "latlng")]
+	crate::LatLng {
+		latitude,
+		longitude
+	},
+	#[cfg(feature = "fraction")]
+	crate::Fraction {
+		numerator,
+		denominator
+	},
+);
+
+#[cfg(feature = "interval")]
+impl Zeroize for crate::Interval {
+	fn zeroize(&mut self) {
+		self.start_time.zeroize();
+		self.end_time.zeroize();
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use zeroize::Zeroizing;
+
+	use super::*;
+	use crate::ToString;
+
+	#[test]
+	fn scrubs_fields() {
+		let mut any = Any {
+			type_url: "type.googleapis.com/secret".to_string(),
+			value: [1, 2, 3].to_vec(),
+		};
+		any.zeroize();
+
+		assert!(any.type_url.is_empty());
+		assert!(any.value.is_empty());
+
+		let mut timestamp = Zeroizing::new(Timestamp::new(1_700_000_000, 5));
+		timestamp.zeroize();
+		assert_eq!(*timestamp, Timestamp::default());
+	}
+}